@@ -0,0 +1,5 @@
+mod operation;
+mod report;
+
+pub use operation::{parse_ids, run_bulk, select_workflows, BulkOperation};
+pub use report::{BulkResult, BulkStatus};
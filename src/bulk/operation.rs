@@ -0,0 +1,251 @@
+use crate::bulk::{BulkResult, BulkStatus};
+use crate::client::N8nClient;
+use crate::error::Result;
+use crate::models::{Tag, Workflow};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Action applied to every selected workflow
+#[derive(Debug, Clone)]
+pub enum BulkOperation {
+    Activate,
+    Deactivate,
+    Delete,
+    /// Add tags, keeping the ones already assigned
+    AddTags(Vec<Tag>),
+    /// Remove tags, keeping the others
+    RemoveTags(Vec<Tag>),
+}
+
+impl BulkOperation {
+    /// Verb used in prompts ("activate")
+    pub fn verb(&self) -> &'static str {
+        match self {
+            Self::Activate => "activate",
+            Self::Deactivate => "deactivate",
+            Self::Delete => "delete",
+            Self::AddTags(_) => "tag",
+            Self::RemoveTags(_) => "untag",
+        }
+    }
+
+    /// Verb used in summaries ("activated")
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            Self::Activate => "activated",
+            Self::Deactivate => "deactivated",
+            Self::Delete => "deleted",
+            Self::AddTags(_) => "tagged",
+            Self::RemoveTags(_) => "untagged",
+        }
+    }
+
+    /// Whether the workflow is already in the requested state
+    pub fn is_noop(&self, workflow: &Workflow) -> bool {
+        let has = |tag: &Tag| workflow.tags.iter().any(|t| t.id == tag.id);
+        match self {
+            Self::Activate => workflow.active,
+            Self::Deactivate => !workflow.active,
+            Self::Delete => false,
+            Self::AddTags(tags) => tags.iter().all(has),
+            Self::RemoveTags(tags) => !tags.iter().any(has),
+        }
+    }
+
+    /// Tag IDs the workflow ends up with (tag operations replace the whole set)
+    fn tag_ids(&self, workflow: &Workflow) -> Vec<String> {
+        let mut ids: Vec<String> = workflow.tags.iter().map(|t| t.id.clone()).collect();
+        match self {
+            Self::AddTags(tags) => {
+                for tag in tags {
+                    if !ids.contains(&tag.id) {
+                        ids.push(tag.id.clone());
+                    }
+                }
+            }
+            Self::RemoveTags(tags) => ids.retain(|id| !tags.iter().any(|t| &t.id == id)),
+            _ => {}
+        }
+        ids
+    }
+
+    async fn apply(&self, client: &N8nClient, workflow: &Workflow) -> Result<()> {
+        match self {
+            Self::Activate => client.activate_workflow(&workflow.id).await.map(|_| ()),
+            Self::Deactivate => client.deactivate_workflow(&workflow.id).await.map(|_| ()),
+            Self::Delete => client.delete_workflow(&workflow.id).await,
+            Self::AddTags(_) | Self::RemoveTags(_) => {
+                client
+                    .assign_tags(&workflow.id, self.tag_ids(workflow))
+                    .await
+            }
+        }
+    }
+}
+
+/// Parse workflow IDs, one per line (first column; blank lines and `#` comments are skipped)
+pub fn parse_ids(input: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    input
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|id| !id.starts_with('#'))
+        .filter(|id| seen.insert(id.to_string()))
+        .map(String::from)
+        .collect()
+}
+
+/// Narrow listed workflows down to the given IDs, returning the IDs that didn't match
+pub fn select_workflows(
+    workflows: Vec<Workflow>,
+    ids: Option<&[String]>,
+) -> (Vec<Workflow>, Vec<String>) {
+    let Some(ids) = ids else {
+        return (workflows, Vec::new());
+    };
+
+    let mut selected = Vec::new();
+    let mut missing = Vec::new();
+    for id in ids {
+        match workflows.iter().find(|w| &w.id == id) {
+            Some(workflow) => selected.push(workflow.clone()),
+            None => missing.push(id.clone()),
+        }
+    }
+    (selected, missing)
+}
+
+/// Apply an operation to workflows with at most `concurrency` requests in flight.
+///
+/// Results are returned in the order of `workflows`; failures don't stop the
+/// remaining workflows.
+pub async fn run_bulk(
+    client: &N8nClient,
+    operation: BulkOperation,
+    workflows: Vec<Workflow>,
+    concurrency: usize,
+) -> Vec<BulkResult> {
+    let client = Arc::new(client.clone());
+    let operation = Arc::new(operation);
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+    let mut results: Vec<BulkResult> = workflows
+        .iter()
+        .map(|w| BulkResult::new(&w.id, &w.name, BulkStatus::Skipped))
+        .collect();
+    let mut tasks = JoinSet::new();
+
+    for (index, workflow) in workflows.into_iter().enumerate() {
+        if operation.is_noop(&workflow) {
+            continue;
+        }
+
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        let client = client.clone();
+        let operation = operation.clone();
+        tasks.spawn(async move {
+            let outcome = operation.apply(&client, &workflow).await;
+            drop(permit);
+            (index, outcome)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (index, outcome) = match joined {
+            Ok(done) => done,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        };
+        let result = &mut results[index];
+        match outcome {
+            Ok(()) => result.status = BulkStatus::Done,
+            Err(e) => {
+                result.status = BulkStatus::Failed;
+                result.error = Some(e.to_string());
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WorkflowTag;
+
+    fn workflow(id: &str, active: bool, tags: &[&str]) -> Workflow {
+        Workflow {
+            id: id.to_string(),
+            name: format!("Workflow {}", id),
+            active,
+            created_at: String::new(),
+            updated_at: String::new(),
+            tags: tags
+                .iter()
+                .map(|t| WorkflowTag {
+                    id: t.to_string(),
+                    name: t.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn tag(id: &str) -> Tag {
+        Tag {
+            id: id.to_string(),
+            name: id.to_string(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        let input = "abc\tFirst workflow\n\n# comment\n  def  \nabc\n";
+        assert_eq!(parse_ids(input), vec!["abc", "def"]);
+    }
+
+    #[test]
+    fn test_select_workflows() {
+        let listed = vec![workflow("a", true, &[]), workflow("b", false, &[])];
+
+        let (all, missing) = select_workflows(listed.clone(), None);
+        assert_eq!(all.len(), 2);
+        assert!(missing.is_empty());
+
+        let ids = vec!["b".to_string(), "zz".to_string()];
+        let (selected, missing) = select_workflows(listed, Some(&ids));
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].id, "b");
+        assert_eq!(missing, vec!["zz"]);
+    }
+
+    #[test]
+    fn test_noop_detection() {
+        let wf = workflow("a", true, &["prod"]);
+        assert!(BulkOperation::Activate.is_noop(&wf));
+        assert!(!BulkOperation::Deactivate.is_noop(&wf));
+        assert!(BulkOperation::AddTags(vec![tag("prod")]).is_noop(&wf));
+        assert!(!BulkOperation::AddTags(vec![tag("prod"), tag("x")]).is_noop(&wf));
+        assert!(BulkOperation::RemoveTags(vec![tag("x")]).is_noop(&wf));
+    }
+
+    #[test]
+    fn test_tag_ids() {
+        let wf = workflow("a", true, &["prod", "team"]);
+        assert_eq!(
+            BulkOperation::AddTags(vec![tag("team"), tag("new")]).tag_ids(&wf),
+            vec!["prod", "team", "new"]
+        );
+        assert_eq!(
+            BulkOperation::RemoveTags(vec![tag("prod")]).tag_ids(&wf),
+            vec!["team"]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+
+/// Outcome of a bulk action on one workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkStatus {
+    Done,
+    Skipped,
+    Failed,
+}
+
+impl std::fmt::Display for BulkStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Done => write!(f, "done"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// Per-workflow line of a bulk action report
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkResult {
+    pub id: String,
+    pub name: String,
+    pub status: BulkStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BulkResult {
+    pub fn new(id: &str, name: &str, status: BulkStatus) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            status,
+            error: None,
+        }
+    }
+
+    pub fn failed(id: &str, name: &str, error: String) -> Self {
+        Self {
+            error: Some(error),
+            ..Self::new(id, name, BulkStatus::Failed)
+        }
+    }
+}
+
+impl Outputable for BulkResult {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("id", "ID", |r: &Self| r.id.clone()),
+            Column::new("name", "NAME", |r: &Self| r.name.clone()).truncate(40),
+            Column::new("status", "STATUS", |r: &Self| r.status.to_string()),
+            Column::new("error", "ERROR", |r: &Self| {
+                r.error
+                    .as_deref()
+                    .and_then(|e| e.lines().next())
+                    .unwrap_or("-")
+                    .to_string()
+            })
+            .truncate(60),
+        ]
+    }
+}
@@ -1,8 +1,10 @@
 use crate::output::OutputFormat;
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand};
+use std::path::PathBuf;
 
 use super::{
-    CredentialsCommand, ExecutionsCommand, HealthCommand, TagsCommand, WorkflowsCommand,
+    ConfigCommand, CredentialsCommand, ExecutionsCommand, HealthCommand, TagsCommand,
+    WorkflowsCommand,
 };
 
 /// n8n CLI - Manage n8n workflows from the command line
@@ -26,13 +28,57 @@ pub struct Cli {
     #[arg(long, global = true, env = "N8N_API_KEY", hide_env_values = true)]
     pub api_key: Option<String>,
 
-    /// Output format
-    #[arg(long, short = 'o', global = true, default_value = "table", value_enum)]
-    pub output: OutputFormat,
+    /// Output format [default: the profile's output_format, or table]
+    #[arg(long, short = 'o', global = true, value_enum)]
+    pub output: Option<OutputFormat>,
 
-    /// Enable verbose output
-    #[arg(long, short = 'v', global = true)]
-    pub verbose: bool,
+    /// Omit the header row in table, csv, tsv and markdown output
+    #[arg(long, global = true)]
+    pub no_headers: bool,
+
+    /// Columns to show in list output, comma-separated (e.g. id,name,created)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<String>,
+
+    /// Sort list output by a column
+    #[arg(long, global = true, value_name = "COLUMN")]
+    pub sort_by: Option<String>,
+
+    /// Reverse the order of list output
+    #[arg(long, global = true)]
+    pub reverse: bool,
+
+    /// Don't truncate long values in table output
+    #[arg(long, global = true)]
+    pub wide: bool,
+
+    /// Print only the values selected by a JSONPath query (e.g. '$.nodes[*].name')
+    #[arg(long, global = true, value_name = "EXPR")]
+    pub query: Option<String>,
+
+    /// Print string query results without quotes, one value per line
+    #[arg(long, global = true, requires = "query")]
+    pub raw: bool,
+
+    /// Render each item with a Go-style template (e.g. '{{.id}}\t{{.name}}')
+    #[arg(long, global = true, value_name = "TEMPLATE", conflicts_with = "query")]
+    pub format: Option<String>,
+
+    /// Write every API request and response to cassette files in DIR (API key redacted)
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Answer API requests from cassette files in DIR instead of the network
+    #[arg(long, global = true, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+
+    /// Log every API request to stderr (-vv adds redacted bodies)
+    #[arg(long, short = 'v', global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Append a JSON line per API request to FILE
+    #[arg(long, global = true, value_name = "FILE")]
+    pub trace_file: Option<PathBuf>,
 
     /// Suppress non-essential output
     #[arg(long, short = 'q', global = true)]
@@ -59,8 +105,11 @@ pub enum Commands {
     /// Health checks
     Health(HealthCommand),
 
-    /// Show current configuration
-    Config,
+    /// Show the configuration and manage profiles
+    Config(ConfigCommand),
+
+    /// Diagnose configuration and connectivity problems
+    Doctor,
 
     /// Install Claude Code skill for n8n workflow development
     #[command(name = "install-claude-skill")]
@@ -69,4 +118,24 @@ pub enum Commands {
         #[arg(long, short = 'f')]
         force: bool,
     },
+
+    /// Serve a mock n8n API from memory (for tests and demos)
+    #[command(name = "mock-server", hide = true)]
+    MockServer {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port to listen on (0 picks a free port)
+        #[arg(long, default_value_t = 5678)]
+        port: u16,
+
+        /// JSON or YAML file with the initial workflows, executions, credentials and tags
+        #[arg(long, value_name = "FILE", conflicts_with = "empty")]
+        fixtures: Option<PathBuf>,
+
+        /// Start without the sample data
+        #[arg(long)]
+        empty: bool,
+    },
 }
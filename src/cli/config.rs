@@ -0,0 +1,137 @@
+use crate::output::OutputFormat;
+use clap::{Args, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct ConfigCommand {
+    /// Defaults to `show`
+    #[command(subcommand)]
+    pub action: Option<ConfigAction>,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Show the resolved configuration and where each value came from
+    Show,
+
+    /// Manage the profiles in the config file
+    Profiles(ProfilesCommand),
+}
+
+#[derive(Args)]
+pub struct ProfilesCommand {
+    #[command(subcommand)]
+    pub action: ProfilesAction,
+}
+
+#[derive(Subcommand)]
+pub enum ProfilesAction {
+    /// List profiles
+    List,
+
+    /// Add a profile
+    Add {
+        /// Profile name
+        name: String,
+
+        /// n8n instance base URL (without /api/v1)
+        #[arg(long)]
+        base_url: String,
+
+        #[command(flatten)]
+        options: ProfileOptions,
+
+        /// Make this the default profile
+        #[arg(long)]
+        default: bool,
+    },
+
+    /// Change settings of a profile
+    Edit {
+        /// Profile name
+        name: String,
+
+        /// n8n instance base URL (without /api/v1)
+        #[arg(long)]
+        base_url: Option<String>,
+
+        #[command(flatten)]
+        options: ProfileOptions,
+
+        /// Settings to remove, comma-separated
+        #[arg(long, value_enum, value_delimiter = ',')]
+        unset: Vec<ProfileKey>,
+    },
+
+    /// Set the default profile
+    Use {
+        /// Profile name
+        name: String,
+    },
+
+    /// Remove a profile
+    Remove {
+        /// Profile name
+        name: String,
+
+        /// Skip confirmation
+        #[arg(long, short = 'f')]
+        force: bool,
+    },
+}
+
+/// Optional profile settings shared by `add` and `edit`
+#[derive(Args)]
+pub struct ProfileOptions {
+    /// Environment variable to read the API key from
+    #[arg(long, value_name = "VAR", conflicts_with = "api_key_stdin")]
+    pub api_key_env: Option<String>,
+
+    /// Read the API key from stdin and store it in the config file
+    #[arg(long)]
+    pub api_key_stdin: bool,
+
+    /// Default output format of the profile
+    #[arg(long, value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// Proxy URL for all requests
+    #[arg(long, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// PEM file with CA certificates to trust; stored as an absolute path
+    #[arg(long, value_name = "FILE", value_parser = parse_file_path)]
+    pub ca_cert: Option<PathBuf>,
+
+    /// PEM client certificate for mutual TLS
+    #[arg(long, value_name = "FILE", value_parser = parse_file_path)]
+    pub client_cert: Option<PathBuf>,
+
+    /// PEM private key of --client-cert
+    #[arg(long, value_name = "FILE", value_parser = parse_file_path)]
+    pub client_key: Option<PathBuf>,
+
+    /// Accept any server certificate (for testing only)
+    #[arg(long)]
+    pub insecure_skip_verify: bool,
+}
+
+/// Optional profile settings that `edit --unset` can remove
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum ProfileKey {
+    ApiKey,
+    ApiKeyEnv,
+    OutputFormat,
+    Retry,
+    Proxy,
+    CaCert,
+    ClientCert,
+    ClientKey,
+    InsecureSkipVerify,
+}
+
+/// Resolve a file argument to an absolute path, so the profile works from any directory
+fn parse_file_path(s: &str) -> Result<PathBuf, String> {
+    std::fs::canonicalize(s).map_err(|e| format!("{}: {}", s, e))
+}
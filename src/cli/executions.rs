@@ -31,6 +31,29 @@ pub enum ExecutionsAction {
         cursor: Option<String>,
     },
 
+    /// Show recent executions, optionally following new ones as they appear
+    Logs {
+        /// Filter by workflow ID
+        #[arg(long, short = 'w')]
+        workflow_id: Option<String>,
+
+        /// Filter by status (running, success, error, waiting, canceled)
+        #[arg(long, short = 's')]
+        status: Option<String>,
+
+        /// Number of recent executions to show first
+        #[arg(long, default_value = "20")]
+        limit: u32,
+
+        /// Keep polling and print new executions until interrupted
+        #[arg(long, short = 'f')]
+        follow: bool,
+
+        /// Seconds between polls in follow mode
+        #[arg(long, default_value = "2", value_name = "SECS")]
+        interval: u64,
+    },
+
     /// Get execution details
     Get {
         /// Execution ID
@@ -41,6 +64,16 @@ pub enum ExecutionsAction {
         include_data: bool,
     },
 
+    /// Show a per-node report of an execution
+    Inspect {
+        /// Execution ID
+        id: String,
+
+        /// Dump the output items of this node instead
+        #[arg(long, short = 'n')]
+        node: Option<String>,
+    },
+
     /// Delete an execution
     Delete {
         /// Execution ID
@@ -58,10 +91,14 @@ pub enum ExecutionsAction {
         /// Workflow ID to execute
         workflow_id: String,
 
-        /// Wait for execution to complete
+        /// Wait for execution to complete (exit code reflects the final status)
         #[arg(long)]
         wait: bool,
 
+        /// Give up waiting after this many seconds
+        #[arg(long, requires = "wait", value_name = "SECS")]
+        timeout: Option<u64>,
+
         /// Input data as JSON string
         #[arg(long, short = 'd')]
         data: Option<String>,
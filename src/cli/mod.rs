@@ -1,4 +1,5 @@
 mod app;
+mod config;
 mod credentials;
 mod executions;
 mod health;
@@ -6,11 +7,14 @@ mod tags;
 mod workflows;
 
 pub use app::{Cli, Commands};
+pub use config::{
+    ConfigAction, ConfigCommand, ProfileKey, ProfileOptions, ProfilesAction, ProfilesCommand,
+};
 pub use credentials::{CredentialsCommand, CredentialsAction};
 pub use executions::{ExecutionsCommand, ExecutionsAction};
 pub use health::{HealthCommand, HealthAction};
 pub use tags::{TagsCommand, TagsAction};
 pub use workflows::{
-    ConnectionsAction, ConnectionsCommand, NodesAction, NodesCommand, WorkflowsAction,
-    WorkflowsCommand,
+    BulkAction, BulkCommand, BulkSelection, ConnectionsAction, ConnectionsCommand, NodesAction,
+    NodesCommand, PromoteArgs, WorkflowsAction, WorkflowsCommand,
 };
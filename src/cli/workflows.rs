@@ -51,6 +51,10 @@ pub enum WorkflowsAction {
         /// Activate workflow after creation
         #[arg(long)]
         activate: bool,
+
+        /// TOML file mapping credential IDs or names to those to use
+        #[arg(long, value_name = "FILE")]
+        credential_map: Option<PathBuf>,
     },
 
     /// Update an existing workflow
@@ -61,6 +65,14 @@ pub enum WorkflowsAction {
         /// Path to workflow JSON file (use - for stdin)
         #[arg(value_name = "FILE")]
         file: PathBuf,
+
+        /// Overwrite even if the file's versionId is outdated or missing
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
+
+        /// TOML file mapping credential IDs or names to those to use
+        #[arg(long, value_name = "FILE")]
+        credential_map: Option<PathBuf>,
     },
 
     /// Delete a workflow
@@ -85,6 +97,9 @@ pub enum WorkflowsAction {
         id: String,
     },
 
+    /// Apply an action to many workflows at once
+    Bulk(BulkCommand),
+
     /// Manage workflow nodes
     Nodes(NodesCommand),
 
@@ -103,6 +118,10 @@ pub enum WorkflowsAction {
         /// Skip validation before upload
         #[arg(long)]
         no_validate: bool,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
     },
 
     /// Compare workflows
@@ -137,6 +156,43 @@ pub enum WorkflowsAction {
         pretty: bool,
     },
 
+    /// Pull workflows into a directory (one normalized JSON file per workflow)
+    Pull {
+        /// Target directory
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+
+        /// Only pull workflows with these tags (comma-separated)
+        #[arg(long, short = 't', value_delimiter = ',')]
+        tags: Option<Vec<String>>,
+
+        /// Remove tracked files whose workflow no longer exists on the server
+        #[arg(long)]
+        prune: bool,
+    },
+
+    /// Push a workflow directory to the server
+    Push {
+        /// Source directory
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+
+        /// Limit deletions to workflows with these tags (comma-separated)
+        #[arg(long, short = 't', value_delimiter = ',')]
+        tags: Option<Vec<String>>,
+
+        /// Show the plan without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Delete server workflows that have no local file
+        #[arg(long)]
+        delete: bool,
+    },
+
+    /// Copy a workflow to the instance of another profile (e.g. dev -> prod)
+    Promote(PromoteArgs),
+
     /// Clone/duplicate a workflow
     Clone {
         /// Source workflow ID
@@ -166,6 +222,28 @@ pub enum WorkflowsAction {
         warnings: bool,
     },
 
+    /// Lint workflow structure with configurable rules
+    Lint {
+        /// Workflow ID (mutually exclusive with --file)
+        #[arg(
+            conflicts_with = "file",
+            required_unless_present_any = ["file", "list_rules"]
+        )]
+        id: Option<String>,
+
+        /// Local file to lint
+        #[arg(long, conflicts_with = "id")]
+        file: Option<PathBuf>,
+
+        /// Lint config file (default: nearest .n8nlint.toml)
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+
+        /// List rules and their effective severities instead of linting
+        #[arg(long)]
+        list_rules: bool,
+    },
+
     /// Run/trigger a workflow (webhook workflows only)
     Run {
         /// Workflow ID
@@ -240,6 +318,10 @@ pub enum NodesAction {
         /// Disable the node
         #[arg(long)]
         disabled: bool,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
     },
 
     /// Remove a node from a workflow
@@ -253,6 +335,10 @@ pub enum NodesAction {
         /// Skip confirmation prompt
         #[arg(long, short = 'f')]
         force: bool,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long)]
+        overwrite: bool,
     },
 
     /// Update a node's configuration
@@ -282,6 +368,26 @@ pub enum NodesAction {
         /// Enable/disable the node
         #[arg(long)]
         disabled: Option<bool>,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
+    },
+
+    /// Rename a node and update every reference to it
+    Rename {
+        /// Workflow ID
+        workflow_id: String,
+
+        /// Node ID or current name
+        node_id: String,
+
+        /// New node name
+        new_name: String,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
     },
 
     /// Move a node to a new position
@@ -295,6 +401,10 @@ pub enum NodesAction {
         /// New position as "x,y"
         #[arg(value_parser = parse_position)]
         position: (i32, i32),
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
     },
 }
 
@@ -344,6 +454,10 @@ pub enum ConnectionsAction {
         /// Connection type (default: "main")
         #[arg(long, default_value = "main")]
         r#type: String,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long, visible_alias = "force")]
+        overwrite: bool,
     },
 
     /// Remove a connection
@@ -362,9 +476,134 @@ pub enum ConnectionsAction {
         /// Skip confirmation prompt
         #[arg(long, short = 'f')]
         force: bool,
+
+        /// Overwrite concurrent changes made since the workflow was read
+        #[arg(long)]
+        overwrite: bool,
     },
 }
 
+#[derive(Args, Clone)]
+pub struct PromoteArgs {
+    /// Source workflow ID
+    pub id: String,
+
+    /// Profile to promote from (defaults to the active profile)
+    #[arg(long, value_name = "PROFILE")]
+    pub from: Option<String>,
+
+    /// Profile to promote to
+    #[arg(long, value_name = "PROFILE")]
+    pub to: String,
+
+    /// Target workflow to update (instead of matching by earlier promotion or name)
+    #[arg(long, value_name = "ID")]
+    pub target_id: Option<String>,
+
+    /// Show the changes without applying them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Show the full diff (including parameter changes)
+    #[arg(long)]
+    pub full: bool,
+
+    /// Promote even if some credentials don't exist on the target
+    #[arg(long)]
+    pub allow_unresolved: bool,
+
+    /// TOML file mapping source credential IDs or names to target ones
+    #[arg(long, value_name = "FILE")]
+    pub credential_map: Option<PathBuf>,
+
+    /// Skip confirmation prompt
+    #[arg(long, short = 'f')]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct BulkCommand {
+    #[command(subcommand)]
+    pub action: BulkAction,
+}
+
+#[derive(Subcommand)]
+pub enum BulkAction {
+    /// Activate matching workflows
+    Activate {
+        #[command(flatten)]
+        selection: BulkSelection,
+    },
+
+    /// Deactivate matching workflows
+    Deactivate {
+        #[command(flatten)]
+        selection: BulkSelection,
+    },
+
+    /// Delete matching workflows
+    Delete {
+        #[command(flatten)]
+        selection: BulkSelection,
+    },
+
+    /// Add tags to matching workflows (keeps existing tags)
+    Tag {
+        /// Tag names or IDs to add (comma-separated)
+        #[arg(value_name = "TAGS", value_delimiter = ',', required = true)]
+        add: Vec<String>,
+
+        #[command(flatten)]
+        selection: BulkSelection,
+    },
+
+    /// Remove tags from matching workflows
+    Untag {
+        /// Tag names or IDs to remove (comma-separated)
+        #[arg(value_name = "TAGS", value_delimiter = ',', required = true)]
+        remove: Vec<String>,
+
+        #[command(flatten)]
+        selection: BulkSelection,
+    },
+}
+
+/// Which workflows a bulk action applies to, and how it runs
+#[derive(Args)]
+pub struct BulkSelection {
+    /// Only workflows with this active status
+    #[arg(long, short = 'a')]
+    pub active: Option<bool>,
+
+    /// Only workflows with these tags (comma-separated)
+    #[arg(long, short = 't', value_delimiter = ',')]
+    pub tags: Option<Vec<String>>,
+
+    /// Only workflows whose name contains this text
+    #[arg(long, short = 'n')]
+    pub name: Option<String>,
+
+    /// Read workflow IDs from stdin (one per line, first column)
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Apply to every workflow when no filter is given
+    #[arg(long, required_unless_present_any = ["active", "tags", "name", "stdin"])]
+    pub all: bool,
+
+    /// Number of requests to run at once
+    #[arg(long, short = 'j', default_value = "4", value_parser = clap::value_parser!(u16).range(1..=64))]
+    pub concurrency: u16,
+
+    /// Show the affected workflows without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip confirmation prompt
+    #[arg(long, short = 'f')]
+    pub force: bool,
+}
+
 /// Parse position string "x,y" into (i32, i32)
 fn parse_position(s: &str) -> Result<(i32, i32), String> {
     let parts: Vec<&str> = s.split(',').collect();
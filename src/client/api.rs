@@ -1,22 +1,32 @@
+use crate::client::cassette::{Cassette, RecordedRequest};
+use crate::client::retry::{is_retryable_error, is_retryable_status, RetryPolicy};
+use crate::client::trace::Tracer;
 use crate::config::Config;
 use crate::error::{ApiErrorResponse, N8nError, Result};
-use reqwest::{header, Client, Response, StatusCode};
+use reqwest::{header, Client, Request, RequestBuilder, Response, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
 
 /// The core n8n API client
+#[derive(Clone)]
 pub struct N8nClient {
     http: Client,
     base_url: String,
+    api_key: header::HeaderValue,
+    retry: RetryPolicy,
+    cassette: Option<Arc<Cassette>>,
+    tracer: Option<Arc<Tracer>>,
 }
 
 impl N8nClient {
     /// Creates a new client from configuration
     pub fn new(config: &Config) -> Result<Self> {
+        let mut api_key =
+            header::HeaderValue::from_str(&config.api_key).map_err(|_| N8nError::InvalidApiKey)?;
+        api_key.set_sensitive(true);
+
         let mut headers = header::HeaderMap::new();
-        headers.insert(
-            "X-N8N-API-KEY",
-            header::HeaderValue::from_str(&config.api_key).map_err(|_| N8nError::InvalidApiKey)?,
-        );
         headers.insert(
             header::ACCEPT,
             header::HeaderValue::from_static("application/json"),
@@ -26,15 +36,27 @@ impl N8nClient {
             header::HeaderValue::from_static("application/json"),
         );
 
-        let http = Client::builder()
+        let builder = Client::builder()
             .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(config.timeout_secs))
+            .timeout(std::time::Duration::from_secs(config.timeout_secs));
+        let http = config
+            .transport
+            .apply(builder)?
             .build()
             .map_err(N8nError::HttpClient)?;
 
         Ok(Self {
             http,
             base_url: config.base_url.trim_end_matches('/').to_string(),
+            api_key,
+            retry: config.retry.clone(),
+            cassette: config
+                .cassette
+                .as_ref()
+                .map(Cassette::open)
+                .transpose()?
+                .map(Arc::new),
+            tracer: Tracer::open(config)?.map(Arc::new),
         })
     }
 
@@ -46,12 +68,7 @@ impl N8nClient {
     /// GET request with deserialization
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.url(path);
-        let response = self
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.get(&url), &url).await?;
 
         self.handle_response(response).await
     }
@@ -63,13 +80,7 @@ impl N8nClient {
         Q: Serialize + ?Sized,
     {
         let url = self.url(path);
-        let response = self
-            .http
-            .get(&url)
-            .query(query)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.get(&url).query(query), &url).await?;
 
         self.handle_response(response).await
     }
@@ -81,13 +92,7 @@ impl N8nClient {
         B: Serialize + ?Sized,
     {
         let url = self.url(path);
-        let response = self
-            .http
-            .post(&url)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.post(&url).json(body), &url).await?;
 
         self.handle_response(response).await
     }
@@ -95,12 +100,7 @@ impl N8nClient {
     /// POST request without body (for actions like activate)
     pub async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.url(path);
-        let response = self
-            .http
-            .post(&url)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.post(&url), &url).await?;
 
         self.handle_response(response).await
     }
@@ -112,13 +112,7 @@ impl N8nClient {
         B: Serialize + ?Sized,
     {
         let url = self.url(path);
-        let response = self
-            .http
-            .put(&url)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.put(&url).json(body), &url).await?;
 
         self.handle_response(response).await
     }
@@ -126,12 +120,7 @@ impl N8nClient {
     /// DELETE request
     pub async fn delete(&self, path: &str) -> Result<()> {
         let url = self.url(path);
-        let response = self
-            .http
-            .delete(&url)
-            .send()
-            .await
-            .map_err(|e| self.map_request_error(e, &url))?;
+        let response = self.send(self.http.delete(&url), &url).await?;
 
         let status = response.status();
         if status.is_success() || status == StatusCode::NO_CONTENT {
@@ -141,12 +130,112 @@ impl N8nClient {
         }
     }
 
+    /// Builds a URL for a path at the instance root (health checks, webhooks)
+    pub(crate) fn root_url(&self, path: &str) -> String {
+        if path.starts_with('/') {
+            format!("{}{}", self.base_url, path)
+        } else {
+            format!("{}/{}", self.base_url, path)
+        }
+    }
+
+    /// Send an API request with the API key
+    pub(crate) async fn send(&self, request: RequestBuilder, url: &str) -> Result<Response> {
+        // Set per request (not as a default header) so recordings show it redacted
+        let request = request.header("X-N8N-API-KEY", self.api_key.clone());
+        self.send_public(request, url).await
+    }
+
+    /// Send a request without the API key, tracing it if enabled
+    ///
+    /// For endpoints outside /api/v1 that must not see the key, like health
+    /// checks. Retries, tracing and cassettes work as for API requests.
+    pub(crate) async fn send_public(&self, request: RequestBuilder, url: &str) -> Result<Response> {
+        self.send_traced(request, url, true).await
+    }
+
+    /// Same as `send_public`, but never retried
+    ///
+    /// For requests that start workflows, like webhooks: after a timeout the
+    /// workflow may already be running, and a retry would start it again.
+    pub(crate) async fn send_public_once(
+        &self,
+        request: RequestBuilder,
+        url: &str,
+    ) -> Result<Response> {
+        self.send_traced(request, url, false).await
+    }
+
+    async fn send_traced(
+        &self,
+        request: RequestBuilder,
+        url: &str,
+        retry: bool,
+    ) -> Result<Response> {
+        let request = request.build().map_err(N8nError::Request)?;
+
+        let Some(tracer) = self.tracer.as_deref() else {
+            return self.dispatch(request, url, retry).await;
+        };
+        let event = tracer.start(&request);
+        let started = Instant::now();
+        let result = self.dispatch(request, url, retry).await;
+        tracer.finish(event, started.elapsed(), result).await
+    }
+
+    /// Send a request over the network, or through the cassette if there is one
+    async fn dispatch(&self, request: Request, url: &str, retry: bool) -> Result<Response> {
+        match self.cassette.as_deref() {
+            None => self.execute(request, url, retry).await,
+            Some(cassette @ Cassette::Replay(_)) => cassette.replay(&self.base_url, &request),
+            Some(cassette @ Cassette::Record(_)) => {
+                let recorded = RecordedRequest::capture(&self.base_url, &request);
+                let response = self.execute(request, url, retry).await?;
+                cassette.record(&self.base_url, recorded, response).await
+            }
+        }
+    }
+
+    /// Execute a request, retrying transient failures according to the retry policy
+    /// unless `retry` is false
+    async fn execute(&self, request: Request, url: &str, retry: bool) -> Result<Response> {
+        let retry_allowed = retry && self.retry.allows_method(request.method());
+        let max_attempts = if retry_allowed {
+            self.retry.max_attempts.max(1)
+        } else {
+            1
+        };
+
+        let mut attempt = 1;
+        let mut pending = Some(request);
+        loop {
+            // Keep a copy for the next attempt; bodies are always buffered here
+            let current = pending.take().expect("request consumed");
+            if attempt < max_attempts {
+                pending = current.try_clone();
+            }
+            let can_retry = pending.is_some();
+
+            let delay = match self.http.execute(current).await {
+                Ok(response) if can_retry && is_retryable_status(response.status()) => {
+                    self.retry.delay_for(attempt, Some(&response))
+                }
+                Ok(response) => return Ok(response),
+                Err(e) if can_retry && is_retryable_error(&e) => self.retry.delay_for(attempt, None),
+                Err(e) => return Err(self.map_request_error(e, url)),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     /// Map request errors to N8nError
     fn map_request_error(&self, error: reqwest::Error, url: &str) -> N8nError {
         if error.is_connect() {
             N8nError::ConnectionFailed {
                 url: url.to_string(),
-                message: error.to_string(),
+                message: error_causes(&error),
             }
         } else if error.is_timeout() {
             N8nError::ConnectionFailed {
@@ -170,7 +259,7 @@ impl N8nClient {
     }
 
     /// Extract API error from response
-    async fn extract_api_error(&self, response: Response) -> N8nError {
+    pub(crate) async fn extract_api_error(&self, response: Response) -> N8nError {
         let status = response.status();
 
         // Try to get response body as text first
@@ -180,14 +269,14 @@ impl N8nClient {
         let error_body: ApiErrorResponse = serde_json::from_str(&body_text)
             .unwrap_or_else(|_| {
                 // If the response is JSON with a "message" field
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body_text) {
-                    if let Some(msg) = json.get("message").and_then(|m| m.as_str()) {
-                        return ApiErrorResponse {
-                            code: status.as_u16(),
-                            message: msg.to_string(),
-                            hint: None,
-                        };
-                    }
+                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&body_text)
+                    && let Some(msg) = json.get("message").and_then(|m| m.as_str())
+                {
+                    return ApiErrorResponse {
+                        code: status.as_u16(),
+                        message: msg.to_string(),
+                        hint: None,
+                    };
                 }
                 // Use raw text as message if not empty
                 if !body_text.is_empty() && body_text.len() < 500 {
@@ -208,4 +297,129 @@ impl N8nClient {
     pub fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    /// Underlying HTTP client, for requests outside /api/v1
+    pub(crate) fn http(&self) -> &Client {
+        &self.http
+    }
+}
+
+/// Causes of a request error (`tcp connect error: Connection refused ...`)
+///
+/// reqwest's own message only says that sending failed; the reason, like a
+/// refused connection or an untrusted certificate, is in the source chain.
+fn error_causes(error: &reqwest::Error) -> String {
+    let mut causes = Vec::new();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    if causes.is_empty() {
+        error.to_string()
+    } else {
+        causes.join(": ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::endpoints::webhooks::WebhookMode;
+    use reqwest::Method;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Start a local HTTP stub that answers with `statuses` in order (last one repeats)
+    async fn stub_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let status = statuses[n.min(statuses.len() - 1)];
+
+                let mut buf = vec![0u8; 8192];
+                let _ = socket.read(&mut buf).await;
+
+                let body = r#"{"ok":true}"#;
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nRetry-After: 0\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{}", addr), hits)
+    }
+
+    fn client_for(base_url: String, retry: RetryPolicy) -> N8nClient {
+        let config = Config {
+            base_url,
+            api_key: "test-key".to_string(),
+            retry,
+            ..Config::default()
+        };
+        N8nClient::new(&config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_transient_status() {
+        let (url, hits) = stub_server(vec![503, 429, 200]).await;
+        let client = client_for(url, RetryPolicy::default());
+
+        let value: serde_json::Value = client.get("/workflows").await.unwrap();
+        assert_eq!(value["ok"], true);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_at_max_attempts() {
+        let (url, hits) = stub_server(vec![502]).await;
+        let client = client_for(url, RetryPolicy::default());
+
+        let err = client.get::<serde_json::Value>("/workflows").await.unwrap_err();
+        assert!(matches!(err, N8nError::Api(ref e) if e.code == 502));
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_not_retried_unless_enabled() {
+        let (url, hits) = stub_server(vec![503, 200]).await;
+        let client = client_for(url.clone(), RetryPolicy::default());
+        assert!(client.post_empty::<serde_json::Value>("/x").await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        let (url, hits) = stub_server(vec![503, 200]).await;
+        let policy = RetryPolicy {
+            retry_post: true,
+            ..RetryPolicy::default()
+        };
+        let client = client_for(url, policy);
+        assert!(client.post_empty::<serde_json::Value>("/x").await.is_ok());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_never_retried() {
+        let (url, hits) = stub_server(vec![503, 200]).await;
+        let client = client_for(url, RetryPolicy::default());
+
+        let err = client
+            .trigger_webhook("orders", WebhookMode::Production, Method::GET, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, N8nError::Api(ref e) if e.code == 503));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,397 @@
+use crate::client::trace::redact_credential_data;
+use crate::error::{N8nError, Result};
+use reqwest::header::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE, TRANSFER_ENCODING};
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Headers whose values are never written to cassettes
+const REDACTED_HEADERS: &[&str] = &["x-n8n-api-key", "authorization", "cookie", "set-cookie"];
+
+/// Placeholder for redacted header values
+const REDACTED: &str = "<redacted>";
+
+/// Number of the next cassette file, shared by all clients of the process
+static NEXT_FILE: AtomicUsize = AtomicUsize::new(1);
+
+/// Where API traffic is recorded to or replayed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Write every request and response to this directory
+    Record(PathBuf),
+    /// Answer requests from the cassettes in this directory
+    Replay(PathBuf),
+}
+
+/// One request and the response it got, as stored in a cassette file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Base URL of the instance the request was sent to
+    pub instance: String,
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path and query below the base URL (e.g. `/api/v1/workflows?limit=100`)
+    pub path: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// JSON body, or the raw text for other content types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// Recorder or player behind an `N8nClient`
+#[derive(Debug)]
+pub(crate) enum Cassette {
+    Record(PathBuf),
+    /// Recorded interactions, with whether each was replayed already
+    Replay(Mutex<Vec<(Interaction, bool)>>),
+}
+
+impl Cassette {
+    /// Create the recording directory or load the cassettes to replay
+    pub(crate) fn open(mode: &CassetteMode) -> Result<Self> {
+        match mode {
+            CassetteMode::Record(dir) => {
+                fs::create_dir_all(dir).map_err(|e| N8nError::FileWrite {
+                    path: dir.display().to_string(),
+                    source: e,
+                })?;
+                // Continue after earlier recordings in the same directory
+                let last = cassette_files(dir)?
+                    .iter()
+                    .filter_map(|path| path.file_name()?.to_str()?.split('-').next()?.parse().ok())
+                    .max()
+                    .unwrap_or(0);
+                NEXT_FILE.fetch_max(last + 1, Ordering::SeqCst);
+                Ok(Self::Record(dir.clone()))
+            }
+            CassetteMode::Replay(dir) => {
+                let mut interactions = Vec::new();
+                for path in cassette_files(dir)? {
+                    let content = fs::read_to_string(&path).map_err(|e| N8nError::FileRead {
+                        path: path.display().to_string(),
+                        source: e,
+                    })?;
+                    let interaction: Interaction =
+                        serde_json::from_str(&content).map_err(|e| N8nError::InvalidFile {
+                            path: path.display().to_string(),
+                            source: e,
+                        })?;
+                    interactions.push((interaction, false));
+                }
+                Ok(Self::Replay(Mutex::new(interactions)))
+            }
+        }
+    }
+
+    /// Answer a request from the recorded interactions
+    ///
+    /// The first unused interaction with the same method and path wins,
+    /// preferring those recorded against the same instance.
+    pub(crate) fn replay(&self, base_url: &str, request: &Request) -> Result<Response> {
+        let Self::Replay(interactions) = self else {
+            unreachable!("replay on a recording cassette");
+        };
+        let method = request.method().as_str();
+        let path = relative_path(base_url, request);
+
+        let mut interactions = interactions.lock().unwrap_or_else(|e| e.into_inner());
+        let candidates: Vec<usize> = interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, (i, used))| !used && i.request.method == method && i.request.path == path)
+            .map(|(index, _)| index)
+            .collect();
+        let index = candidates
+            .iter()
+            .find(|&&index| interactions[index].0.instance == base_url)
+            .or(candidates.first())
+            .copied()
+            .ok_or_else(|| N8nError::CassetteMiss {
+                method: method.to_string(),
+                path: path.clone(),
+            })?;
+
+        interactions[index].1 = true;
+        Ok(to_response(&interactions[index].0.response))
+    }
+
+    /// Write an interaction to the next cassette file and hand back the response
+    pub(crate) async fn record(
+        &self,
+        base_url: &str,
+        request: RecordedRequest,
+        response: Response,
+    ) -> Result<Response> {
+        let Self::Record(dir) = self else {
+            unreachable!("record on a replaying cassette");
+        };
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await.map_err(N8nError::Request)?;
+
+        let response = RecordedResponse {
+            status,
+            headers: redacted(&headers),
+            body: body_value(&headers, &bytes),
+        };
+        // The command still gets the secrets, only the file goes without them
+        let interaction = Interaction {
+            instance: base_url.to_string(),
+            response: RecordedResponse {
+                body: response
+                    .body
+                    .clone()
+                    .map(|body| redact_credential_data(body, &request.path)),
+                ..response.clone()
+            },
+            request,
+        };
+
+        let number = NEXT_FILE.fetch_add(1, Ordering::SeqCst);
+        let path = dir.join(format!(
+            "{:04}-{}-{}.json",
+            number,
+            interaction.request.method,
+            slug(&interaction.request.path)
+        ));
+        let content = serde_json::to_string_pretty(&interaction).map_err(N8nError::Serialize)?;
+        fs::write(&path, format!("{}\n", content)).map_err(|e| N8nError::FileWrite {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        Ok(to_response(&response))
+    }
+}
+
+impl RecordedRequest {
+    /// Snapshot a request before it's sent, without its secrets
+    pub(crate) fn capture(base_url: &str, request: &Request) -> Self {
+        let path = relative_path(base_url, request);
+        Self {
+            method: request.method().to_string(),
+            headers: redacted(request.headers()),
+            body: request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .and_then(|bytes| body_value(request.headers(), bytes))
+                .map(|body| redact_credential_data(body, &path)),
+            path,
+        }
+    }
+}
+
+/// Cassette files of a directory in recording order
+fn cassette_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| N8nError::FileRead {
+        path: dir.display().to_string(),
+        source: e,
+    })?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn relative_path(base_url: &str, request: &Request) -> String {
+    let url = request.url().as_str();
+    url.strip_prefix(base_url).unwrap_or(url).to_string()
+}
+
+fn redacted(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Body as JSON if it parses, else as text
+pub(super) fn body_value(headers: &HeaderMap, bytes: &[u8]) -> Option<Value> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let json = is_json(headers).then(|| serde_json::from_slice(bytes).ok());
+    Some(
+        json.flatten()
+            .unwrap_or_else(|| Value::String(String::from_utf8_lossy(bytes).into_owned())),
+    )
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("json"))
+}
+
+fn to_response(recorded: &RecordedResponse) -> Response {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        if name != CONTENT_LENGTH.as_str() && name != TRANSFER_ENCODING.as_str() {
+            builder = builder.header(name, value);
+        }
+    }
+    let body = match &recorded.body {
+        None => Vec::new(),
+        Some(Value::String(text)) if !recorded.is_json() => text.clone().into_bytes(),
+        Some(value) => value.to_string().into_bytes(),
+    };
+    builder
+        .body(body)
+        .map(Response::from)
+        .unwrap_or_else(|_| Response::from(http::Response::new(Vec::new())))
+}
+
+impl RecordedResponse {
+    fn is_json(&self) -> bool {
+        self.headers
+            .get(CONTENT_TYPE.as_str())
+            .is_some_and(|v| v.contains("json"))
+    }
+}
+
+/// File name part for a request path (`/api/v1/workflows/1?x=y` -> `workflows-1`)
+fn slug(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    let path = path.strip_prefix("/api/v1").unwrap_or(path);
+    let slug: String = path
+        .trim_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .take(60)
+        .collect();
+    if slug.is_empty() {
+        "root".to_string()
+    } else {
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::N8nClient;
+    use crate::config::Config;
+    use crate::mock::{Fixtures, MockServer};
+    use crate::models::{CredentialCreate, WorkflowDefinition};
+    use serde_json::json;
+
+    fn client(base_url: String, api_key: &str, cassette: CassetteMode) -> N8nClient {
+        let config = Config {
+            base_url,
+            api_key: api_key.to_string(),
+            cassette: Some(cassette),
+            ..Config::default()
+        };
+        N8nClient::new(&config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+
+        let recorder = client(
+            server.url(),
+            server.api_key(),
+            CassetteMode::Record(dir.path().to_path_buf()),
+        );
+        let recorded = recorder.get_workflow("1").await.unwrap();
+        recorder.create_tag("new").await.unwrap();
+        assert!(recorder.get_workflow("999").await.is_err());
+        drop(server);
+
+        let files = cassette_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 3);
+        let content = fs::read_to_string(&files[0]).unwrap();
+        assert!(!content.contains("n8n-mock-api-key"));
+        assert!(content.contains(REDACTED));
+
+        // No server: every answer comes from the cassettes
+        let player = client(
+            "http://elsewhere".to_string(),
+            "",
+            CassetteMode::Replay(dir.path().to_path_buf()),
+        );
+        assert_eq!(player.get_workflow("1").await.unwrap().name, recorded.name);
+        assert_eq!(player.create_tag("new").await.unwrap().name, "new");
+        let err = player.get_workflow("999").await.unwrap_err();
+        assert_eq!(err.exit_code(), 69);
+
+        // Each interaction is replayed once
+        let err = player.get_workflow("1").await.unwrap_err();
+        assert!(matches!(err, N8nError::CassetteMiss { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_record_redacts_credential_data_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+
+        let recorder = client(
+            server.url(),
+            server.api_key(),
+            CassetteMode::Record(dir.path().to_path_buf()),
+        );
+        let credential = CredentialCreate {
+            name: "Slack".to_string(),
+            credential_type: "slackApi".to_string(),
+            data: json!({ "accessToken": "xoxb-secret" }),
+        };
+        let created = recorder.create_credential(&credential).await.unwrap();
+        assert_eq!(created.name, "Slack");
+
+        let files = cassette_files(dir.path()).unwrap();
+        let content = fs::read_to_string(&files[0]).unwrap();
+        assert!(!content.contains("xoxb-secret"));
+        let interaction: Interaction = serde_json::from_str(&content).unwrap();
+        assert_eq!(interaction.request.body.unwrap()["data"], REDACTED);
+
+        // Other bodies are recorded as sent, so replay reproduces them
+        let parameters = json!({ "maxTokens": 256, "token": "{{ $json.token }}" });
+        let workflow = WorkflowDefinition {
+            name: "Chat".to_string(),
+            nodes: vec![json!({ "name": "Model", "parameters": parameters })],
+            connections: json!({}),
+            settings: json!({}),
+        };
+        recorder.create_workflow(&workflow).await.unwrap();
+
+        let files = cassette_files(dir.path()).unwrap();
+        let content = fs::read_to_string(&files[1]).unwrap();
+        let interaction: Interaction = serde_json::from_str(&content).unwrap();
+        let request = interaction.request.body.unwrap();
+        assert_eq!(request["nodes"][0]["parameters"], parameters);
+        let response = interaction.response.body.unwrap();
+        assert_eq!(response["nodes"][0]["parameters"], parameters);
+    }
+}
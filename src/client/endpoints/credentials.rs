@@ -23,6 +23,28 @@ impl N8nClient {
         self.get_with_query("/credentials", params).await
     }
 
+    /// List all credentials (auto-paginate)
+    pub async fn list_all_credentials(
+        &self,
+        mut params: CredentialListParams,
+    ) -> Result<Vec<Credential>> {
+        let mut all_credentials = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            params.cursor = cursor;
+            let response = self.list_credentials(&params).await?;
+            all_credentials.extend(response.data);
+
+            match response.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(all_credentials)
+    }
+
     /// Get credential schema for a type
     pub async fn get_credential_schema(&self, type_name: &str) -> Result<CredentialSchema> {
         self.get(&format!("/credentials/schema/{}", type_name))
@@ -1,10 +1,18 @@
 use crate::client::{N8nClient, PaginatedResponse};
-use crate::error::Result;
-use crate::models::{ExecuteRequest, Execution, ExecutionDetail};
+use crate::error::{N8nError, Result};
+use crate::models::{ExecuteRequest, Execution, ExecutionDetail, ExecutionStatus};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// First delay between polls while waiting for an execution
+const POLL_INITIAL: Duration = Duration::from_millis(500);
+
+/// Longest delay between polls while waiting for an execution
+const POLL_MAX: Duration = Duration::from_secs(5);
 
 /// Query parameters for listing executions
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ExecutionListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
@@ -60,4 +68,331 @@ impl N8nClient {
         self.post(&format!("/workflows/{}/execute", workflow_id), &request)
             .await
     }
+
+    /// Poll an execution until it finishes
+    ///
+    /// Polls with backoff (0.5s doubling up to 5s) and calls `on_poll` with every
+    /// fetched state. Fails with `N8nError::ExecutionTimeout` once `timeout` elapses.
+    pub async fn wait_for_execution<F>(
+        &self,
+        id: &str,
+        timeout: Option<Duration>,
+        mut on_poll: F,
+    ) -> Result<ExecutionDetail>
+    where
+        F: FnMut(&ExecutionDetail, Duration),
+    {
+        let started = Instant::now();
+        let mut delay = POLL_INITIAL;
+
+        loop {
+            let execution = self.get_execution(id, false).await?;
+            on_poll(&execution, started.elapsed());
+            if execution.is_done() {
+                return Ok(execution);
+            }
+
+            if let Some(limit) = timeout {
+                let remaining = limit.saturating_sub(started.elapsed());
+                if remaining.is_zero() {
+                    return Err(N8nError::ExecutionTimeout {
+                        id: id.to_string(),
+                        secs: limit.as_secs(),
+                    });
+                }
+                delay = delay.min(remaining);
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(POLL_MAX);
+        }
+    }
+}
+
+/// Whether a tail event is a new execution or a status change of a known one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailEventKind {
+    New,
+    Updated,
+}
+
+/// Execution reported by `ExecutionTail::poll`
+#[derive(Debug, Clone)]
+pub struct TailEvent {
+    pub kind: TailEventKind,
+    pub execution: Execution,
+}
+
+/// Incremental view of the execution list, used by `executions logs --follow`
+///
+/// Tracks the newest execution ID seen so far as a high-water mark and walks
+/// the cursor only until it reaches already-seen executions. Executions that
+/// were still running or waiting are re-fetched until they finish.
+pub struct ExecutionTail {
+    params: ExecutionListParams,
+    high_water: Option<u64>,
+    /// Reported IDs that aren't numbers (numeric ones are covered by `high_water`)
+    seen: HashSet<String>,
+    pending: HashMap<String, ExecutionStatus>,
+}
+
+impl ExecutionTail {
+    /// Start a tail with the given filters (`cursor` is ignored)
+    pub fn new(params: ExecutionListParams) -> Self {
+        Self {
+            params,
+            high_water: None,
+            seen: HashSet::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Fetch the most recent `backlog` executions, oldest first, and start tracking
+    pub async fn start(&mut self, client: &N8nClient, backlog: u32) -> Result<Vec<TailEvent>> {
+        // Always fetch at least one execution to establish the high-water mark
+        let params = ExecutionListParams {
+            limit: Some(backlog.max(1)),
+            cursor: None,
+            ..self.params.clone()
+        };
+        let response = client.list_executions(&params).await?;
+
+        let mut events = Vec::new();
+        for execution in response.data.into_iter().rev() {
+            self.track(&execution);
+            if backlog > 0 {
+                events.push(TailEvent {
+                    kind: TailEventKind::New,
+                    execution,
+                });
+            }
+        }
+        Ok(events)
+    }
+
+    /// Fetch executions that appeared or finished since the last call
+    pub async fn poll(&mut self, client: &N8nClient) -> Result<Vec<TailEvent>> {
+        let mut fresh = Vec::new();
+        let mut params = ExecutionListParams {
+            cursor: None,
+            ..self.params.clone()
+        };
+
+        // Newest first: stop at the first execution we've already reported
+        'pages: loop {
+            let response = client.list_executions(&params).await?;
+            for execution in response.data {
+                if self.seen.contains(&execution.id) || !self.is_newer(&execution.id) {
+                    break 'pages;
+                }
+                fresh.push(execution);
+            }
+            match response.next_cursor {
+                Some(next) => params.cursor = Some(next),
+                None => break,
+            }
+        }
+
+        let mut events = Vec::new();
+        for execution in fresh.into_iter().rev() {
+            self.track(&execution);
+            events.push(TailEvent {
+                kind: TailEventKind::New,
+                execution,
+            });
+        }
+
+        let pending: Vec<(String, ExecutionStatus)> = self
+            .pending
+            .iter()
+            .map(|(id, status)| (id.clone(), status.clone()))
+            .collect();
+        for (id, last_status) in pending {
+            let detail = client.get_execution(&id, false).await?;
+            if detail.is_done() {
+                self.pending.remove(&id);
+            } else {
+                self.pending.insert(id.clone(), detail.status.clone());
+            }
+            if detail.status != last_status {
+                events.push(TailEvent {
+                    kind: TailEventKind::Updated,
+                    execution: Execution {
+                        id: detail.id,
+                        workflow_id: detail.workflow_id,
+                        status: detail.status,
+                        started_at: detail.started_at,
+                        stopped_at: detail.stopped_at,
+                        finished: detail.finished,
+                        mode: detail.mode,
+                    },
+                });
+            }
+        }
+
+        Ok(events)
+    }
+
+    fn track(&mut self, execution: &Execution) {
+        match execution.id.parse::<u64>() {
+            Ok(id) => self.high_water = Some(self.high_water.map_or(id, |hw| hw.max(id))),
+            Err(_) => {
+                self.seen.insert(execution.id.clone());
+            }
+        }
+        if matches!(
+            execution.status,
+            ExecutionStatus::Running | ExecutionStatus::Waiting
+        ) {
+            self.pending
+                .insert(execution.id.clone(), execution.status.clone());
+        }
+    }
+
+    fn is_newer(&self, id: &str) -> bool {
+        match (id.parse::<u64>(), self.high_water) {
+            (Ok(id), Some(hw)) => id > hw,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::mock::{Fixtures, MockServer};
+    use serde_json::json;
+
+    fn start(executions: Vec<serde_json::Value>) -> (MockServer, N8nClient) {
+        let fixtures = Fixtures {
+            executions,
+            ..Fixtures::sample()
+        };
+        let server = MockServer::start(fixtures).unwrap();
+        let config = Config {
+            base_url: server.url(),
+            api_key: server.api_key().to_string(),
+            ..Config::default()
+        };
+        let client = N8nClient::new(&config).unwrap();
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_success() {
+        let (server, client) = start(vec![
+            json!({ "id": "7", "workflowId": "1", "status": "running" }),
+        ]);
+
+        let mut polls = 0;
+        let finished = client
+            .wait_for_execution("7", None, |_, _| {
+                polls += 1;
+                server.store().set_execution_status("7", "success");
+            })
+            .await
+            .unwrap();
+        assert_eq!(polls, 2);
+        assert!(finished.into_result().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_failed_execution() {
+        let (_server, client) = start(vec![
+            json!({ "id": "7", "workflowId": "1", "status": "error" }),
+            json!({ "id": "8", "workflowId": "1", "status": "canceled" }),
+        ]);
+
+        let failed = client
+            .wait_for_execution("7", None, |_, _| {})
+            .await
+            .unwrap();
+        assert_eq!(failed.status, ExecutionStatus::Error);
+        let err = failed.into_result().unwrap_err();
+        assert!(matches!(err, N8nError::ExecutionFailed { .. }));
+        assert_eq!(err.exit_code(), 4);
+
+        let canceled = client
+            .wait_for_execution("8", None, |_, _| {})
+            .await
+            .unwrap();
+        assert_eq!(canceled.into_result().unwrap_err().exit_code(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_times_out() {
+        let (_server, client) = start(vec![
+            json!({ "id": "7", "workflowId": "1", "status": "running" }),
+        ]);
+
+        let err = client
+            .wait_for_execution("7", Some(Duration::from_millis(100)), |_, _| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(err, N8nError::ExecutionTimeout { .. }));
+        assert_eq!(err.exit_code(), 124);
+    }
+
+    fn events(events: &[TailEvent]) -> Vec<(TailEventKind, &str)> {
+        events
+            .iter()
+            .map(|e| (e.kind, e.execution.id.as_str()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_tail_reports_new_and_finished_executions() {
+        let (server, client) = start(vec![
+            json!({ "id": "1", "workflowId": "1", "status": "success" }),
+            json!({ "id": "2", "workflowId": "1", "status": "running" }),
+        ]);
+        let mut tail = ExecutionTail::new(ExecutionListParams::default());
+
+        let backlog = tail.start(&client, 5).await.unwrap();
+        assert_eq!(
+            events(&backlog),
+            vec![(TailEventKind::New, "1"), (TailEventKind::New, "2")]
+        );
+        assert!(tail.poll(&client).await.unwrap().is_empty());
+
+        let started = client.execute_workflow("1", None).await.unwrap();
+        server.store().set_execution_status("2", "success");
+        let polled = tail.poll(&client).await.unwrap();
+        assert_eq!(
+            events(&polled),
+            vec![
+                (TailEventKind::New, started.id.as_str()),
+                (TailEventKind::Updated, "2")
+            ]
+        );
+        assert_eq!(polled[1].execution.status, ExecutionStatus::Success);
+
+        // Finished executions are no longer re-fetched
+        assert!(tail.poll(&client).await.unwrap().is_empty());
+        // Numeric IDs are tracked by the high-water mark alone
+        assert!(tail.seen.is_empty() && tail.pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tail_without_backlog_follows_cursors() {
+        let (_server, client) = start(Fixtures::sample().executions);
+        let mut tail = ExecutionTail::new(ExecutionListParams {
+            limit: Some(1),
+            ..Default::default()
+        });
+
+        assert!(tail.start(&client, 0).await.unwrap().is_empty());
+
+        let first = client.execute_workflow("1", None).await.unwrap();
+        let second = client.execute_workflow("2", None).await.unwrap();
+        let polled = tail.poll(&client).await.unwrap();
+        assert_eq!(
+            events(&polled),
+            vec![
+                (TailEventKind::New, first.id.as_str()),
+                (TailEventKind::New, second.id.as_str())
+            ]
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::client::N8nClient;
-use crate::error::{N8nError, Result};
+use crate::error::Result;
+use reqwest::Response;
 use serde::Deserialize;
 
 /// Health check response
@@ -11,18 +12,7 @@ pub struct HealthResponse {
 impl N8nClient {
     /// Basic health check (/healthz)
     pub async fn health_check(&self) -> Result<HealthResponse> {
-        // Health endpoint is at root, not under /api/v1
-        let url = format!("{}/healthz", self.base_url());
-        let response = reqwest::get(&url).await.map_err(|e| {
-            if e.is_connect() {
-                N8nError::ConnectionFailed {
-                    url: url.clone(),
-                    message: e.to_string(),
-                }
-            } else {
-                N8nError::Request(e)
-            }
-        })?;
+        let response = self.get_root("/healthz").await?;
 
         if response.status().is_success() {
             Ok(HealthResponse {
@@ -37,17 +27,7 @@ impl N8nClient {
 
     /// Readiness check (/healthz/readiness)
     pub async fn readiness_check(&self) -> Result<HealthResponse> {
-        let url = format!("{}/healthz/readiness", self.base_url());
-        let response = reqwest::get(&url).await.map_err(|e| {
-            if e.is_connect() {
-                N8nError::ConnectionFailed {
-                    url: url.clone(),
-                    message: e.to_string(),
-                }
-            } else {
-                N8nError::Request(e)
-            }
-        })?;
+        let response = self.get_root("/healthz/readiness").await?;
 
         if response.status().is_success() {
             Ok(HealthResponse {
@@ -59,4 +39,10 @@ impl N8nClient {
             })
         }
     }
+
+    /// GET an endpoint at the instance root rather than under /api/v1
+    async fn get_root(&self, path: &str) -> Result<Response> {
+        let url = self.root_url(path);
+        self.send_public(self.http().get(&url), &url).await
+    }
 }
@@ -2,4 +2,5 @@ pub mod credentials;
 pub mod executions;
 pub mod health;
 pub mod tags;
+pub mod webhooks;
 pub mod workflows;
@@ -0,0 +1,130 @@
+use crate::client::N8nClient;
+use crate::error::{N8nError, Result};
+use reqwest::{header, Method};
+use serde_json::Value;
+
+/// Which webhook URL of a workflow to call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookMode {
+    /// `/webhook/...`, registered while the workflow is active
+    Production,
+    /// `/webhook-test/...`, registered while the editor listens for a test event
+    Test,
+}
+
+impl WebhookMode {
+    /// Production for active workflows, test otherwise
+    pub fn for_active(active: bool) -> Self {
+        if active {
+            Self::Production
+        } else {
+            Self::Test
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Production => "/webhook",
+            Self::Test => "/webhook-test",
+        }
+    }
+}
+
+/// A successful webhook call
+#[derive(Debug, Clone)]
+pub struct WebhookResponse {
+    pub status: u16,
+    /// JSON body, the text of other bodies, or `Null` if empty
+    pub body: Value,
+}
+
+impl N8nClient {
+    /// Full URL of a webhook path (e.g. `https://n8n.example.com/webhook/orders`)
+    pub fn webhook_url(&self, path: &str, mode: WebhookMode) -> String {
+        let path = path.trim_start_matches('/');
+        self.root_url(&format!("{}/{}", mode.prefix(), path))
+    }
+
+    /// Call a workflow's webhook, sending `body` as JSON
+    ///
+    /// The API key is not sent, and the call is never retried since that could
+    /// start the workflow twice. Responses other than 2xx are returned as
+    /// `N8nError::Api`, so an unregistered webhook is a 404.
+    pub async fn trigger_webhook(
+        &self,
+        path: &str,
+        mode: WebhookMode,
+        method: Method,
+        body: Option<&Value>,
+    ) -> Result<WebhookResponse> {
+        let url = self.webhook_url(path, mode);
+        let mut request = self.http().request(method, &url);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = self.send_public_once(request, &url).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.extract_api_error(response).await);
+        }
+        let is_json = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.contains("json"));
+        let text = response.text().await.map_err(N8nError::Request)?;
+        let body = if text.is_empty() {
+            Value::Null
+        } else if is_json {
+            serde_json::from_str(&text).unwrap_or(Value::String(text))
+        } else {
+            Value::String(text)
+        };
+
+        Ok(WebhookResponse {
+            status: status.as_u16(),
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::mock::{Fixtures, MockServer};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_trigger_webhook() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let config = Config {
+            base_url: server.url(),
+            api_key: server.api_key().to_string(),
+            ..Config::default()
+        };
+        let client = N8nClient::new(&config).unwrap();
+        assert_eq!(
+            client.webhook_url("/orders", WebhookMode::Test),
+            format!("{}/webhook-test/orders", server.url())
+        );
+
+        let body = json!({ "order": 7 });
+        let response = client
+            .trigger_webhook("orders", WebhookMode::Production, Method::POST, Some(&body))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["message"], "Workflow was started");
+        let execution = server.store().executions().last().unwrap().clone();
+        assert_eq!(execution["mode"], "webhook");
+        assert_eq!(execution["data"]["input"], body);
+
+        let err = client
+            .trigger_webhook("missing", WebhookMode::Production, Method::GET, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.exit_code(), 69);
+    }
+}
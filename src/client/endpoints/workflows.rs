@@ -1,6 +1,7 @@
 use crate::client::{N8nClient, PaginatedResponse};
-use crate::error::Result;
-use crate::models::{Workflow, WorkflowDefinition, WorkflowDetail};
+use crate::diff::{three_way_merge, WorkflowDiff};
+use crate::error::{N8nError, Result};
+use crate::models::{TypedWorkflow, Workflow, WorkflowDefinition, WorkflowDetail};
 use serde::Serialize;
 
 /// Query parameters for listing workflows
@@ -18,6 +19,15 @@ pub struct WorkflowListParams {
     pub name: Option<String>,
 }
 
+/// Result of `N8nClient::save_workflow`
+#[derive(Debug)]
+pub struct SavedWorkflow {
+    /// Workflow as stored on the server
+    pub workflow: WorkflowDetail,
+    /// Concurrent server changes the edit was merged with, if any
+    pub merged: Option<WorkflowDiff>,
+}
+
 impl N8nClient {
     /// List workflows with optional filters
     pub async fn list_workflows(
@@ -46,6 +56,83 @@ impl N8nClient {
         self.put(&format!("/workflows/{}", id), workflow).await
     }
 
+    /// Update a workflow only if the server still has `expected_version`
+    ///
+    /// The workflow is re-fetched right before writing; a different `versionId`
+    /// on the server fails with `N8nError::VersionConflict`. Without an expected
+    /// version this behaves like `update_workflow`.
+    pub async fn update_workflow_if_version(
+        &self,
+        id: &str,
+        workflow: &WorkflowDefinition,
+        expected_version: Option<&str>,
+    ) -> Result<WorkflowDetail> {
+        if let Some(expected) = expected_version {
+            let current = self.get_workflow(id).await?;
+            if let Some(actual) = current.version_id
+                && actual != expected
+            {
+                return Err(N8nError::VersionConflict {
+                    id: id.to_string(),
+                    expected: expected.to_string(),
+                    actual,
+                });
+            }
+        }
+
+        self.update_workflow(id, workflow).await
+    }
+
+    /// Save an edited workflow with optimistic concurrency
+    ///
+    /// `base` is the version the edit started from. If the server changed in the
+    /// meantime, the edit is three-way merged onto the server version when the
+    /// changes touch different nodes, and rejected with `N8nError::MergeConflict`
+    /// otherwise.
+    pub async fn save_workflow(
+        &self,
+        id: &str,
+        base: &TypedWorkflow,
+        edited: &TypedWorkflow,
+    ) -> Result<SavedWorkflow> {
+        let definition = edited.to_definition();
+        match self
+            .update_workflow_if_version(id, &definition, base.version_id.as_deref())
+            .await
+        {
+            Ok(workflow) => Ok(SavedWorkflow {
+                workflow,
+                merged: None,
+            }),
+            Err(N8nError::VersionConflict { .. }) => {
+                let current = TypedWorkflow::from_detail(self.get_workflow(id).await?)?;
+                let merged = three_way_merge(base, edited, &current).map_err(|conflicts| {
+                    N8nError::MergeConflict {
+                        id: id.to_string(),
+                        conflicts: conflicts
+                            .iter()
+                            .map(|c| format!("  - {}", c))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    }
+                })?;
+
+                let workflow = self
+                    .update_workflow_if_version(
+                        id,
+                        &merged.to_definition(),
+                        current.version_id.as_deref(),
+                    )
+                    .await?;
+                Ok(SavedWorkflow {
+                    workflow,
+                    merged: Some(WorkflowDiff::compare(base, &current)),
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     /// Delete a workflow
     pub async fn delete_workflow(&self, id: &str) -> Result<()> {
         self.delete(&format!("/workflows/{}", id)).await
@@ -85,3 +172,38 @@ impl N8nClient {
         Ok(all_workflows)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::mock::{Fixtures, MockServer};
+
+    #[tokio::test]
+    async fn test_save_workflow_reports_merged_changes() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let config = Config {
+            base_url: server.url(),
+            api_key: server.api_key().to_string(),
+            ..Config::default()
+        };
+        let client = N8nClient::new(&config).unwrap();
+        let base = TypedWorkflow::from_detail(client.get_workflow("1").await.unwrap()).unwrap();
+
+        // Someone else moves the webhook while we disable Slack
+        let mut theirs = base.clone();
+        theirs.nodes[0].position.x = 100;
+        let saved = client.save_workflow("1", &base, &theirs).await.unwrap();
+        assert!(saved.merged.is_none());
+
+        let mut ours = base.clone();
+        ours.nodes[1].disabled = true;
+        let saved = client.save_workflow("1", &base, &ours).await.unwrap();
+        let merged = saved.merged.unwrap();
+        assert_eq!(merged.changed_items(), vec!["node 'Webhook' modified"]);
+
+        let stored = TypedWorkflow::from_detail(saved.workflow).unwrap();
+        assert_eq!(stored.nodes[0].position.x, 100);
+        assert!(stored.nodes[1].disabled);
+    }
+}
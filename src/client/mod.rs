@@ -1,6 +1,14 @@
 mod api;
+mod cassette;
 mod pagination;
+mod retry;
+mod trace;
+mod transport;
 pub mod endpoints;
 
 pub use api::N8nClient;
+pub use cassette::{CassetteMode, Interaction, RecordedRequest, RecordedResponse};
 pub use pagination::{PaginatedResponse, PaginationParams};
+pub use retry::RetryPolicy;
+pub use trace::TraceEvent;
+pub use transport::TransportOptions;
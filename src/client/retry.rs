@@ -0,0 +1,190 @@
+use reqwest::{header, Method, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Retry policy for transient API failures
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RetryPolicy {
+    /// Total attempts per request, including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled on every following attempt
+    pub base_delay_ms: u64,
+    /// Upper bound for any single delay, including `Retry-After`
+    pub max_delay_ms: u64,
+    /// Randomize delays to avoid synchronized retries
+    pub jitter: bool,
+    /// Also retry POST requests (not idempotent, so off by default)
+    pub retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+            jitter: true,
+            retry_post: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether requests with this method may be retried
+    pub fn allows_method(&self, method: &Method) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS => true,
+            Method::POST => self.retry_post,
+            _ => false,
+        }
+    }
+
+    /// Exponential backoff delay before retry number `retry` (starting at 1)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .base_delay_ms
+            .saturating_mul(1u64 << retry.saturating_sub(1).min(32))
+            .min(self.max_delay_ms);
+
+        let ms = if self.jitter && exp > 0 {
+            // Equal jitter: keep half the delay, randomize the other half
+            exp / 2 + random_u64() % (exp / 2 + 1)
+        } else {
+            exp
+        };
+
+        Duration::from_millis(ms)
+    }
+
+    /// Delay before the next retry, preferring the server's `Retry-After`
+    pub fn delay_for(&self, retry: u32, response: Option<&Response>) -> Duration {
+        let cap = Duration::from_millis(self.max_delay_ms);
+        response
+            .and_then(retry_after)
+            .map(|d| d.min(cap))
+            .unwrap_or_else(|| self.backoff(retry))
+    }
+}
+
+/// Status codes that indicate a transient failure
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Transport errors that are worth retrying (connect failures, resets, timeouts)
+pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+    if error.is_connect() || error.is_timeout() {
+        return true;
+    }
+
+    let mut source = std::error::Error::source(error);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io_err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            );
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Parse the `Retry-After` header (delay in seconds or an HTTP date)
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(header::RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value)
+}
+
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_without_jitter() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay_ms: 100,
+            max_delay_ms: 350,
+            jitter: false,
+            retry_post: false,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_backoff_jitter_bounds() {
+        let policy = RetryPolicy::default();
+        for _ in 0..50 {
+            let delay = policy.backoff(2).as_millis();
+            assert!((500..=1000).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn test_method_policy() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_method(&Method::GET));
+        assert!(policy.allows_method(&Method::DELETE));
+        assert!(!policy.allows_method(&Method::POST));
+
+        let policy = RetryPolicy {
+            retry_post: true,
+            ..RetryPolicy::default()
+        };
+        assert!(policy.allows_method(&Method::POST));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("7"), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::client::cassette::body_value;
+use crate::config::Config;
+use crate::error::{N8nError, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Request, Response};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Placeholder for redacted body values
+const REDACTED: &str = "<redacted>";
+
+/// Body fields whose values are never traced
+///
+/// Whole key names, compared case-insensitively and ignoring `_` and `-`, so
+/// `api_key` matches but `maxTokens` doesn't.
+const SENSITIVE_FIELDS: &[&str] = &[
+    "password",
+    "secret",
+    "token",
+    "accesstoken",
+    "refreshtoken",
+    "clientsecret",
+    "apikey",
+    "privatekey",
+    "authorization",
+];
+
+/// One API request as written to the trace file
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceEvent {
+    pub timestamp: DateTime<Utc>,
+    pub method: String,
+    /// URL without the query string
+    pub url: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub query: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Time until the response arrived, including retries
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_body: Option<Value>,
+    /// Why no response arrived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Writes trace logs for the requests of an `N8nClient`
+///
+/// `-v` prints one line per request to stderr, `-vv` adds the bodies. The
+/// trace file always gets the full event as a JSON line.
+#[derive(Debug)]
+pub(crate) struct Tracer {
+    level: u8,
+    file: Option<(PathBuf, Mutex<File>)>,
+}
+
+impl Tracer {
+    /// Tracer for the configured verbosity and trace file (`None` if neither is set)
+    pub(crate) fn open(config: &Config) -> Result<Option<Self>> {
+        if config.verbose == 0 && config.trace_file.is_none() {
+            return Ok(None);
+        }
+        let file = match &config.trace_file {
+            Some(path) => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| N8nError::FileWrite {
+                        path: path.display().to_string(),
+                        source: e,
+                    })?;
+                Some((path.clone(), Mutex::new(file)))
+            }
+            None => None,
+        };
+        Ok(Some(Self {
+            level: config.verbose,
+            file,
+        }))
+    }
+
+    /// Start an event for a request about to be sent
+    pub(crate) fn start(&self, request: &Request) -> TraceEvent {
+        let mut url = request.url().clone();
+        let query = url.query_pairs().into_owned().collect();
+        url.set_query(None);
+
+        TraceEvent {
+            timestamp: Utc::now(),
+            method: request.method().to_string(),
+            url: url.to_string(),
+            query,
+            status: None,
+            latency_ms: 0,
+            response_bytes: None,
+            request_body: request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .and_then(|bytes| body_value(request.headers(), bytes))
+                .map(|body| redact(body, request.url().as_str())),
+            response_body: None,
+            error: None,
+        }
+    }
+
+    /// Complete and write an event, handing back the (buffered) response
+    pub(crate) async fn finish(
+        &self,
+        mut event: TraceEvent,
+        elapsed: Duration,
+        result: Result<Response>,
+    ) -> Result<Response> {
+        event.latency_ms = elapsed.as_millis() as u64;
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                event.error = Some(e.to_string());
+                self.write(&event);
+                return Err(e);
+            }
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await.map_err(N8nError::Request)?;
+        event.status = Some(status.as_u16());
+        event.response_bytes = Some(bytes.len());
+        event.response_body = body_value(&headers, &bytes).map(|body| redact(body, &event.url));
+        self.write(&event);
+
+        let mut rebuilt = http::Response::new(bytes.to_vec());
+        *rebuilt.status_mut() = status;
+        *rebuilt.headers_mut() = headers;
+        Ok(Response::from(rebuilt))
+    }
+
+    fn write(&self, event: &TraceEvent) {
+        if self.level >= 1 {
+            eprintln!("{}", summary(event));
+        }
+        if self.level >= 2 {
+            for (label, body) in [
+                ("request", &event.request_body),
+                ("response", &event.response_body),
+            ] {
+                if let Some(body) = body {
+                    eprintln!("  {}: {}", label, indented(body));
+                }
+            }
+        }
+        if let Some((path, file)) = &self.file
+            && let Ok(line) = serde_json::to_string(event)
+        {
+            let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+            if let Err(e) = writeln!(file, "{}", line) {
+                eprintln!(
+                    "Warning: Could not write trace to {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// One-line form of an event (`GET http://... -> 200 (12 ms, 345 bytes)`)
+fn summary(event: &TraceEvent) -> String {
+    let mut url = event.url.clone();
+    if !event.query.is_empty() {
+        let query: Vec<String> = event
+            .query
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        url = format!("{}?{}", url, query.join("&"));
+    }
+    match (&event.status, &event.error) {
+        (Some(status), _) => format!(
+            "{} {} -> {} ({} ms, {} bytes)",
+            event.method,
+            url,
+            status,
+            event.latency_ms,
+            event.response_bytes.unwrap_or_default()
+        ),
+        (None, error) => format!(
+            "{} {} -> failed: {} ({} ms)",
+            event.method,
+            url,
+            error.as_deref().unwrap_or_default(),
+            event.latency_ms
+        ),
+    }
+}
+
+/// Pretty JSON with continuation lines indented under the label
+fn indented(body: &Value) -> String {
+    serde_json::to_string_pretty(body)
+        .unwrap_or_default()
+        .replace('\n', "\n  ")
+}
+
+/// Replace secrets in a request or response body
+///
+/// Fields named like passwords, tokens or keys are redacted anywhere, and so
+/// is the `data` of credentials.
+fn redact(body: Value, url: &str) -> Value {
+    redact_credential_data(redact_fields(body), url)
+}
+
+/// Replace the `data` of a credentials request or response
+pub(super) fn redact_credential_data(mut body: Value, url: &str) -> Value {
+    if url.contains("/credentials")
+        && let Some(data) = body.get_mut("data")
+    {
+        *data = Value::String(REDACTED.to_string());
+    }
+    body
+}
+
+fn redact_fields(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let name: String = key
+                        .chars()
+                        .filter(|c| !matches!(c, '_' | '-'))
+                        .collect::<String>()
+                        .to_lowercase();
+                    let value = if SENSITIVE_FIELDS.contains(&name.as_str()) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_fields(value)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(redact_fields).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::N8nClient;
+    use crate::mock::{Fixtures, MockServer};
+    use serde_json::json;
+
+    #[test]
+    fn test_redact() {
+        let body = json!({
+            "name": "Slack",
+            "data": { "accessToken": "xoxb" },
+            "nodes": [{ "parameters": {
+                "password": "hunter2",
+                "api_key": "k",
+                "maxTokens": 256,
+                "url": "https://x"
+            } }]
+        });
+
+        let traced = redact(body.clone(), "/api/v1/workflows");
+        let parameters = &traced["nodes"][0]["parameters"];
+        assert_eq!(parameters["password"], REDACTED);
+        assert_eq!(parameters["api_key"], REDACTED);
+        assert_eq!(parameters["maxTokens"], 256);
+        assert_eq!(parameters["url"], "https://x");
+        assert_eq!(traced["data"]["accessToken"], REDACTED);
+
+        let traced = redact(body, "/api/v1/credentials");
+        assert_eq!(traced["data"], REDACTED);
+        assert_eq!(traced["name"], "Slack");
+    }
+
+    #[tokio::test]
+    async fn test_trace_file() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.jsonl");
+        let config = Config {
+            base_url: server.url(),
+            api_key: server.api_key().to_string(),
+            trace_file: Some(path.clone()),
+            ..Config::default()
+        };
+        let client = N8nClient::new(&config).unwrap();
+
+        let workflow = client.get_workflow("1").await.unwrap();
+        assert_eq!(workflow.name, "Order notifications");
+        client.get_workflow("999").await.unwrap_err();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let events: Vec<Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["method"], "GET");
+        assert_eq!(
+            events[0]["url"],
+            format!("{}/api/v1/workflows/1", server.url())
+        );
+        assert_eq!(events[0]["status"], 200);
+        assert_eq!(events[0]["response_body"]["name"], "Order notifications");
+        assert_eq!(events[1]["status"], 404);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::error::{N8nError, Result};
+use reqwest::{Certificate, ClientBuilder, Identity, NoProxy, Proxy};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Proxy and TLS settings of the HTTP client
+///
+/// Without a `proxy`, the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY` and
+/// `NO_PROXY` environment variables are used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransportOptions {
+    /// Proxy URL for all requests; hosts in `NO_PROXY` still bypass it
+    pub proxy: Option<String>,
+    /// PEM file with CA certificates to trust in addition to the built-in roots
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate for mutual TLS (may also contain the key)
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`
+    pub client_key: Option<PathBuf>,
+    /// Accept any server certificate (for testing only)
+    pub insecure_skip_verify: bool,
+}
+
+impl TransportOptions {
+    /// Resolve relative certificate and key paths against `dir`
+    pub fn relative_to(mut self, dir: &Path) -> Self {
+        for path in [
+            &mut self.ca_cert,
+            &mut self.client_cert,
+            &mut self.client_key,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = dir.join(&*path);
+        }
+        self
+    }
+
+    /// Apply the settings to a client builder
+    pub fn apply(&self, mut builder: ClientBuilder) -> Result<ClientBuilder> {
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::all(proxy.as_str())
+                .map_err(|e| N8nError::Config(format!("Invalid proxy URL '{}': {}", proxy, e)))?;
+            builder = builder.proxy(proxy.no_proxy(NoProxy::from_env()));
+        }
+
+        if let Some(path) = &self.ca_cert {
+            let certificates = Certificate::from_pem_bundle(&read_pem(path)?)
+                .map_err(|e| invalid_certificate(path, e))?;
+            if certificates.is_empty() {
+                return Err(N8nError::InvalidCertificate {
+                    path: path.display().to_string(),
+                    message: "no certificates found".to_string(),
+                });
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), key) => {
+                let mut pem = read_pem(cert)?;
+                if let Some(key) = key {
+                    pem.push(b'\n');
+                    pem.extend(read_pem(key)?);
+                }
+                let identity =
+                    Identity::from_pem(&pem).map_err(|e| invalid_certificate(cert, e))?;
+                builder = builder.identity(identity);
+            }
+            (None, Some(_)) => {
+                return Err(N8nError::Config(
+                    "client_key is set without client_cert".to_string(),
+                ));
+            }
+            (None, None) => {}
+        }
+
+        Ok(builder.danger_accept_invalid_certs(self.insecure_skip_verify))
+    }
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| N8nError::FileRead {
+        path: path.display().to_string(),
+        source: e,
+    })
+}
+
+fn invalid_certificate(path: &Path, error: reqwest::Error) -> N8nError {
+    N8nError::InvalidCertificate {
+        path: path.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::N8nClient;
+    use crate::config::Config;
+    use reqwest::Client;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_invalid_settings() {
+        let options = TransportOptions {
+            proxy: Some("not a url".to_string()),
+            ..TransportOptions::default()
+        };
+        let err = options.apply(Client::builder()).unwrap_err();
+        assert_eq!(err.exit_code(), 78);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ca.pem");
+        fs::write(&path, "not a certificate").unwrap();
+        let options = TransportOptions {
+            ca_cert: Some(path),
+            ..TransportOptions::default()
+        };
+        let err = options.apply(Client::builder()).unwrap_err();
+        assert!(matches!(err, N8nError::InvalidCertificate { .. }));
+
+        let options = TransportOptions {
+            client_key: Some(dir.path().join("key.pem")),
+            ..TransportOptions::default()
+        };
+        assert!(options.apply(Client::builder()).is_err());
+    }
+
+    #[test]
+    fn test_relative_paths() {
+        let options = TransportOptions {
+            ca_cert: Some(PathBuf::from("certs/ca.pem")),
+            client_cert: Some(PathBuf::from("/etc/n8n/client.pem")),
+            ..TransportOptions::default()
+        }
+        .relative_to(Path::new("/home/me/.config/n8n-cli"));
+        assert_eq!(
+            options.ca_cert,
+            Some(PathBuf::from("/home/me/.config/n8n-cli/certs/ca.pem"))
+        );
+        assert_eq!(
+            options.client_cert,
+            Some(PathBuf::from("/etc/n8n/client.pem"))
+        );
+        assert_eq!(options.client_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_the_proxy() {
+        // A proxy that answers every request itself
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        let proxied = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut header = String::new();
+            while reader.read_line(&mut header).unwrap() > 2 {
+                header.clear();
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .unwrap();
+            request_line
+        });
+
+        // The instance doesn't exist, so only the proxy can answer
+        let config = Config {
+            base_url: "http://n8n.invalid".to_string(),
+            api_key: "key".to_string(),
+            transport: TransportOptions {
+                proxy: Some(proxy),
+                ..TransportOptions::default()
+            },
+            ..Config::default()
+        };
+        let health = N8nClient::new(&config)
+            .unwrap()
+            .health_check()
+            .await
+            .unwrap();
+        assert_eq!(health.status, "ok");
+        assert!(proxied
+            .join()
+            .unwrap()
+            .starts_with("GET http://n8n.invalid/healthz "));
+    }
+}
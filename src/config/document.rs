@@ -0,0 +1,412 @@
+use super::loader::{config_file_path, ConfigFile, Profile};
+use crate::error::{N8nError, Result};
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Table};
+
+/// Editable config file that keeps comments, formatting and unknown keys
+///
+/// Changes are made key by key, so only the values that actually change are
+/// rewritten.
+#[derive(Debug, Clone)]
+pub struct ConfigDocument {
+    path: PathBuf,
+    doc: DocumentMut,
+}
+
+impl ConfigDocument {
+    /// Open the default config file (empty if it doesn't exist yet)
+    pub fn open() -> Result<Self> {
+        let path = config_file_path()
+            .ok_or_else(|| N8nError::Config("No config directory on this platform".to_string()))?;
+        Self::load(&path)
+    }
+
+    /// Open a config file (empty if it doesn't exist yet)
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = if path.exists() {
+            fs::read_to_string(path).map_err(N8nError::ConfigFileRead)?
+        } else {
+            String::new()
+        };
+        Self::parse(path, &contents)
+    }
+
+    fn parse(path: &Path, contents: &str) -> Result<Self> {
+        // Parse with the typed structs first for the usual error messages
+        toml::from_str::<ConfigFile>(contents).map_err(N8nError::ConfigFileParse)?;
+        let doc = contents
+            .parse::<DocumentMut>()
+            .map_err(|e| N8nError::Config(e.to_string()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            doc,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Typed view of the current contents
+    pub fn config(&self) -> Result<ConfigFile> {
+        toml::from_str(&self.doc.to_string()).map_err(N8nError::ConfigFileParse)
+    }
+
+    pub fn profile(&self, name: &str) -> Result<Profile> {
+        self.config()?
+            .profiles
+            .remove(name)
+            .ok_or_else(|| N8nError::ProfileNotFound(name.to_string()))
+    }
+
+    /// Profiles sorted by name
+    pub fn profiles(&self) -> Result<Vec<ProfileSummary>> {
+        let config = self.config()?;
+        let mut profiles: Vec<_> = config
+            .profiles
+            .iter()
+            .map(|(name, profile)| ProfileSummary {
+                name: name.clone(),
+                default: config.default_profile.as_deref() == Some(name.as_str()),
+                base_url: profile.base_url.clone(),
+                api_key: match (&profile.api_key, &profile.api_key_env) {
+                    (Some(_), _) => "stored".to_string(),
+                    (None, Some(var)) => format!("${}", var),
+                    (None, None) => "-".to_string(),
+                },
+            })
+            .collect();
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(profiles)
+    }
+
+    /// Add a new `[profiles.<name>]` table
+    pub fn add_profile(&mut self, name: &str, profile: &Profile) -> Result<()> {
+        if name.is_empty() {
+            return Err(N8nError::Config(
+                "Profile name must not be empty".to_string(),
+            ));
+        }
+        let has_content = !self.doc.to_string().trim().is_empty();
+        let profiles = self.profiles_table()?;
+        if profiles.contains_key(name) {
+            return Err(N8nError::Config(format!(
+                "Profile '{}' already exists (use `n8n config profiles edit`)",
+                name
+            )));
+        }
+
+        let mut table = to_table(profile)?;
+        if has_content {
+            table.decor_mut().set_prefix("\n");
+        }
+        profiles.insert(name, Item::Table(table));
+        Ok(())
+    }
+
+    /// Change a profile, rewriting only the keys whose values change
+    pub fn update_profile(
+        &mut self,
+        name: &str,
+        edit: impl FnOnce(&mut Profile),
+    ) -> Result<Profile> {
+        let old = self.profile(name)?;
+        let mut new = old.clone();
+        edit(&mut new);
+
+        let old_table = to_table(&old)?;
+        let new_table = to_table(&new)?;
+        let table = self
+            .profiles_table()?
+            .get_mut(name)
+            .and_then(Item::as_table_like_mut)
+            .ok_or_else(|| N8nError::ProfileNotFound(name.to_string()))?;
+
+        for (key, _) in old_table.iter() {
+            if !new_table.contains_key(key) {
+                remove_item(table, key);
+            }
+        }
+        for (key, item) in new_table.iter() {
+            let unchanged = old_table
+                .get(key)
+                .is_some_and(|old| old.to_string() == item.to_string());
+            if !unchanged {
+                set_item(table, key, item.clone());
+            }
+        }
+        Ok(new)
+    }
+
+    /// Remove a profile; returns whether it was the default profile, which
+    /// is then unset
+    pub fn remove_profile(&mut self, name: &str) -> Result<bool> {
+        if self.profiles_table()?.remove(name).is_none() {
+            return Err(N8nError::ProfileNotFound(name.to_string()));
+        }
+        let was_default = self.doc.get("default_profile").and_then(Item::as_str) == Some(name);
+        if was_default {
+            remove_item(self.doc.as_table_mut(), "default_profile");
+        }
+        Ok(was_default)
+    }
+
+    /// Set `default_profile`
+    pub fn set_default_profile(&mut self, name: &str) -> Result<()> {
+        if !self.config()?.profiles.contains_key(name) {
+            return Err(N8nError::ProfileNotFound(name.to_string()));
+        }
+        set_item(
+            self.doc.as_table_mut(),
+            "default_profile",
+            toml_edit::value(name),
+        );
+        Ok(())
+    }
+
+    /// Write the file, creating its directory if needed
+    ///
+    /// On Unix, a file that stores an API key is only readable by its owner.
+    pub fn save(&self) -> Result<()> {
+        let write_error = |e| N8nError::FileWrite {
+            path: self.path.display().to_string(),
+            source: e,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        let has_api_key = self
+            .config()?
+            .profiles
+            .values()
+            .any(|p| p.api_key.is_some());
+        // Removing the first value can leave the file starting with a blank line
+        let contents = self.doc.to_string();
+        write_config(&self.path, contents.trim_start(), has_api_key).map_err(write_error)
+    }
+
+    fn profiles_table(&mut self) -> Result<&mut Table> {
+        self.doc
+            .entry("profiles")
+            .or_insert_with(|| {
+                // Only the [profiles.<name>] headers are written
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .ok_or_else(|| N8nError::Config("'profiles' must be a table".to_string()))
+    }
+}
+
+#[cfg(unix)]
+fn write_config(path: &Path, contents: &str, private: bool) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    if !private {
+        return fs::write(path, contents);
+    }
+    // Restrict an existing file before the key is written to it
+    if path.exists() {
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn write_config(path: &Path, contents: &str, _private: bool) -> std::io::Result<()> {
+    fs::write(path, contents)
+}
+
+fn to_table(profile: &Profile) -> Result<Table> {
+    let doc = toml_edit::ser::to_document(profile)
+        .map_err(|e| N8nError::Config(format!("Cannot serialize profile: {}", e)))?;
+    Ok(doc.as_table().clone())
+}
+
+/// Replace a value, keeping the comments around the old one
+fn set_item(table: &mut dyn toml_edit::TableLike, key: &str, mut item: Item) {
+    match table.get_mut(key) {
+        Some(old) => {
+            if let (Some(old), Some(new)) = (old.as_value(), item.as_value_mut()) {
+                *new.decor_mut() = old.decor().clone();
+            }
+            *old = item;
+        }
+        None => {
+            table.insert(key, item);
+        }
+    }
+}
+
+/// Remove a value, moving the comments above it to the next value
+fn remove_item(table: &mut dyn toml_edit::TableLike, key: &str) {
+    let prefix = table
+        .key(key)
+        .and_then(|k| k.leaf_decor().prefix())
+        .and_then(|p| p.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let next = table
+        .iter()
+        .filter(|(_, item)| item.is_value())
+        .map(|(k, _)| k.to_string())
+        .skip_while(|k| k != key)
+        .nth(1);
+    table.remove(key);
+
+    if let Some(next) = next
+        && let Some(mut next) = table.key_mut(&next)
+    {
+        let decor = next.leaf_decor_mut();
+        let own = decor.prefix().and_then(|p| p.as_str()).unwrap_or_default();
+        let prefix = format!("{}{}", prefix, own);
+        decor.set_prefix(prefix);
+    }
+}
+
+/// One row of `config profiles list`
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub default: bool,
+    pub base_url: String,
+    /// `stored`, the `$VARIABLE` it is read from, or `-`
+    pub api_key: String,
+}
+
+impl Outputable for ProfileSummary {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("name", "NAME", |p: &Self| p.name.clone()),
+            Column::new("default", "DEFAULT", |p: &Self| {
+                if p.default { "*" } else { "" }.to_string()
+            }),
+            Column::new("base_url", "BASE URL", |p: &Self| p.base_url.clone()),
+            Column::new("api_key", "API KEY", |p: &Self| p.api_key.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# Shared settings
+default_profile = "production"
+timeout_secs = 60
+editor_theme = "dark" # not used by the CLI
+
+[profiles.production]
+base_url = "https://n8n.example.com" # main instance
+api_key_env = "N8N_PROD_API_KEY"
+team = "platform"
+
+[profiles.production.retry]
+max_attempts = 5
+"#;
+
+    fn document() -> ConfigDocument {
+        ConfigDocument::parse(Path::new("config.toml"), CONFIG).unwrap()
+    }
+
+    #[test]
+    fn test_add_and_default() {
+        let mut doc = document();
+        doc.add_profile("staging", &Profile::new("https://staging.example.com"))
+            .unwrap();
+        assert!(doc.add_profile("staging", &Profile::new("x")).is_err());
+        doc.set_default_profile("staging").unwrap();
+        assert!(doc.set_default_profile("missing").is_err());
+
+        let expected = CONFIG.replace(
+            "default_profile = \"production\"",
+            "default_profile = \"staging\"",
+        ) + "\n[profiles.staging]\nbase_url = \"https://staging.example.com\"\n";
+        assert_eq!(doc.doc.to_string(), expected);
+
+        let names: Vec<_> = doc
+            .profiles()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, ["production", "staging"]);
+    }
+
+    #[test]
+    fn test_update_keeps_comments_and_unknown_keys() {
+        let mut doc = document();
+        doc.update_profile("production", |p| {
+            p.base_url = "https://n8n2.example.com".to_string();
+            p.api_key_env = None;
+            p.proxy = Some("http://proxy:3128".to_string());
+        })
+        .unwrap();
+
+        let text = doc.doc.to_string();
+        assert!(text.contains("base_url = \"https://n8n2.example.com\" # main instance\n"));
+        assert!(!text.contains("api_key_env"));
+        assert!(text.contains("team = \"platform\""));
+        assert!(text.contains("[profiles.production.retry]\nmax_attempts = 5"));
+        assert!(text.contains("editor_theme = \"dark\" # not used by the CLI"));
+        assert_eq!(
+            doc.profile("production").unwrap().proxy.as_deref(),
+            Some("http://proxy:3128")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_restricts_stored_keys() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "timeout_secs = 60\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+        let mut doc = ConfigDocument::load(&path).unwrap();
+        doc.add_profile("env", &Profile::new("https://n8n.example.com"))
+            .unwrap();
+        doc.save().unwrap();
+        assert_eq!(mode(&path), 0o644);
+
+        let mut profile = Profile::new("http://localhost:5678");
+        profile.api_key = Some("secret".to_string());
+        doc.add_profile("dev", &profile).unwrap();
+        doc.save().unwrap();
+        assert_eq!(mode(&path), 0o600);
+
+        let new_path = dir.path().join("new").join("config.toml");
+        let mut doc = ConfigDocument::load(&new_path).unwrap();
+        doc.add_profile("dev", &profile).unwrap();
+        doc.save().unwrap();
+        assert_eq!(mode(&new_path), 0o600);
+    }
+
+    #[test]
+    fn test_remove_default_profile() {
+        let mut doc = document();
+        assert!(doc.remove_profile("production").unwrap());
+        assert!(doc.remove_profile("production").is_err());
+        let config = doc.config().unwrap();
+        assert!(config.default_profile.is_none());
+        assert!(config.profiles.is_empty());
+        assert!(doc
+            .doc
+            .to_string()
+            .starts_with("# Shared settings\ntimeout_secs = 60\n"));
+    }
+}
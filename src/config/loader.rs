@@ -1,18 +1,26 @@
+use crate::client::{CassetteMode, RetryPolicy, TransportOptions};
 use crate::error::{N8nError, Result};
-use crate::output::OutputFormat;
+use crate::output::{OutputFormat, OutputOptions, Query, Template};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Runtime configuration (fully resolved)
 #[derive(Debug, Clone)]
 pub struct Config {
     pub base_url: String,
     pub api_key: String,
-    pub output_format: OutputFormat,
+    pub output: OutputOptions,
     pub timeout_secs: u64,
-    pub verbose: bool,
+    pub retry: RetryPolicy,
+    pub transport: TransportOptions,
+    /// Record API traffic to, or replay it from, cassette files
+    pub cassette: Option<CassetteMode>,
+    /// Trace level of API requests (`-v` = 1, `-vv` = 2)
+    pub verbose: u8,
+    /// Append a JSON line per API request to this file
+    pub trace_file: Option<PathBuf>,
     pub quiet: bool,
 }
 
@@ -21,9 +29,13 @@ impl Default for Config {
         Self {
             base_url: "http://localhost:5678".to_string(),
             api_key: String::new(),
-            output_format: OutputFormat::Table,
+            output: OutputOptions::default(),
             timeout_secs: 30,
-            verbose: false,
+            retry: RetryPolicy::default(),
+            transport: TransportOptions::default(),
+            cassette: None,
+            verbose: 0,
+            trace_file: None,
             quiet: false,
         }
     }
@@ -35,10 +47,24 @@ pub struct ConfigFile {
     pub default_profile: Option<String>,
     pub output_format: Option<OutputFormat>,
     pub timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
 }
 
+impl ConfigFile {
+    /// Apply file-level connection settings
+    fn apply_to(&self, config: &mut Config) {
+        if let Some(timeout) = self.timeout_secs {
+            config.timeout_secs = timeout;
+        }
+        if let Some(retry) = &self.retry {
+            retry.apply_to(&mut config.retry);
+        }
+    }
+}
+
 /// Named profile configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Profile {
@@ -49,18 +75,154 @@ pub struct Profile {
     pub api_key_env: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_format: Option<OutputFormat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+    /// Proxy URL (defaults to `HTTPS_PROXY`/`HTTP_PROXY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Extra CA certificates (PEM)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    /// Client certificate for mutual TLS (PEM)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    /// Private key of `client_cert` (PEM)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+}
+
+impl Profile {
+    /// A profile with only a base URL
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: None,
+            api_key_env: None,
+            output_format: None,
+            retry: None,
+            proxy: None,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            insecure_skip_verify: None,
+        }
+    }
+
+    /// Apply the profile's URL, API key, retry, proxy and TLS settings
+    fn apply_to(&self, config: &mut Config) {
+        config.base_url = self.base_url.clone();
+
+        // Resolve API key from profile
+        if let Some(key) = &self.api_key {
+            config.api_key = key.clone();
+        } else if let Some(env_var) = &self.api_key_env
+            && let Ok(key) = std::env::var(env_var)
+        {
+            config.api_key = key;
+        }
+
+        if let Some(retry) = &self.retry {
+            retry.apply_to(&mut config.retry);
+        }
+
+        let transport = TransportOptions {
+            proxy: self.proxy.clone(),
+            ca_cert: self.ca_cert.clone(),
+            client_cert: self.client_cert.clone(),
+            client_key: self.client_key.clone(),
+            insecure_skip_verify: self.insecure_skip_verify.unwrap_or_default(),
+        };
+        // Relative paths are relative to the config file, not the working directory
+        let config_dir = config_file_path().and_then(|p| p.parent().map(Path::to_path_buf));
+        config.transport = match config_dir {
+            Some(dir) => transport.relative_to(&dir),
+            None => transport,
+        };
+    }
+}
+
+/// Retry settings from the config file (unset fields keep the previous layer's value)
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RetryConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_delay_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_post: Option<bool>,
+}
+
+impl RetryConfig {
+    /// Apply the settings that are present onto a policy
+    pub fn apply_to(&self, policy: &mut RetryPolicy) {
+        if let Some(v) = self.max_attempts {
+            policy.max_attempts = v;
+        }
+        if let Some(v) = self.base_delay_ms {
+            policy.base_delay_ms = v;
+        }
+        if let Some(v) = self.max_delay_ms {
+            policy.max_delay_ms = v;
+        }
+        if let Some(v) = self.jitter {
+            policy.jitter = v;
+        }
+        if let Some(v) = self.retry_post {
+            policy.retry_post = v;
+        }
+    }
 }
 
 /// CLI options that can override configuration
+#[derive(Clone)]
 pub struct CliOverrides {
     pub profile: Option<String>,
     pub url: Option<String>,
     pub api_key: Option<String>,
-    pub output: OutputFormat,
-    pub verbose: bool,
+    /// `None` falls back to the file and profile `output_format`
+    pub output: Option<OutputFormat>,
+    pub no_headers: bool,
+    pub columns: Vec<String>,
+    pub sort_by: Option<String>,
+    pub reverse: bool,
+    pub wide: bool,
+    pub query: Option<Query>,
+    pub raw: bool,
+    pub template: Option<Template>,
+    pub cassette: Option<CassetteMode>,
+    pub verbose: u8,
+    pub trace_file: Option<PathBuf>,
     pub quiet: bool,
 }
 
+impl CliOverrides {
+    /// Output settings from the command line, without the config file
+    pub fn output_options(&self) -> OutputOptions {
+        let mut output = OutputOptions {
+            format: self.output.unwrap_or_default(),
+            no_headers: self.no_headers,
+            columns: self.columns.clone(),
+            sort_by: self.sort_by.clone(),
+            reverse: self.reverse,
+            wide: self.wide,
+            query: self.query.clone(),
+            raw: self.raw,
+            template: None,
+        };
+        if let Some(template) = &self.template {
+            output.format = OutputFormat::Template;
+            output.template = Some(template.clone());
+        }
+        output
+    }
+}
+
 /// Get the default config file path
 pub fn config_file_path() -> Option<PathBuf> {
     ProjectDirs::from("", "", "n8n-cli").map(|dirs| dirs.config_dir().join("config.toml"))
@@ -71,47 +233,26 @@ pub fn load_config(overrides: CliOverrides) -> Result<Config> {
     let mut config = Config::default();
 
     // Layer 1: Load config file if it exists
-    if let Some(config_path) = config_file_path() {
-        if config_path.exists() {
-            let contents = std::fs::read_to_string(&config_path).map_err(N8nError::ConfigFileRead)?;
-            let file_config: ConfigFile =
-                toml::from_str(&contents).map_err(N8nError::ConfigFileParse)?;
-
-            // Apply file-level defaults
-            if let Some(fmt) = file_config.output_format {
-                config.output_format = fmt;
-            }
-            if let Some(timeout) = file_config.timeout_secs {
-                config.timeout_secs = timeout;
-            }
+    if let Some(file_config) = read_config_file()? {
+        // Apply file-level defaults
+        if let Some(fmt) = file_config.output_format {
+            config.output.format = fmt;
+        }
+        file_config.apply_to(&mut config);
+
+        // Determine which profile to use
+        let profile_name = selected_profile(overrides.profile.as_deref(), &file_config);
+
+        // Apply profile settings
+        if let Some(name) = profile_name {
+            if let Some(profile) = file_config.profiles.get(&name) {
+                profile.apply_to(&mut config);
 
-            // Determine which profile to use
-            let profile_name = overrides
-                .profile
-                .clone()
-                .or(std::env::var("N8N_PROFILE").ok())
-                .or(file_config.default_profile);
-
-            // Apply profile settings
-            if let Some(name) = profile_name {
-                if let Some(profile) = file_config.profiles.get(&name) {
-                    config.base_url = profile.base_url.clone();
-
-                    // Resolve API key from profile
-                    if let Some(key) = &profile.api_key {
-                        config.api_key = key.clone();
-                    } else if let Some(env_var) = &profile.api_key_env {
-                        if let Ok(key) = std::env::var(env_var) {
-                            config.api_key = key;
-                        }
-                    }
-
-                    if let Some(fmt) = profile.output_format {
-                        config.output_format = fmt;
-                    }
-                } else {
-                    return Err(N8nError::ProfileNotFound(name));
+                if let Some(fmt) = profile.output_format {
+                    config.output.format = fmt;
                 }
+            } else {
+                return Err(N8nError::ProfileNotFound(name));
             }
         }
     }
@@ -125,23 +266,77 @@ pub fn load_config(overrides: CliOverrides) -> Result<Config> {
     }
 
     // Layer 3: CLI arguments override everything
-    if let Some(url) = overrides.url {
-        config.base_url = url;
+    if let Some(url) = &overrides.url {
+        config.base_url = url.clone();
     }
-    if let Some(key) = overrides.api_key {
-        config.api_key = key;
+    if let Some(key) = &overrides.api_key {
+        config.api_key = key.clone();
     }
 
-    // Output format from CLI always wins (it has a default value)
-    config.output_format = overrides.output;
+    // Output settings come from the CLI; the format falls back to the file and profile
+    let file_format = config.output.format;
+    config.output = overrides.output_options();
+    if overrides.output.is_none() && overrides.template.is_none() {
+        config.output.format = file_format;
+    }
+    config.cassette = overrides.cassette;
     config.verbose = overrides.verbose;
+    config.trace_file = overrides.trace_file;
     config.quiet = overrides.quiet;
 
     Ok(config)
 }
 
+/// Load the configuration of a named profile
+///
+/// Only the config file is consulted: `N8N_PROFILE`, `N8N_BASE_URL`,
+/// `N8N_API_KEY`, `--url` and `--api-key` don't apply. Output, logging and
+/// cassette settings are taken from `base`.
+pub fn load_profile_config(name: &str, base: &Config) -> Result<Config> {
+    let file_config = read_config_file()?.unwrap_or_default();
+    let profile = file_config
+        .profiles
+        .get(name)
+        .ok_or_else(|| N8nError::ProfileNotFound(name.to_string()))?;
+
+    let mut config = Config {
+        output: base.output.clone(),
+        cassette: base.cassette.clone(),
+        verbose: base.verbose,
+        trace_file: base.trace_file.clone(),
+        quiet: base.quiet,
+        ..Config::default()
+    };
+    file_config.apply_to(&mut config);
+    profile.apply_to(&mut config);
+    Ok(config)
+}
+
+/// Name of the profile to use: `--profile`, then `N8N_PROFILE`, then `default_profile`
+pub fn selected_profile(explicit: Option<&str>, file_config: &ConfigFile) -> Option<String> {
+    explicit
+        .map(str::to_string)
+        .or(std::env::var("N8N_PROFILE").ok())
+        .or(file_config.default_profile.clone())
+}
+
+/// Read and parse the config file (`None` if it doesn't exist)
+pub fn read_config_file() -> Result<Option<ConfigFile>> {
+    let Some(config_path) = config_file_path().filter(|p| p.exists()) else {
+        return Ok(None);
+    };
+    let contents = std::fs::read_to_string(&config_path).map_err(N8nError::ConfigFileRead)?;
+    toml::from_str(&contents)
+        .map(Some)
+        .map_err(N8nError::ConfigFileParse)
+}
+
 /// Validate that required configuration is present
 pub fn validate_config(config: &Config) -> Result<()> {
+    // Replayed responses need neither a key nor a reachable instance
+    if matches!(config.cassette, Some(CassetteMode::Replay(_))) {
+        return Ok(());
+    }
     if config.api_key.is_empty() {
         return Err(N8nError::MissingApiKey);
     }
@@ -161,4 +356,29 @@ mod tests {
         assert_eq!(config.base_url, "http://localhost:5678");
         assert!(config.api_key.is_empty());
     }
+
+    #[test]
+    fn test_profile_retry_overrides_file_level() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+            [retry]
+            max_attempts = 5
+            retry_post = true
+
+            [profiles.ci]
+            base_url = "http://n8n.internal:5678"
+            retry = { max_attempts = 8, jitter = false }
+            "#,
+        )
+        .unwrap();
+
+        let mut policy = RetryPolicy::default();
+        file.retry.as_ref().unwrap().apply_to(&mut policy);
+        file.profiles["ci"].retry.as_ref().unwrap().apply_to(&mut policy);
+
+        assert_eq!(policy.max_attempts, 8);
+        assert!(policy.retry_post);
+        assert!(!policy.jitter);
+        assert_eq!(policy.base_delay_ms, RetryPolicy::default().base_delay_ms);
+    }
 }
@@ -1,3 +1,10 @@
+mod document;
 mod loader;
+mod sources;
 
-pub use loader::{Config, ConfigFile, Profile, CliOverrides, load_config, validate_config};
+pub use document::{ConfigDocument, ProfileSummary};
+pub use loader::{
+    Config, ConfigFile, Profile, RetryConfig, CliOverrides, config_file_path, load_config,
+    load_profile_config, read_config_file, selected_profile, validate_config,
+};
+pub use sources::{ConfigLayer, ConfigSetting, explain_config};
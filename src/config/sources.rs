@@ -0,0 +1,327 @@
+use super::loader::{read_config_file, CliOverrides, Config, ConfigFile, Profile, RetryConfig};
+use crate::error::Result;
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Configuration layer a resolved value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigLayer {
+    Default,
+    File,
+    Env,
+    Cli,
+}
+
+impl std::fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::File => write!(f, "file"),
+            Self::Env => write!(f, "env"),
+            Self::Cli => write!(f, "cli"),
+        }
+    }
+}
+
+/// One resolved setting of `config show`
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSetting {
+    pub setting: String,
+    pub value: String,
+    pub source: ConfigLayer,
+    /// Config key, environment variable or flag that set the value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+}
+
+impl Outputable for ConfigSetting {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("setting", "SETTING", |s: &Self| s.setting.clone()),
+            Column::new("value", "VALUE", |s: &Self| s.value.clone()),
+            Column::new("source", "SOURCE", |s: &Self| s.source.to_string()),
+            Column::new("origin", "ORIGIN", |s: &Self| {
+                s.origin.clone().unwrap_or_else(|| "-".to_string())
+            }),
+        ]
+    }
+}
+
+type Source = (ConfigLayer, Option<String>);
+
+/// Whether a retry setting is present in a `RetryConfig`
+type RetryField = fn(&RetryConfig) -> bool;
+
+/// Pair each value of a config resolved from `overrides` with the layer it came from
+///
+/// This follows the precedence of `load_config`: defaults, then the config
+/// file and the selected profile, then `N8N_*` variables, then flags.
+pub fn explain_config(config: &Config, overrides: &CliOverrides) -> Result<Vec<ConfigSetting>> {
+    let file = read_config_file()?;
+    Ok(explain(config, overrides, file.as_ref(), &|name| {
+        std::env::var(name).ok()
+    }))
+}
+
+fn explain(
+    config: &Config,
+    overrides: &CliOverrides,
+    file: Option<&ConfigFile>,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Vec<ConfigSetting> {
+    let default = || (ConfigLayer::Default, None);
+    let from_file = |key: String| (ConfigLayer::File, Some(key));
+    let from_env = |name: &str| env(name).map(|_| (ConfigLayer::Env, Some(name.to_string())));
+    // Flags with an `env` fallback get the variable's value from clap
+    let from_flag = |value: Option<&String>, flag: &str, name: &str| {
+        value.map(|value| match env(name) {
+            Some(v) if &v == value => (ConfigLayer::Env, Some(name.to_string())),
+            _ => (ConfigLayer::Cli, Some(flag.to_string())),
+        })
+    };
+
+    let profile_source = from_flag(overrides.profile.as_ref(), "--profile", "N8N_PROFILE")
+        .or_else(|| from_env("N8N_PROFILE"))
+        .or_else(|| {
+            file.and_then(|f| f.default_profile.as_ref())
+                .map(|_| from_file("default_profile".to_string()))
+        });
+    let profile_name = overrides
+        .profile
+        .clone()
+        .or_else(|| env("N8N_PROFILE"))
+        .or_else(|| file.and_then(|f| f.default_profile.clone()));
+    let profile: Option<&Profile> = profile_name
+        .as_ref()
+        .and_then(|name| file.and_then(|f| f.profiles.get(name)));
+    let profile_key = |key: &str| {
+        format!(
+            "profiles.{}.{}",
+            profile_name.as_deref().unwrap_or_default(),
+            key
+        )
+    };
+
+    let mut settings = Vec::new();
+    let mut add = |setting: &str, value: String, source: Source| {
+        settings.push(ConfigSetting {
+            setting: setting.to_string(),
+            value,
+            source: source.0,
+            origin: source.1,
+        });
+    };
+
+    add(
+        "profile",
+        profile_name.clone().unwrap_or_else(|| "-".to_string()),
+        profile_source.unwrap_or_else(default),
+    );
+
+    let source = from_flag(overrides.url.as_ref(), "--url", "N8N_BASE_URL")
+        .or_else(|| from_env("N8N_BASE_URL"))
+        .or_else(|| profile.map(|_| from_file(profile_key("base_url"))))
+        .unwrap_or_else(default);
+    add("base_url", config.base_url.clone(), source);
+
+    let source = from_flag(overrides.api_key.as_ref(), "--api-key", "N8N_API_KEY")
+        .or_else(|| from_env("N8N_API_KEY"))
+        .or_else(|| {
+            let profile = profile?;
+            match (&profile.api_key, &profile.api_key_env) {
+                (Some(_), _) => Some(from_file(profile_key("api_key"))),
+                (None, Some(var)) => from_env(var),
+                (None, None) => None,
+            }
+        })
+        .unwrap_or_else(default);
+    let api_key = if config.api_key.is_empty() {
+        "(not set)"
+    } else {
+        "(set)"
+    };
+    add("api_key", api_key.to_string(), source);
+
+    let source = if overrides.template.is_some() {
+        (ConfigLayer::Cli, Some("--format".to_string()))
+    } else if overrides.output.is_some() {
+        (ConfigLayer::Cli, Some("--output".to_string()))
+    } else if profile.is_some_and(|p| p.output_format.is_some()) {
+        from_file(profile_key("output_format"))
+    } else if file.is_some_and(|f| f.output_format.is_some()) {
+        from_file("output_format".to_string())
+    } else {
+        default()
+    };
+    let format = clap::ValueEnum::to_possible_value(&config.output.format)
+        .map_or_else(|| "template".to_string(), |v| v.get_name().to_string());
+    add("output", format, source);
+
+    let source = file
+        .and_then(|f| f.timeout_secs)
+        .map(|_| from_file("timeout_secs".to_string()))
+        .unwrap_or_else(default);
+    add("timeout_secs", config.timeout_secs.to_string(), source);
+
+    let retry = &config.retry;
+    let retry_settings: [(&str, String, RetryField); 5] = [
+        ("max_attempts", retry.max_attempts.to_string(), |r| {
+            r.max_attempts.is_some()
+        }),
+        ("base_delay_ms", retry.base_delay_ms.to_string(), |r| {
+            r.base_delay_ms.is_some()
+        }),
+        ("max_delay_ms", retry.max_delay_ms.to_string(), |r| {
+            r.max_delay_ms.is_some()
+        }),
+        ("jitter", retry.jitter.to_string(), |r| r.jitter.is_some()),
+        ("retry_post", retry.retry_post.to_string(), |r| {
+            r.retry_post.is_some()
+        }),
+    ];
+    for (key, value, is_set) in retry_settings {
+        let key = format!("retry.{}", key);
+        let source = if profile.and_then(|p| p.retry.as_ref()).is_some_and(is_set) {
+            from_file(profile_key(&key))
+        } else if file.and_then(|f| f.retry.as_ref()).is_some_and(is_set) {
+            from_file(key.clone())
+        } else {
+            default()
+        };
+        add(&key, value, source);
+    }
+
+    let transport = &config.transport;
+    let path = |p: &Option<PathBuf>| p.as_ref().map(|p| p.display().to_string());
+    let transport_settings = [
+        (
+            "proxy",
+            transport.proxy.clone(),
+            profile.is_some_and(|p| p.proxy.is_some()),
+        ),
+        (
+            "ca_cert",
+            path(&transport.ca_cert),
+            profile.is_some_and(|p| p.ca_cert.is_some()),
+        ),
+        (
+            "client_cert",
+            path(&transport.client_cert),
+            profile.is_some_and(|p| p.client_cert.is_some()),
+        ),
+        (
+            "client_key",
+            path(&transport.client_key),
+            profile.is_some_and(|p| p.client_key.is_some()),
+        ),
+        (
+            "insecure_skip_verify",
+            Some(transport.insecure_skip_verify.to_string()),
+            profile.is_some_and(|p| p.insecure_skip_verify.is_some()),
+        ),
+    ];
+    for (key, value, is_set) in transport_settings {
+        let source = if is_set {
+            from_file(profile_key(key))
+        } else {
+            default()
+        };
+        add(key, value.unwrap_or_else(|| "-".to_string()), source);
+    }
+
+    settings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides() -> CliOverrides {
+        CliOverrides {
+            profile: None,
+            url: None,
+            api_key: None,
+            output: None,
+            no_headers: false,
+            columns: Vec::new(),
+            sort_by: None,
+            reverse: false,
+            wide: false,
+            query: None,
+            raw: false,
+            template: None,
+            cassette: None,
+            verbose: 0,
+            trace_file: None,
+            quiet: false,
+        }
+    }
+
+    fn source(settings: &[ConfigSetting], name: &str) -> (ConfigLayer, Option<String>) {
+        let setting = settings.iter().find(|s| s.setting == name).unwrap();
+        (setting.source, setting.origin.clone())
+    }
+
+    #[test]
+    fn test_explain_layers() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+            default_profile = "prod"
+            timeout_secs = 60
+            retry = { max_attempts = 5 }
+
+            [profiles.prod]
+            base_url = "https://n8n.example.com"
+            api_key_env = "PROD_KEY"
+            output_format = "yaml"
+            retry = { jitter = false }
+            "#,
+        )
+        .unwrap();
+        let env = |name: &str| match name {
+            "PROD_KEY" | "N8N_API_KEY" => Some("secret".to_string()),
+            _ => None,
+        };
+        let overrides = CliOverrides {
+            url: Some("http://localhost:5678".to_string()),
+            api_key: Some("secret".to_string()),
+            ..overrides()
+        };
+        let config = Config {
+            api_key: "secret".to_string(),
+            ..Config::default()
+        };
+
+        let settings = explain(&config, &overrides, Some(&file), &env);
+        let file_key = |key: &str| (ConfigLayer::File, Some(key.to_string()));
+        assert_eq!(source(&settings, "profile"), file_key("default_profile"));
+        assert_eq!(
+            source(&settings, "base_url"),
+            (ConfigLayer::Cli, Some("--url".to_string()))
+        );
+        assert_eq!(
+            source(&settings, "api_key"),
+            (ConfigLayer::Env, Some("N8N_API_KEY".to_string()))
+        );
+        assert_eq!(
+            source(&settings, "output"),
+            file_key("profiles.prod.output_format")
+        );
+        assert_eq!(source(&settings, "timeout_secs"), file_key("timeout_secs"));
+        assert_eq!(
+            source(&settings, "retry.max_attempts"),
+            file_key("retry.max_attempts")
+        );
+        assert_eq!(
+            source(&settings, "retry.jitter"),
+            file_key("profiles.prod.retry.jitter")
+        );
+        assert_eq!(
+            source(&settings, "retry.retry_post"),
+            (ConfigLayer::Default, None)
+        );
+        assert_eq!(source(&settings, "proxy"), (ConfigLayer::Default, None));
+    }
+}
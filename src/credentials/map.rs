@@ -0,0 +1,64 @@
+use crate::error::{N8nError, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Explicit credential mappings from a `--credential-map` file
+///
+/// ```toml
+/// [credentials]
+/// "abc123" = "xyz789"          # by referenced credential ID
+/// "Slack (dev)" = "Slack bot"  # by referenced name, to a target name
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CredentialMap {
+    /// Referenced credential ID or name -> credential ID or name to use
+    #[serde(default)]
+    pub credentials: BTreeMap<String, String>,
+}
+
+impl CredentialMap {
+    /// Load a credential map file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        toml::from_str(&content).map_err(|e| N8nError::CredentialMapParse {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Mapping for a reference, by ID first and then by name
+    pub fn lookup(&self, id: Option<&str>, name: Option<&str>) -> Option<&str> {
+        [id, name]
+            .into_iter()
+            .flatten()
+            .find_map(|key| self.credentials.get(key))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_prefers_id() {
+        let map: CredentialMap = toml::from_str(
+            r#"
+            [credentials]
+            "abc" = "by-id"
+            "Slack" = "by-name"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(map.lookup(Some("abc"), Some("Slack")), Some("by-id"));
+        assert_eq!(map.lookup(Some("zzz"), Some("Slack")), Some("by-name"));
+        assert_eq!(map.lookup(None, Some("Other")), None);
+    }
+}
@@ -0,0 +1,5 @@
+mod map;
+mod resolve;
+
+pub use map::CredentialMap;
+pub use resolve::{CredentialResolution, CredentialResolver, ResolutionStatus};
@@ -0,0 +1,444 @@
+use crate::credentials::CredentialMap;
+use crate::models::{Credential, TypedWorkflow, WorkflowDefinition};
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// What happened to one credential reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResolutionStatus {
+    /// The referenced credential exists as-is
+    Kept,
+    /// The reference now points to a credential with the same name and type
+    Remapped,
+    /// The reference was set by the credential map
+    Mapped,
+    /// No matching credential was found
+    Unresolved,
+}
+
+impl std::fmt::Display for ResolutionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Kept => write!(f, "kept"),
+            Self::Remapped => write!(f, "remapped"),
+            Self::Mapped => write!(f, "mapped"),
+            Self::Unresolved => write!(f, "unresolved"),
+        }
+    }
+}
+
+/// A credential reference of a node and how it was resolved
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialResolution {
+    pub node: String,
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub name: Option<String>,
+    pub from: Option<String>,
+    /// Credential ID now referenced (for unresolved references, the mapped value if any)
+    pub to: Option<String>,
+    pub status: ResolutionStatus,
+}
+
+impl Outputable for CredentialResolution {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("node", "NODE", |r: &Self| r.node.clone()).truncate(30),
+            Column::new("type", "TYPE", |r: &Self| r.credential_type.clone()),
+            Column::new("name", "NAME", |r: &Self| or_dash(&r.name)).truncate(30),
+            Column::new("from", "FROM", |r: &Self| or_dash(&r.from)),
+            Column::new("to", "TO", |r: &Self| or_dash(&r.to)),
+            Column::new("status", "STATUS", |r: &Self| r.status.to_string()),
+        ]
+    }
+}
+
+fn or_dash(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+/// Points node credential references at credentials that exist on an instance
+pub struct CredentialResolver {
+    /// Credentials of the instance (`None` if they couldn't be listed)
+    available: Option<Vec<Credential>>,
+    map: CredentialMap,
+    /// Whether IDs can decide a match (they are only meaningful on the same instance)
+    match_ids: bool,
+}
+
+impl CredentialResolver {
+    /// Resolve against the credentials of the target instance
+    pub fn new(available: Vec<Credential>) -> Self {
+        Self {
+            available: Some(available),
+            map: CredentialMap::default(),
+            match_ids: true,
+        }
+    }
+
+    /// Only apply explicit mappings, trusting the mapped IDs
+    pub fn map_only(map: CredentialMap) -> Self {
+        Self {
+            available: None,
+            map,
+            match_ids: true,
+        }
+    }
+
+    /// Apply explicit mappings before matching by name and type
+    pub fn with_map(mut self, map: CredentialMap) -> Self {
+        self.map = map;
+        self
+    }
+
+    /// Match by name and type only, for references from another instance
+    ///
+    /// IDs are local to an instance, so an ID that happens to exist on the
+    /// target says nothing about the credential it belongs to.
+    pub fn names_only(mut self) -> Self {
+        self.match_ids = false;
+        self
+    }
+
+    /// Rewrite the credential references of every node in place
+    ///
+    /// A reference matches a credential of the same type and name; when the
+    /// name is missing or ambiguous, the ID is used unless `names_only` is
+    /// set. References without a match are left untouched and reported as
+    /// unresolved.
+    pub fn resolve(&self, workflow: &mut TypedWorkflow) -> Vec<CredentialResolution> {
+        let mut resolutions = Vec::new();
+        for node in &mut workflow.nodes {
+            if let Some(refs) = &mut node.credentials {
+                self.resolve_node(&node.name, refs, &mut resolutions);
+            }
+        }
+        resolutions
+    }
+
+    /// Same as `resolve`, for a workflow read as a plain definition
+    pub fn resolve_definition(
+        &self,
+        workflow: &mut WorkflowDefinition,
+    ) -> Vec<CredentialResolution> {
+        let mut resolutions = Vec::new();
+        for node in &mut workflow.nodes {
+            let name = node
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            if let Some(refs) = node.get_mut("credentials") {
+                self.resolve_node(&name, refs, &mut resolutions);
+            }
+        }
+        resolutions
+    }
+
+    fn resolve_node(
+        &self,
+        node: &str,
+        refs: &mut Value,
+        resolutions: &mut Vec<CredentialResolution>,
+    ) {
+        let Value::Object(refs) = refs else {
+            return;
+        };
+
+        for (credential_type, reference) in refs.iter_mut() {
+            let id = reference
+                .get("id")
+                .and_then(Value::as_str)
+                .map(String::from);
+            let name = reference
+                .get("name")
+                .and_then(Value::as_str)
+                .map(String::from);
+
+            let (target, status) = self.target_for(credential_type, id.as_deref(), name.as_deref());
+            if let Some((target_id, target_name)) = &target {
+                *reference = match target_name {
+                    Some(target_name) => json!({ "id": target_id, "name": target_name }),
+                    None => json!({ "id": target_id }),
+                };
+            }
+
+            resolutions.push(CredentialResolution {
+                node: node.to_string(),
+                credential_type: credential_type.clone(),
+                to: match status {
+                    ResolutionStatus::Unresolved => self
+                        .map
+                        .lookup(id.as_deref(), name.as_deref())
+                        .map(String::from),
+                    _ => target.map(|(id, _)| id).or_else(|| id.clone()),
+                },
+                name,
+                from: id,
+                status,
+            });
+        }
+    }
+
+    /// Credential ID and name a reference should use
+    fn target_for(
+        &self,
+        credential_type: &str,
+        id: Option<&str>,
+        name: Option<&str>,
+    ) -> (Option<(String, Option<String>)>, ResolutionStatus) {
+        let found = |c: &Credential| (c.id.clone(), Some(c.name.clone()));
+
+        if let Some(mapped) = self.map.lookup(id, name) {
+            let Some(available) = &self.available else {
+                let target = (mapped.to_string(), name.map(String::from));
+                return (Some(target), ResolutionStatus::Mapped);
+            };
+            // The mapped value can be a credential ID or name
+            let of_type = || {
+                available
+                    .iter()
+                    .filter(|c| c.credential_type == credential_type)
+            };
+            return match of_type()
+                .find(|c| c.id == mapped)
+                .or_else(|| of_type().find(|c| c.name == mapped))
+            {
+                Some(c) => (Some(found(c)), ResolutionStatus::Mapped),
+                None => (None, ResolutionStatus::Unresolved),
+            };
+        }
+
+        let Some(available) = &self.available else {
+            return (None, ResolutionStatus::Kept);
+        };
+        let match_id = id.filter(|_| self.match_ids);
+        match find(available, credential_type, match_id, name) {
+            Some(c) if Some(c.id.as_str()) == id => (Some(found(c)), ResolutionStatus::Kept),
+            Some(c) => (Some(found(c)), ResolutionStatus::Remapped),
+            None => (None, ResolutionStatus::Unresolved),
+        }
+    }
+}
+
+/// Credential of the type with this name, or with this ID if the name doesn't decide
+///
+/// A name that matches nothing is not overridden by the ID: on another
+/// instance, the same ID can belong to an unrelated credential.
+fn find<'a>(
+    available: &'a [Credential],
+    credential_type: &str,
+    id: Option<&str>,
+    name: Option<&str>,
+) -> Option<&'a Credential> {
+    let of_type = || {
+        available
+            .iter()
+            .filter(move |c| c.credential_type == credential_type)
+    };
+    let by_id = || of_type().find(|c| Some(c.id.as_str()) == id);
+
+    let by_name: Vec<&Credential> = of_type()
+        .filter(|c| Some(c.name.as_str()) == name)
+        .collect();
+    match by_name.as_slice() {
+        [only] => Some(only),
+        [] if name.is_some() => None,
+        [] => by_id(),
+        // Several credentials share the name: only the ID can tell them apart
+        _ => by_id().filter(|c| Some(c.name.as_str()) == name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Node;
+    use std::collections::HashMap;
+
+    fn credential(id: &str, name: &str, credential_type: &str) -> Credential {
+        Credential {
+            id: id.to_string(),
+            name: name.to_string(),
+            credential_type: credential_type.to_string(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn workflow(refs: Vec<(&str, Value)>) -> TypedWorkflow {
+        let nodes = refs
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, credentials))| {
+                let mut node = Node::new(i.to_string(), name.to_string(), "type".to_string());
+                node.credentials = Some(credentials);
+                node
+            })
+            .collect();
+        TypedWorkflow {
+            id: None,
+            name: "Test".to_string(),
+            active: false,
+            nodes,
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_by_name_then_id() {
+        let resolver = CredentialResolver::new(vec![
+            credential("10", "Slack", "slackApi"),
+            credential("20", "Postgres", "postgres"),
+            credential("30", "Slack", "httpHeaderAuth"),
+        ]);
+        let mut wf = workflow(vec![
+            (
+                "Notify",
+                json!({ "slackApi": { "id": "1", "name": "Slack" } }),
+            ),
+            ("Query", json!({ "postgres": { "id": "20" } })),
+            (
+                "Call",
+                json!({ "githubApi": { "id": "5", "name": "GitHub" } }),
+            ),
+        ]);
+
+        let report = resolver.resolve(&mut wf);
+        let statuses: Vec<_> = report.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ResolutionStatus::Remapped,
+                ResolutionStatus::Kept,
+                ResolutionStatus::Unresolved
+            ]
+        );
+
+        assert_eq!(
+            wf.nodes[0].credentials,
+            Some(json!({ "slackApi": { "id": "10", "name": "Slack" } }))
+        );
+        // Kept references get the current name
+        assert_eq!(
+            wf.nodes[1].credentials,
+            Some(json!({ "postgres": { "id": "20", "name": "Postgres" } }))
+        );
+        assert_eq!(
+            wf.nodes[2].credentials,
+            Some(json!({ "githubApi": { "id": "5", "name": "GitHub" } }))
+        );
+    }
+
+    #[test]
+    fn test_unknown_name_ignores_matching_id() {
+        // ID 20 belongs to another credential on this instance
+        let resolver = CredentialResolver::new(vec![credential("20", "Postgres", "postgres")]);
+        let mut wf = workflow(vec![(
+            "Query",
+            json!({ "postgres": { "id": "20", "name": "Postgres (dev)" } }),
+        )]);
+
+        let report = resolver.resolve(&mut wf);
+        assert_eq!(report[0].status, ResolutionStatus::Unresolved);
+        assert_eq!(
+            wf.nodes[0].credentials,
+            Some(json!({ "postgres": { "id": "20", "name": "Postgres (dev)" } }))
+        );
+    }
+
+    #[test]
+    fn test_credential_map() {
+        let available = vec![
+            credential("1", "Slack bot", "slackApi"),
+            credential("2", "Slack alerts", "slackApi"),
+        ];
+        let map: CredentialMap = toml::from_str(
+            r#"
+            [credentials]
+            "dev-1" = "Slack alerts"
+            "dev-2" = "99"
+            "#,
+        )
+        .unwrap();
+        let refs = || {
+            vec![
+                (
+                    "A",
+                    json!({ "slackApi": { "id": "dev-1", "name": "Slack bot" } }),
+                ),
+                ("B", json!({ "slackApi": { "id": "dev-2", "name": "Old" } })),
+            ]
+        };
+
+        // Mapped values are looked up by ID or name on the instance
+        let mut wf = workflow(refs());
+        let report = CredentialResolver::new(available)
+            .with_map(map.clone())
+            .resolve(&mut wf);
+        assert_eq!(report[0].status, ResolutionStatus::Mapped);
+        assert_eq!(report[0].to.as_deref(), Some("2"));
+        assert_eq!(report[1].status, ResolutionStatus::Unresolved);
+        assert_eq!(report[1].to.as_deref(), Some("99"));
+
+        // Without the instance's credentials, mapped IDs are trusted
+        let mut wf = workflow(refs());
+        let report = CredentialResolver::map_only(map).resolve(&mut wf);
+        assert_eq!(report[1].status, ResolutionStatus::Mapped);
+        assert_eq!(
+            wf.nodes[1].credentials,
+            Some(json!({ "slackApi": { "id": "99", "name": "Old" } }))
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_name_needs_id() {
+        let resolver = CredentialResolver::new(vec![
+            credential("1", "Slack", "slackApi"),
+            credential("2", "Slack", "slackApi"),
+        ]);
+        let mut wf = workflow(vec![
+            ("A", json!({ "slackApi": { "id": "2", "name": "Slack" } })),
+            ("B", json!({ "slackApi": { "id": "9", "name": "Slack" } })),
+        ]);
+
+        let report = resolver.resolve(&mut wf);
+        assert_eq!(report[0].status, ResolutionStatus::Kept);
+        assert_eq!(report[1].status, ResolutionStatus::Unresolved);
+    }
+
+    #[test]
+    fn test_names_only_ignores_ids() {
+        let resolver = CredentialResolver::new(vec![
+            credential("3", "Slack prod", "slackApi"),
+            credential("4", "Postgres", "postgres"),
+            credential("5", "Postgres", "postgres"),
+        ])
+        .names_only();
+        let mut wf = workflow(vec![
+            (
+                "Notify",
+                json!({ "slackApi": { "id": "3", "name": "Slack dev" } }),
+            ),
+            (
+                "Query",
+                json!({ "postgres": { "id": "4", "name": "Postgres" } }),
+            ),
+        ]);
+
+        let report = resolver.resolve(&mut wf);
+        assert_eq!(report[0].status, ResolutionStatus::Unresolved);
+        assert_eq!(report[0].to, None);
+        assert_eq!(report[1].status, ResolutionStatus::Unresolved);
+        assert_eq!(
+            wf.nodes[0].credentials,
+            Some(json!({ "slackApi": { "id": "3", "name": "Slack dev" } }))
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::models::{Connection, Node, TypedWorkflow};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Three-way merge of two concurrent edits of the same workflow
+///
+/// `base` is the version both sides started from, `ours` the local edit and
+/// `theirs` the current server version. Changes merge cleanly when each node,
+/// the name and the settings were changed on at most one side (or identically
+/// on both). Returns the list of conflicting items otherwise.
+pub fn three_way_merge(
+    base: &TypedWorkflow,
+    ours: &TypedWorkflow,
+    theirs: &TypedWorkflow,
+) -> Result<TypedWorkflow, Vec<String>> {
+    let mut conflicts = Vec::new();
+    let mut merged = theirs.clone();
+
+    // Workflow-level fields
+    match pick(&base.name, &ours.name, &theirs.name) {
+        Some(name) => merged.name = name.clone(),
+        None => conflicts.push("workflow name".to_string()),
+    }
+    match pick(&base.settings, &ours.settings, &theirs.settings) {
+        Some(settings) => merged.settings = settings.clone(),
+        None => conflicts.push("workflow settings".to_string()),
+    }
+
+    // Nodes, keyed by ID; server order first, then nodes only we added
+    let mut ids: Vec<&str> = theirs.nodes.iter().map(|n| n.id.as_str()).collect();
+    for node in &ours.nodes {
+        if !ids.contains(&node.id.as_str()) {
+            ids.push(&node.id);
+        }
+    }
+    for node in &base.nodes {
+        if !ids.contains(&node.id.as_str()) {
+            ids.push(&node.id);
+        }
+    }
+
+    let mut nodes = Vec::new();
+    for id in ids {
+        let b = find(base, id);
+        let o = find(ours, id);
+        let t = find(theirs, id);
+
+        match pick(&b, &o, &t) {
+            Some(Some(node)) => nodes.push(Node::clone(node)),
+            Some(None) => {}
+            None => {
+                let name = o.or(t).or(b).map(|n| n.name.as_str()).unwrap_or(id);
+                conflicts.push(format!("node '{}'", name));
+            }
+        }
+    }
+    merged.nodes = nodes;
+
+    // Connections: apply our additions and removals on top of the server version
+    let base_conns = base.connections_flat();
+    let ours_conns = ours.connections_flat();
+    let mut conns = theirs.connections_flat();
+
+    for conn in &base_conns {
+        if !ours_conns.contains(conn) {
+            conns.retain(|c| c != conn);
+        }
+    }
+    for conn in &ours_conns {
+        if !base_conns.contains(conn) && !conns.contains(conn) {
+            conns.push(conn.clone());
+        }
+    }
+
+    let names: HashSet<&str> = merged.nodes.iter().map(|n| n.name.as_str()).collect();
+    for conn in &conns {
+        if !names.contains(conn.source_node.as_str()) || !names.contains(conn.target_node.as_str())
+        {
+            conflicts.push(format!(
+                "connection {} -> {}",
+                conn.source_node, conn.target_node
+            ));
+        }
+    }
+    merged.connections = Connection::to_connections_map(&conns);
+
+    if conflicts.is_empty() {
+        Ok(merged)
+    } else {
+        Err(conflicts)
+    }
+}
+
+fn find<'a>(workflow: &'a TypedWorkflow, id: &str) -> Option<&'a Node> {
+    workflow.nodes.iter().find(|n| n.id == id)
+}
+
+/// Resolve one value: take the side that changed, or `None` on a real conflict
+fn pick<'a, T: Serialize>(base: &'a T, ours: &'a T, theirs: &'a T) -> Option<&'a T> {
+    let b = serde_json::to_value(base).ok();
+    let o = serde_json::to_value(ours).ok();
+    let t = serde_json::to_value(theirs).ok();
+
+    if o == b || o == t {
+        Some(theirs)
+    } else if t == b {
+        Some(ours)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn workflow(nodes: Vec<Node>) -> TypedWorkflow {
+        TypedWorkflow {
+            id: Some("wf".into()),
+            name: "Test".into(),
+            active: false,
+            nodes,
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_disjoint_node_changes() {
+        let base = workflow(vec![
+            Node::new("1".into(), "A".into(), "type".into()),
+            Node::new("2".into(), "B".into(), "type".into()),
+        ]);
+
+        let mut ours = base.clone();
+        ours.nodes[0].disabled = true;
+        ours.add_connection(Connection::new("A".into(), "B".into()));
+
+        let mut theirs = base.clone();
+        theirs.nodes[1].position.x = 500;
+
+        let merged = three_way_merge(&base, &ours, &theirs).unwrap();
+        assert!(merged.nodes[0].disabled);
+        assert_eq!(merged.nodes[1].position.x, 500);
+        assert_eq!(merged.connections_flat().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_conflict_on_same_node() {
+        let base = workflow(vec![Node::new("1".into(), "A".into(), "type".into())]);
+
+        let mut ours = base.clone();
+        ours.nodes[0].position.x = 100;
+        let mut theirs = base.clone();
+        theirs.nodes[0].position.x = 200;
+
+        let conflicts = three_way_merge(&base, &ours, &theirs).unwrap_err();
+        assert_eq!(conflicts, vec!["node 'A'".to_string()]);
+    }
+}
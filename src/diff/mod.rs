@@ -1,3 +1,5 @@
+mod merge;
 mod workflow_diff;
 
+pub use merge::three_way_merge;
 pub use workflow_diff::{NodeChange, NodeDiff, WorkflowDiff};
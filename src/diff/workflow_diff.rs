@@ -186,6 +186,39 @@ impl WorkflowDiff {
             && self.connections_removed.is_empty()
     }
 
+    /// One line per changed item, e.g. `node 'Set' modified`
+    pub fn changed_items(&self) -> Vec<String> {
+        let mut items = Vec::new();
+        if let Some((old, new)) = &self.name_changed {
+            items.push(format!("name \"{}\" -> \"{}\"", old, new));
+        }
+        if let Some((old, new)) = self.active_changed {
+            items.push(format!("active {} -> {}", old, new));
+        }
+        for node in &self.nodes_added {
+            items.push(format!("node '{}' added", node.name));
+        }
+        for node in &self.nodes_removed {
+            items.push(format!("node '{}' removed", node.name));
+        }
+        for node_diff in &self.nodes_modified {
+            items.push(format!("node '{}' modified", node_diff.node_name));
+        }
+        for conn in &self.connections_added {
+            items.push(format!(
+                "connection {} -> {} added",
+                conn.source_node, conn.target_node
+            ));
+        }
+        for conn in &self.connections_removed {
+            items.push(format!(
+                "connection {} -> {} removed",
+                conn.source_node, conn.target_node
+            ));
+        }
+        items
+    }
+
     /// Print summary to stdout
     pub fn print_summary(&self) {
         if self.is_empty() {
@@ -0,0 +1,378 @@
+use crate::client::endpoints::workflows::WorkflowListParams;
+use crate::client::N8nClient;
+use crate::config::{
+    config_file_path, load_config, read_config_file, selected_profile, CliOverrides, Config,
+    ConfigFile,
+};
+use crate::doctor::{CheckStatus, DoctorCheck};
+use crate::error::N8nError;
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use serde_json::Value;
+use std::time::Instant;
+
+/// Largest difference to the server's clock that is not reported
+const MAX_CLOCK_SKEW_SECS: i64 = 30;
+
+/// Run every check in order
+///
+/// Checks that depend on a failed one are reported as skipped, so the
+/// report always lists the same checks.
+pub async fn run_doctor(overrides: CliOverrides) -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+    let explicit_profile = overrides.profile.clone();
+
+    let file_config = match check_config_file() {
+        Ok((check, file_config)) => {
+            checks.push(check);
+            file_config
+        }
+        Err(check) => {
+            checks.push(check);
+            return with_skipped(checks, "the config file can't be read");
+        }
+    };
+
+    let profile = selected_profile(explicit_profile.as_deref(), &file_config);
+    let config = match load_config(overrides) {
+        Ok(config) => {
+            checks.push(match &profile {
+                Some(name) => DoctorCheck::pass("Profile", format!("Using profile '{}'", name)),
+                None => DoctorCheck::pass("Profile", "No profile; using environment and flags"),
+            });
+            config
+        }
+        Err(e) => {
+            let mut check = DoctorCheck::fail("Profile", e.to_string());
+            if matches!(e, N8nError::ProfileNotFound(_)) {
+                check = check.with_hint(profile_hint(&file_config));
+            }
+            checks.push(check);
+            return with_skipped(checks, "the configuration can't be loaded");
+        }
+    };
+
+    let base_url = check_base_url(&config);
+    let url_ok = base_url.status != CheckStatus::Fail;
+    checks.push(base_url);
+    checks.push(check_api_key(&config, profile.as_deref(), &file_config));
+    if !url_ok {
+        return with_skipped(checks, "the base URL is invalid");
+    }
+
+    let client = match N8nClient::new(&config) {
+        Ok(client) => client,
+        Err(e) => {
+            checks.push(
+                DoctorCheck::fail("HTTP client", e.to_string())
+                    .with_hint("Check the proxy and certificate settings of the profile"),
+            );
+            return with_skipped(checks, "the HTTP client can't be created");
+        }
+    };
+
+    // Connection, TLS and health all come from the /healthz request
+    let started = Instant::now();
+    let health = client.health_check().await;
+    let https = config.base_url.starts_with("https://");
+    match health {
+        Ok(health) => {
+            checks.push(DoctorCheck::pass(
+                "Connection",
+                format!(
+                    "Reached {} in {} ms",
+                    config.base_url,
+                    started.elapsed().as_millis()
+                ),
+            ));
+            if https {
+                checks.push(check_tls_settings(&config));
+            }
+            checks.push(if health.status == "ok" {
+                DoctorCheck::pass("Health", "/healthz reports ok")
+            } else {
+                DoctorCheck::fail("Health", format!("/healthz reports {}", health.status))
+                    .with_hint("Check the n8n server logs")
+            });
+        }
+        Err(e) => {
+            checks.extend(connection_failure(&e, https));
+            return with_skipped(checks, "the instance can't be reached");
+        }
+    }
+
+    checks.push(match client.readiness_check().await {
+        Ok(readiness) if readiness.status == "ready" => {
+            DoctorCheck::pass("Readiness", "/healthz/readiness reports ready")
+        }
+        Ok(readiness) => DoctorCheck::warn(
+            "Readiness",
+            format!("/healthz/readiness reports {}", readiness.status),
+        )
+        .with_hint("n8n may still be starting, or its database is unavailable"),
+        Err(e) => DoctorCheck::warn("Readiness", e.to_string()),
+    });
+
+    checks.push(check_authentication(&client, &config).await);
+
+    let (version, clock) = check_server_settings(&client).await;
+    checks.push(version);
+    checks.push(clock);
+    checks
+}
+
+fn check_config_file() -> Result<(DoctorCheck, ConfigFile), DoctorCheck> {
+    let Some(path) = config_file_path() else {
+        return Ok((
+            DoctorCheck::warn("Config file", "No config directory on this platform"),
+            ConfigFile::default(),
+        ));
+    };
+    match read_config_file() {
+        Ok(Some(file_config)) => Ok((
+            DoctorCheck::pass("Config file", format!("Found {}", path.display())),
+            file_config,
+        )),
+        Ok(None) if std::env::var("N8N_BASE_URL").is_ok() => Ok((
+            DoctorCheck::pass(
+                "Config file",
+                format!("Not present at {}; using N8N_BASE_URL", path.display()),
+            ),
+            ConfigFile::default(),
+        )),
+        Ok(None) => Ok((
+            DoctorCheck::warn("Config file", format!("Not found at {}", path.display())).with_hint(
+                "Create it with `n8n config profiles add`, or set N8N_BASE_URL and N8N_API_KEY",
+            ),
+            ConfigFile::default(),
+        )),
+        Err(e) => Err(DoctorCheck::fail("Config file", e.to_string())
+            .with_hint(format!("Fix the TOML syntax in {}", path.display()))),
+    }
+}
+
+fn profile_hint(file_config: &ConfigFile) -> String {
+    let mut names: Vec<&str> = file_config.profiles.keys().map(String::as_str).collect();
+    names.sort();
+    if names.is_empty() {
+        "No profiles are defined; add one with `n8n config profiles add`".to_string()
+    } else {
+        format!(
+            "Available profiles: {}. Check --profile, N8N_PROFILE and default_profile",
+            names.join(", ")
+        )
+    }
+}
+
+fn check_base_url(config: &Config) -> DoctorCheck {
+    let url = match url::Url::parse(&config.base_url) {
+        Ok(url) => url,
+        Err(e) => {
+            return DoctorCheck::fail(
+                "Base URL",
+                format!("'{}' is invalid: {}", config.base_url, e),
+            )
+            .with_hint("Use the instance root, e.g. https://n8n.example.com");
+        }
+    };
+    let local = matches!(
+        url.host_str(),
+        Some("localhost" | "127.0.0.1" | "::1" | "[::1]")
+    );
+    match url.scheme() {
+        "https" => DoctorCheck::pass("Base URL", config.base_url.clone()),
+        "http" if local => DoctorCheck::pass("Base URL", config.base_url.clone()),
+        "http" => DoctorCheck::warn("Base URL", format!("{} is not encrypted", config.base_url))
+            .with_hint("Use https:// so the API key isn't sent in clear text"),
+        scheme => DoctorCheck::fail("Base URL", format!("Unsupported scheme '{}'", scheme))
+            .with_hint("Use an http:// or https:// URL"),
+    }
+}
+
+fn check_api_key(config: &Config, profile: Option<&str>, file_config: &ConfigFile) -> DoctorCheck {
+    if !config.api_key.is_empty() {
+        return DoctorCheck::pass("API key", "Configured");
+    }
+    let key_env = profile
+        .and_then(|name| file_config.profiles.get(name))
+        .and_then(|p| p.api_key_env.as_deref());
+    let check = DoctorCheck::fail("API key", "Not configured");
+    match key_env {
+        Some(var) => check.with_hint(format!(
+            "The profile reads the key from {}, which is not set",
+            var
+        )),
+        None => check.with_hint("Set N8N_API_KEY, pass --api-key, or add api_key to the profile"),
+    }
+}
+
+fn check_tls_settings(config: &Config) -> DoctorCheck {
+    if config.transport.insecure_skip_verify {
+        DoctorCheck::warn("TLS", "Certificate verification is disabled")
+            .with_hint("Set ca_cert instead of insecure_skip_verify")
+    } else {
+        DoctorCheck::pass("TLS", "Server certificate verified")
+    }
+}
+
+/// Connection and TLS checks for a failed /healthz request
+fn connection_failure(error: &N8nError, https: bool) -> Vec<DoctorCheck> {
+    let N8nError::ConnectionFailed { message, .. } = error else {
+        return vec![DoctorCheck::fail("Connection", error.to_string())];
+    };
+    let lower = message.to_lowercase();
+    if https
+        && ["certificate", "tls", "handshake", "ssl"]
+            .iter()
+            .any(|w| lower.contains(w))
+    {
+        return vec![
+            DoctorCheck::pass("Connection", "Server reached"),
+            DoctorCheck::fail("TLS", message.clone()).with_hint(
+                "Set ca_cert to the CA that signed the server certificate, \
+                 or client_cert/client_key if the server requires one",
+            ),
+        ];
+    }
+    let hint = if message == "Request timed out" {
+        "Check the proxy settings, or increase timeout_secs"
+    } else {
+        "Check that n8n is running, the URL and port are right, and any proxy is reachable"
+    };
+    vec![DoctorCheck::fail("Connection", message.clone()).with_hint(hint)]
+}
+
+/// Authenticated probe: list one workflow
+async fn check_authentication(client: &N8nClient, config: &Config) -> DoctorCheck {
+    if config.api_key.is_empty() {
+        return DoctorCheck::skip("Authentication", "Skipped: no API key");
+    }
+    let params = WorkflowListParams {
+        limit: Some(1),
+        ..Default::default()
+    };
+    match client.list_workflows(&params).await {
+        Ok(_) => DoctorCheck::pass("Authentication", "API key accepted"),
+        Err(N8nError::Api(e)) if e.code == 401 || e.code == 403 => {
+            DoctorCheck::fail("Authentication", format!("API key rejected ({})", e.code)).with_hint(
+                "Create a new key under Settings > n8n API and check it's for this instance",
+            )
+        }
+        Err(N8nError::Api(e)) if e.code == 404 => {
+            DoctorCheck::fail("Authentication", "Public API not found at /api/v1")
+                .with_hint("Check that the base URL is the n8n root and the public API is enabled")
+        }
+        Err(e) => DoctorCheck::fail("Authentication", e.to_string()),
+    }
+}
+
+/// n8n version and clock skew, from `/rest/settings` and its `Date` header
+async fn check_server_settings(client: &N8nClient) -> (DoctorCheck, DoctorCheck) {
+    let url = client.root_url("/rest/settings");
+    let response = match client.send_public(client.http().get(&url), &url).await {
+        Ok(response) => response,
+        Err(e) => {
+            return (
+                DoctorCheck::warn("Version", format!("Could not read {}: {}", url, e)),
+                DoctorCheck::skip("Clock", "Skipped: no response"),
+            );
+        }
+    };
+
+    let clock = match response
+        .headers()
+        .get(header::DATE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| DateTime::parse_from_rfc2822(v).ok())
+    {
+        Some(server_time) => check_clock(server_time.with_timezone(&Utc), Utc::now()),
+        None => DoctorCheck::skip("Clock", "Skipped: the server sent no Date header"),
+    };
+
+    let settings: Value = response.json().await.unwrap_or_default();
+    let version = match settings["data"]["versionCli"].as_str() {
+        Some(version) => DoctorCheck::pass("Version", format!("n8n {}, public API v1", version)),
+        None => DoctorCheck::warn("Version", "Could not determine the n8n version")
+            .with_hint("The instance may hide /rest/settings; other checks are unaffected"),
+    };
+    (version, clock)
+}
+
+fn check_clock(server: DateTime<Utc>, local: DateTime<Utc>) -> DoctorCheck {
+    let skew = (local - server).num_seconds();
+    if skew.abs() <= MAX_CLOCK_SKEW_SECS {
+        DoctorCheck::pass("Clock", format!("Within {}s of the server", skew.abs()))
+    } else {
+        let direction = if skew > 0 { "ahead of" } else { "behind" };
+        DoctorCheck::warn("Clock", format!("{}s {} the server", skew.abs(), direction)).with_hint(
+            "Synchronize the system clock (e.g. with NTP); execution times are shown in server time",
+        )
+    }
+}
+
+/// Append the checks that weren't run
+fn with_skipped(mut checks: Vec<DoctorCheck>, reason: &str) -> Vec<DoctorCheck> {
+    const ALL: &[&str] = &[
+        "Config file",
+        "Profile",
+        "Base URL",
+        "API key",
+        "Connection",
+        "Health",
+        "Readiness",
+        "Authentication",
+        "Version",
+        "Clock",
+    ];
+    for name in ALL {
+        if !checks.iter().any(|c| c.name == *name) {
+            checks.push(DoctorCheck::skip(name, format!("Skipped: {}", reason)));
+        }
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_connection_failure() {
+        let error = N8nError::ConnectionFailed {
+            url: "https://n8n.internal".to_string(),
+            message: "invalid peer certificate: UnknownIssuer".to_string(),
+        };
+        let checks = connection_failure(&error, true);
+        assert_eq!(checks[0].status, CheckStatus::Pass);
+        assert_eq!(checks[1].name, "TLS");
+        assert_eq!(checks[1].status, CheckStatus::Fail);
+
+        let error = N8nError::ConnectionFailed {
+            url: "http://localhost:5678".to_string(),
+            message: "tcp connect error: Connection refused (os error 111)".to_string(),
+        };
+        let checks = connection_failure(&error, false);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_check_clock() {
+        let now = Utc::now();
+        assert_eq!(
+            check_clock(now, now + Duration::seconds(5)).status,
+            CheckStatus::Pass
+        );
+        let check = check_clock(now, now - Duration::seconds(120));
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.message.contains("behind"));
+    }
+
+    #[test]
+    fn test_with_skipped() {
+        let checks = with_skipped(vec![DoctorCheck::pass("Config file", "Found")], "reason");
+        assert_eq!(checks.len(), 10);
+        assert!(checks[1..].iter().all(|c| c.status == CheckStatus::Skip));
+    }
+}
@@ -0,0 +1,5 @@
+mod checks;
+mod report;
+
+pub use checks::run_doctor;
+pub use report::{CheckStatus, DoctorCheck};
@@ -26,6 +26,19 @@ pub enum N8nError {
     #[error("Failed to parse config file: {0}")]
     ConfigFileParse(#[source] toml::de::Error),
 
+    #[error("Failed to parse lint config '{path}': {source}")]
+    LintConfigParse {
+        path: String,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("Failed to parse credential map '{path}': {message}")]
+    CredentialMapParse { path: String, message: String },
+
+    #[error("Invalid certificate or key '{path}': {message}")]
+    InvalidCertificate { path: String, message: String },
+
     // HTTP/Network errors
     #[error("HTTP client error: {0}")]
     HttpClient(#[source] reqwest::Error),
@@ -37,6 +50,9 @@ pub enum N8nError {
     ConnectionFailed { url: String, message: String },
 
     // API errors
+    #[error("No recorded response for {method} {path}\n\nHint: Record the command again with --record")]
+    CassetteMiss { method: String, path: String },
+
     #[error("{}", .0.user_message())]
     Api(ApiErrorResponse),
 
@@ -47,9 +63,34 @@ pub enum N8nError {
     #[error("Failed to parse input: {0}")]
     InvalidInput(#[source] serde_json::Error),
 
+    #[error("Failed to parse YAML input: {0}")]
+    InvalidYamlInput(#[source] serde_norway::Error),
+
     #[error("Failed to serialize data: {0}")]
     Serialize(#[source] serde_json::Error),
 
+    #[error("Failed to serialize data as YAML: {0}")]
+    YamlSerialize(#[source] serde_norway::Error),
+
+    #[error("Unknown column '{name}'. Available columns: {available}")]
+    UnknownColumn { name: String, available: String },
+
+    #[error("Invalid query '{query}': {message}")]
+    InvalidQuery { query: String, message: String },
+
+    #[error("Invalid format template '{template}': {message}")]
+    InvalidTemplate { template: String, message: String },
+
+    #[error("{option} is not supported by {command}")]
+    UnsupportedOption { option: String, command: String },
+
+    #[error("Failed to parse '{path}': {source}")]
+    InvalidFile {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
     // Resource errors
     #[error("Workflow '{0}' not found")]
     WorkflowNotFound(String),
@@ -60,6 +101,16 @@ pub enum N8nError {
     #[error("Credential '{0}' not found")]
     CredentialNotFound(String),
 
+    // Execution outcomes
+    #[error("Execution '{id}' finished with status '{status}'")]
+    ExecutionFailed { id: String, status: String },
+
+    #[error("Execution '{0}' was canceled")]
+    ExecutionCanceled(String),
+
+    #[error("Timed out after {secs}s waiting for execution '{id}'")]
+    ExecutionTimeout { id: String, secs: u64 },
+
     // I/O errors
     #[error("Failed to read file '{path}': {source}")]
     FileRead {
@@ -78,6 +129,13 @@ pub enum N8nError {
     #[error("Failed to read from stdin: {0}")]
     StdinRead(#[source] std::io::Error),
 
+    #[error("Failed to start mock server on {addr}: {source}")]
+    MockServer {
+        addr: String,
+        #[source]
+        source: std::io::Error,
+    },
+
     // User interaction
     #[error("Operation cancelled by user")]
     Cancelled,
@@ -86,17 +144,63 @@ pub enum N8nError {
     #[error("Node '{0}' not found in workflow")]
     NodeNotFound(String),
 
+    #[error("A node named '{0}' already exists in the workflow")]
+    DuplicateNodeName(String),
+
+    #[error("Invalid node name: '{0}'")]
+    InvalidNodeName(String),
+
     #[error("Connection not found: {from} -> {to}")]
     ConnectionNotFound { from: String, to: String },
 
     #[error("Validation failed:\n{0}")]
     ValidationFailed(String),
 
+    #[error("Lint found {0} error(s)")]
+    LintFailed(usize),
+
+    #[error("{failed} of {total} workflow(s) failed")]
+    BulkFailed { failed: usize, total: usize },
+
+    #[error("{0} check(s) failed")]
+    DoctorFailed(usize),
+
+    // Promotion errors
+    #[error(
+        "Several workflows on the target are named '{name}': {ids}\n\nHint: Choose one with --target-id"
+    )]
+    AmbiguousWorkflowName { name: String, ids: String },
+
+    #[error(
+        "{0} credential reference(s) could not be resolved on the target\n\nHint: Create the missing credentials there, map them with --credential-map, or use --allow-unresolved"
+    )]
+    UnresolvedCredentials(usize),
+
     #[error("Editor failed: {0}")]
     EditorFailed(String),
 
     #[error("No changes detected")]
     NoChanges,
+
+    // Concurrency errors
+    #[error(
+        "Workflow '{id}' was modified since it was read (read version {expected}, server has {actual})\n\nHint: Re-run the command, or use --overwrite to replace the server's changes"
+    )]
+    VersionConflict {
+        id: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error(
+        "Workflow '{id}' was modified concurrently and the changes overlap:\n{conflicts}\n\nHint: Re-run the command, or use --overwrite to replace the server's changes"
+    )]
+    MergeConflict { id: String, conflicts: String },
+
+    #[error(
+        "Can't tell whether workflow '{id}' changed on the server: the file has no versionId\n\nHint: Start from `n8n wf export {id}`, or use --overwrite to replace the server version"
+    )]
+    UnknownBaseVersion { id: String },
 }
 
 /// API error response from n8n
@@ -135,6 +239,9 @@ impl N8nError {
             | Self::ProfileNotFound(_)
             | Self::ConfigFileRead(_)
             | Self::ConfigFileParse(_)
+            | Self::LintConfigParse { .. }
+            | Self::CredentialMapParse { .. }
+            | Self::InvalidCertificate { .. }
             | Self::MissingBaseUrl => 78,
 
             // Permission errors (EX_NOPERM = 77)
@@ -147,22 +254,52 @@ impl N8nError {
             | Self::ExecutionNotFound(_)
             | Self::CredentialNotFound(_) => 69,
             Self::ConnectionFailed { .. } | Self::Request(_) | Self::HttpClient(_) => 69,
+            Self::CassetteMiss { .. } => 69,
 
             // I/O errors (EX_IOERR = 74)
             Self::FileRead { .. } | Self::FileWrite { .. } | Self::StdinRead(_) => 74,
+            Self::MockServer { .. } => 74,
 
             // Data errors (EX_DATAERR = 65)
-            Self::InvalidInput(_) | Self::Serialize(_) | Self::Deserialize(_) => 65,
+            Self::InvalidInput(_)
+            | Self::InvalidYamlInput(_)
+            | Self::InvalidFile { .. }
+            | Self::Serialize(_)
+            | Self::YamlSerialize(_)
+            | Self::Deserialize(_) => 65,
+
+            // Invalid command-line usage (EX_USAGE = 64)
+            Self::UnknownColumn { .. }
+            | Self::InvalidQuery { .. }
+            | Self::InvalidTemplate { .. }
+            | Self::UnsupportedOption { .. } => 64,
 
             // User cancelled
             Self::Cancelled => 130, // Standard for Ctrl+C
 
+            // Execution outcomes (for `executions run --wait`)
+            Self::ExecutionFailed { .. } => 4,
+            Self::ExecutionCanceled(_) => 3,
+            Self::ExecutionTimeout { .. } => 124, // Same as timeout(1)
+
+            // Partial failure of a bulk action
+            Self::BulkFailed { .. } => 1,
+            Self::DoctorFailed(_) => 1,
+
+            // Promotion can't pick a target or would break nodes (EX_DATAERR = 65)
+            Self::AmbiguousWorkflowName { .. } | Self::UnresolvedCredentials(_) => 65,
+
             // Workflow editing errors
             Self::NodeNotFound(_) | Self::ConnectionNotFound { .. } => 69, // EX_UNAVAILABLE
-            Self::ValidationFailed(_) => 65,                               // EX_DATAERR
+            Self::ValidationFailed(_) | Self::LintFailed(_) => 65,         // EX_DATAERR
+            Self::DuplicateNodeName(_) | Self::InvalidNodeName(_) => 65,   // EX_DATAERR
             Self::EditorFailed(_) => 74,                                   // EX_IOERR
             Self::NoChanges => 0,                                          // Not an error
 
+            // Concurrent modification (EX_TEMPFAIL = 75)
+            Self::VersionConflict { .. } | Self::MergeConflict { .. } => 75,
+            Self::UnknownBaseVersion { .. } => 65, // EX_DATAERR
+
             // Generic failure
             Self::Api(_) => 1,
         }
@@ -0,0 +1,3 @@
+mod workflow_graph;
+
+pub use workflow_graph::{newly_unreachable, UnusedOutput, WorkflowGraph};
@@ -0,0 +1,477 @@
+use crate::models::{Connection, Node, TypedWorkflow};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Node type of n8n's "Loop Over Items", whose cycles are intentional
+const LOOP_NODE_TYPE: &str = "n8n-nodes-base.splitInBatches";
+
+/// Directed graph of a workflow's nodes and connections
+///
+/// Only `main` connections carry items between nodes. Other connection types
+/// (`ai_languageModel`, `ai_tool`, ...) attach a sub-node to the node it
+/// serves, so the sub-node counts as reachable whenever that node is.
+/// Connections to nodes that don't exist are ignored.
+pub struct WorkflowGraph<'a> {
+    nodes: Vec<&'a Node>,
+    index: HashMap<&'a str, usize>,
+    connections: Vec<Connection>,
+    /// Main connections: node -> targets
+    outgoing: Vec<Vec<usize>>,
+    /// Main connections: node -> sources
+    incoming: Vec<Vec<usize>>,
+    /// Sub-node connections: served node -> sub-nodes
+    attached: Vec<Vec<usize>>,
+}
+
+/// An output of a branching node with nothing connected to it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnusedOutput {
+    pub node: String,
+    pub output: u32,
+    pub label: String,
+}
+
+impl<'a> WorkflowGraph<'a> {
+    pub fn new(workflow: &'a TypedWorkflow) -> Self {
+        let nodes: Vec<&Node> = workflow.nodes.iter().collect();
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.name.as_str(), i))
+            .collect();
+
+        let mut outgoing = vec![Vec::new(); nodes.len()];
+        let mut incoming = vec![Vec::new(); nodes.len()];
+        let mut attached = vec![Vec::new(); nodes.len()];
+
+        let connections: Vec<Connection> = Connection::from_connections_map(&workflow.connections)
+            .into_iter()
+            .filter(|c| {
+                index.contains_key(c.source_node.as_str())
+                    && index.contains_key(c.target_node.as_str())
+            })
+            .collect();
+
+        for conn in &connections {
+            let from = index[conn.source_node.as_str()];
+            let to = index[conn.target_node.as_str()];
+            if conn.source_type == "main" {
+                outgoing[from].push(to);
+                incoming[to].push(from);
+            } else {
+                attached[to].push(from);
+            }
+        }
+
+        Self {
+            nodes,
+            index,
+            connections,
+            outgoing,
+            incoming,
+            attached,
+        }
+    }
+
+    /// Names of all trigger nodes
+    pub fn triggers(&self) -> Vec<&'a str> {
+        self.nodes
+            .iter()
+            .filter(|n| n.is_trigger())
+            .map(|n| n.name.as_str())
+            .collect()
+    }
+
+    /// Nodes reachable from any trigger (triggers included)
+    pub fn reachable_from_triggers(&self) -> HashSet<&'a str> {
+        let starts: Vec<usize> = (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].is_trigger())
+            .collect();
+        self.walk(&starts, |i| {
+            self.outgoing[i].iter().chain(&self.attached[i]).copied().collect()
+        })
+    }
+
+    /// Non-trigger nodes that have connections but can't be reached from a trigger
+    ///
+    /// Empty when the workflow has no trigger at all, and nodes without any
+    /// connection are left out (those are orphans rather than unreachable).
+    pub fn unreachable(&self) -> Vec<&'a str> {
+        if self.triggers().is_empty() {
+            return vec![];
+        }
+
+        let connected: HashSet<&str> = self
+            .connections
+            .iter()
+            .flat_map(|c| [c.source_node.as_str(), c.target_node.as_str()])
+            .collect();
+        let reachable = self.reachable_from_triggers();
+
+        self.nodes
+            .iter()
+            .map(|n| n.name.as_str())
+            .filter(|name| connected.contains(name) && !reachable.contains(name))
+            .collect()
+    }
+
+    /// Nodes that receive items from `node`, directly or indirectly
+    pub fn downstream(&self, node: &str) -> Vec<&'a str> {
+        self.related(node, |i| self.outgoing[i].clone())
+    }
+
+    /// Nodes that feed items into `node`, directly or indirectly
+    pub fn upstream(&self, node: &str) -> Vec<&'a str> {
+        self.related(node, |i| self.incoming[i].clone())
+    }
+
+    /// Cycles of two or more nodes (strongly connected components)
+    ///
+    /// Self-loops are not included, and neither are loops through a
+    /// "Loop Over Items" node, which is how n8n expresses iteration.
+    pub fn cycles(&self) -> Vec<Vec<&'a str>> {
+        self.strongly_connected_components()
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .filter(|scc| !scc.iter().any(|&i| self.nodes[i].node_type == LOOP_NODE_TYPE))
+            .map(|mut scc| {
+                scc.sort_unstable();
+                scc.into_iter().map(|i| self.nodes[i].name.as_str()).collect()
+            })
+            .collect()
+    }
+
+    /// Outputs of IF and Switch nodes that nothing is connected to
+    pub fn unused_outputs(&self) -> Vec<UnusedOutput> {
+        let mut unused = Vec::new();
+
+        for node in &self.nodes {
+            if node.disabled {
+                continue;
+            }
+            let Some(labels) = branch_outputs(node) else {
+                continue;
+            };
+
+            let used: HashSet<u32> = self
+                .connections
+                .iter()
+                .filter(|c| c.source_node == node.name && c.source_type == "main")
+                .map(|c| c.source_output)
+                .collect();
+
+            for (output, label) in labels.into_iter().enumerate() {
+                let output = output as u32;
+                if !used.contains(&output) {
+                    unused.push(UnusedOutput {
+                        node: node.name.clone(),
+                        output,
+                        label,
+                    });
+                }
+            }
+        }
+        unused
+    }
+
+    /// Breadth-first walk from `starts`, following `next`
+    fn walk(&self, starts: &[usize], next: impl Fn(usize) -> Vec<usize>) -> HashSet<&'a str> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut queue: VecDeque<usize> = starts.iter().copied().collect();
+        for &i in starts {
+            seen[i] = true;
+        }
+
+        while let Some(i) = queue.pop_front() {
+            for j in next(i) {
+                if !seen[j] {
+                    seen[j] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        (0..self.nodes.len())
+            .filter(|&i| seen[i])
+            .map(|i| self.nodes[i].name.as_str())
+            .collect()
+    }
+
+    /// Nodes related to `node` through `next`, excluding `node` itself
+    fn related(&self, node: &str, next: impl Fn(usize) -> Vec<usize>) -> Vec<&'a str> {
+        let Some(&start) = self.index.get(node) else {
+            return vec![];
+        };
+        let found = self.walk(&[start], next);
+
+        // Keep workflow order for stable output
+        self.nodes
+            .iter()
+            .map(|n| n.name.as_str())
+            .filter(|name| *name != node && found.contains(name))
+            .collect()
+    }
+
+    /// Tarjan's algorithm over main connections (iterative, to avoid deep recursion)
+    fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let n = self.nodes.len();
+        let mut index = vec![usize::MAX; n];
+        let mut lowlink = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+
+            // (node, position in its successor list)
+            let mut call_stack = vec![(root, 0)];
+            while let Some(&mut (v, ref mut pos)) = call_stack.last_mut() {
+                if *pos == 0 && index[v] == usize::MAX {
+                    index[v] = next_index;
+                    lowlink[v] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+
+                if let Some(&w) = self.outgoing[v].get(*pos) {
+                    *pos += 1;
+                    if index[w] == usize::MAX {
+                        call_stack.push((w, 0));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w]);
+                    }
+                    continue;
+                }
+
+                // All successors done
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+
+                if lowlink[v] == index[v] {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+}
+
+/// Output labels of a branching node, or `None` for other node types
+fn branch_outputs(node: &Node) -> Option<Vec<String>> {
+    match node.node_type.as_str() {
+        "n8n-nodes-base.if" => Some(vec!["true".to_string(), "false".to_string()]),
+        "n8n-nodes-base.switch" => Some(switch_outputs(&node.parameters)),
+        _ => None,
+    }
+}
+
+/// Output labels of a Switch node, derived from its parameters
+fn switch_outputs(params: &Value) -> Vec<String> {
+    if params.get("mode").and_then(Value::as_str) == Some("expression") {
+        let count = params
+            .get("numberOutputs")
+            .and_then(Value::as_u64)
+            .unwrap_or(4);
+        return (0..count).map(|i| format!("output {}", i)).collect();
+    }
+
+    // Rules live in rules.values (v3) or rules.rules (v1/v2)
+    let rules = params
+        .get("rules")
+        .and_then(|r| r.get("values").or_else(|| r.get("rules")))
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+
+    let mut labels: Vec<String> = rules
+        .iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            rule.get("outputKey")
+                .and_then(Value::as_str)
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("output {}", i))
+        })
+        .collect();
+
+    let fallback = params
+        .get("options")
+        .and_then(|o| o.get("fallbackOutput"))
+        .and_then(Value::as_str);
+    if fallback == Some("extra") {
+        labels.push("fallback".to_string());
+    }
+    labels
+}
+
+/// Nodes that were reachable from a trigger in `before` but aren't in `after`
+pub fn newly_unreachable(before: &TypedWorkflow, after: &TypedWorkflow) -> Vec<String> {
+    let was_reachable = WorkflowGraph::new(before).reachable_from_triggers();
+    let after_graph = WorkflowGraph::new(after);
+    if after_graph.triggers().is_empty() {
+        return vec![];
+    }
+    let now_reachable = after_graph.reachable_from_triggers();
+
+    after
+        .nodes
+        .iter()
+        .map(|n| n.name.as_str())
+        .filter(|name| was_reachable.contains(name) && !now_reachable.contains(name))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn workflow(nodes: &[(&str, &str)], conns: &[(&str, u32, &str)]) -> TypedWorkflow {
+        let mut workflow = TypedWorkflow {
+            id: None,
+            name: "Test".to_string(),
+            active: false,
+            nodes: nodes
+                .iter()
+                .enumerate()
+                .map(|(i, (name, ty))| Node::new(i.to_string(), name.to_string(), ty.to_string()))
+                .collect(),
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: None,
+        };
+        for (from, output, to) in conns {
+            workflow.add_connection(Connection::new_full(
+                from.to_string(),
+                *output,
+                "main".into(),
+                to.to_string(),
+                0,
+                "main".into(),
+            ));
+        }
+        workflow
+    }
+
+    #[test]
+    fn test_unreachable_and_cycles() {
+        let wf = workflow(
+            &[
+                ("Trigger", "n8n-nodes-base.manualTrigger"),
+                ("A", "n8n-nodes-base.set"),
+                ("B", "n8n-nodes-base.set"),
+                ("C", "n8n-nodes-base.set"),
+                ("Lonely", "n8n-nodes-base.set"),
+            ],
+            &[("Trigger", 0, "A"), ("B", 0, "C"), ("C", 0, "B")],
+        );
+        let graph = WorkflowGraph::new(&wf);
+
+        assert_eq!(graph.unreachable(), vec!["B", "C"]);
+        assert_eq!(graph.cycles(), vec![vec!["B", "C"]]);
+        assert_eq!(graph.downstream("Trigger"), vec!["A"]);
+        assert_eq!(graph.upstream("B"), vec!["C"]);
+    }
+
+    #[test]
+    fn test_loop_over_items_is_not_a_cycle() {
+        let wf = workflow(
+            &[
+                ("Trigger", "n8n-nodes-base.manualTrigger"),
+                ("Loop", LOOP_NODE_TYPE),
+                ("Work", "n8n-nodes-base.set"),
+            ],
+            &[("Trigger", 0, "Loop"), ("Loop", 1, "Work"), ("Work", 0, "Loop")],
+        );
+        assert!(WorkflowGraph::new(&wf).cycles().is_empty());
+    }
+
+    #[test]
+    fn test_sub_nodes_are_reachable() {
+        let mut wf = workflow(
+            &[
+                ("Trigger", "n8n-nodes-base.manualTrigger"),
+                ("Agent", "@n8n/n8n-nodes-langchain.agent"),
+                ("Model", "@n8n/n8n-nodes-langchain.lmChatOpenAi"),
+            ],
+            &[("Trigger", 0, "Agent")],
+        );
+        wf.add_connection(Connection::new_full(
+            "Model".into(),
+            0,
+            "ai_languageModel".into(),
+            "Agent".into(),
+            0,
+            "ai_languageModel".into(),
+        ));
+        assert!(WorkflowGraph::new(&wf).unreachable().is_empty());
+    }
+
+    #[test]
+    fn test_unused_outputs() {
+        let mut wf = workflow(
+            &[
+                ("Trigger", "n8n-nodes-base.manualTrigger"),
+                ("IF", "n8n-nodes-base.if"),
+                ("Route", "n8n-nodes-base.switch"),
+            ],
+            &[("Trigger", 0, "IF"), ("IF", 0, "Route")],
+        );
+        wf.nodes[2].parameters = json!({
+            "rules": { "values": [{ "outputKey": "orders" }, {}] },
+            "options": { "fallbackOutput": "extra" }
+        });
+
+        let unused: Vec<_> = WorkflowGraph::new(&wf)
+            .unused_outputs()
+            .into_iter()
+            .map(|u| format!("{}:{}", u.node, u.label))
+            .collect();
+        assert_eq!(
+            unused,
+            vec!["IF:false", "Route:orders", "Route:output 1", "Route:fallback"]
+        );
+    }
+
+    #[test]
+    fn test_newly_unreachable() {
+        let before = workflow(
+            &[
+                ("Trigger", "n8n-nodes-base.manualTrigger"),
+                ("A", "n8n-nodes-base.set"),
+                ("B", "n8n-nodes-base.set"),
+            ],
+            &[("Trigger", 0, "A"), ("A", 0, "B")],
+        );
+        let mut after = before.clone();
+        after.remove_node("1");
+
+        assert_eq!(newly_unreachable(&before, &after), vec!["B"]);
+
+        let mut after = before.clone();
+        after.remove_connection("Trigger", "A");
+        assert_eq!(newly_unreachable(&before, &after), vec!["A", "B"]);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::{N8nError, Result};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// Format of an input document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Json,
+    Yaml,
+}
+
+impl DocumentFormat {
+    /// Detect the format from the file extension, or from the content for
+    /// stdin and unknown extensions (JSON documents start with `{` or `[`)
+    pub fn detect(path: &Path, content: &str) -> Self {
+        if is_yaml_path(path) {
+            return Self::Yaml;
+        }
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+            return Self::Json;
+        }
+
+        match content.trim_start().chars().next() {
+            Some('{') | Some('[') | None => Self::Json,
+            Some(_) => Self::Yaml,
+        }
+    }
+}
+
+/// Whether a path has a `.yaml` or `.yml` extension
+pub fn is_yaml_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"))
+}
+
+/// Parse a JSON or YAML document read from `path`
+pub fn parse_document<T: DeserializeOwned>(content: &str, path: &Path) -> Result<T> {
+    match DocumentFormat::detect(path, content) {
+        DocumentFormat::Json => serde_json::from_str(content).map_err(N8nError::InvalidInput),
+        DocumentFormat::Yaml => serde_norway::from_str(content).map_err(N8nError::InvalidYamlInput),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_detect_format() {
+        let stdin = Path::new("-");
+        assert_eq!(DocumentFormat::detect(Path::new("wf.yml"), "{}"), DocumentFormat::Yaml);
+        assert_eq!(DocumentFormat::detect(Path::new("wf.json"), "a: 1"), DocumentFormat::Json);
+        assert_eq!(DocumentFormat::detect(stdin, "  {\"a\": 1}"), DocumentFormat::Json);
+        assert_eq!(DocumentFormat::detect(stdin, "name: Test"), DocumentFormat::Yaml);
+    }
+
+    #[test]
+    fn test_parse_yaml_document() {
+        let value: Value = parse_document(
+            "name: Test\nnodes:\n  - name: Code\n    parameters:\n      jsCode: |-\n        const a = 1;\n        return a;\n",
+            Path::new("-"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "name": "Test",
+                "nodes": [{ "name": "Code", "parameters": { "jsCode": "const a = 1;\nreturn a;" } }]
+            })
+        );
+    }
+}
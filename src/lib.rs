@@ -1,16 +1,24 @@
+pub mod bulk;
 pub mod cli;
 pub mod client;
 pub mod config;
+pub mod credentials;
 pub mod diff;
+pub mod doctor;
 pub mod editor;
 pub mod error;
+pub mod graph;
+pub mod input;
+pub mod mock;
 pub mod models;
 pub mod output;
+pub mod promote;
 pub mod skill;
+pub mod sync;
 pub mod validation;
 
 pub use cli::{Cli, Commands};
 pub use client::N8nClient;
 pub use config::{load_config, validate_config, CliOverrides, Config};
 pub use error::{N8nError, Result};
-pub use output::{OutputFormat, Outputable, print_output, print_single};
+pub use output::{OutputFormat, OutputOptions, Outputable, print_output, print_single};
@@ -1,27 +1,48 @@
 use clap::Parser;
 use n8n_cli::{
+    bulk::{self, BulkOperation, BulkStatus},
     cli::{
-        Cli, Commands, ConnectionsAction, CredentialsAction, ExecutionsAction, HealthAction,
-        NodesAction, TagsAction, WorkflowsAction,
+        BulkAction, BulkSelection, Cli, Commands, ConfigAction, ConnectionsAction,
+        CredentialsAction, ExecutionsAction, HealthAction, NodesAction, ProfileKey, ProfileOptions,
+        ProfilesAction, PromoteArgs, TagsAction, WorkflowsAction,
     },
     client::{
         endpoints::{
-            credentials::CredentialListParams, executions::ExecutionListParams,
+            credentials::CredentialListParams,
+            executions::{ExecutionListParams, ExecutionTail, TailEvent, TailEventKind},
+            webhooks::WebhookMode,
             workflows::WorkflowListParams,
         },
-        N8nClient,
+        CassetteMode, N8nClient,
     },
-    config::{load_config, validate_config, CliOverrides},
+    config::{
+        config_file_path, explain_config, load_config, load_profile_config, validate_config,
+        CliOverrides, ConfigDocument, Profile,
+    },
+    credentials::{CredentialMap, CredentialResolver, ResolutionStatus},
     diff::WorkflowDiff,
+    doctor::{self, CheckStatus},
     editor::edit_workflow,
     error::{N8nError, Result},
-    models::{Connection, CredentialCreate, Node, Position, TypedWorkflow, WorkflowDefinition},
-    output::{print_output, print_single},
-    validation::validate_workflow,
+    graph,
+    models::{
+        Connection, CredentialCreate, ExecutionStatus, Node, Position, RenameKind, RenameReport,
+        Tag, TypedWorkflow, Workflow, WorkflowDefinition, WorkflowDetail,
+    },
+    input,
+    mock::{Fixtures, MockServer, MOCK_API_KEY},
+    output::{print_output, print_single, to_yaml, OutputFormat, Outputable, Query, Template},
+    promote::{self, PromotionLinks},
+    sync::{self, SyncActionKind},
+    validation::{LintConfig, Linter},
 };
+use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::io::{self, Read};
-use std::path::Path;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::net::ToSocketAddrs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() {
@@ -40,31 +61,96 @@ async fn run() -> Result<()> {
         url: cli.url.clone(),
         api_key: cli.api_key.clone(),
         output: cli.output,
+        no_headers: cli.no_headers,
+        columns: cli.columns.clone(),
+        sort_by: cli.sort_by.clone(),
+        reverse: cli.reverse,
+        wide: cli.wide,
+        query: cli.query.as_deref().map(Query::parse).transpose()?,
+        raw: cli.raw,
+        template: cli.format.as_deref().map(Template::parse).transpose()?,
+        cassette: match (&cli.record, &cli.replay) {
+            (Some(dir), _) => Some(CassetteMode::Record(dir.clone())),
+            (_, Some(dir)) => Some(CassetteMode::Replay(dir.clone())),
+            _ => None,
+        },
         verbose: cli.verbose,
+        trace_file: cli.trace_file.clone(),
         quiet: cli.quiet,
     };
-    let config = load_config(overrides)?;
+
+    // Handle doctor command before loading config (it reports config errors itself)
+    if let Commands::Doctor = cli.command {
+        return handle_doctor(overrides).await;
+    }
+
+    // Handle profile management before loading config (the selected profile may not exist yet)
+    if let Commands::Config(ref cmd) = cli.command
+        && let Some(ConfigAction::Profiles(ref profiles)) = cmd.action
+    {
+        return handle_profiles(&profiles.action, &overrides);
+    }
 
     // Handle config command separately (doesn't need API key)
-    if let Commands::Config = cli.command {
-        return handle_config(&config);
+    if let Commands::Config(_) = cli.command {
+        let config = load_config(overrides.clone())?;
+        return handle_config(&config, &overrides);
     }
 
+    let config = load_config(overrides)?;
+
     // Handle install-claude-skill command (doesn't need API key)
     if let Commands::InstallClaudeSkill { force } = cli.command {
         return n8n_cli::skill::install_claude_skill(force, config.quiet);
     }
 
+    // Handle mock-server command (serves an API instead of calling one)
+    if let Commands::MockServer {
+        ref host,
+        port,
+        ref fixtures,
+        empty,
+    } = cli.command
+    {
+        return handle_mock_server(
+            host,
+            port,
+            fixtures.as_deref(),
+            empty,
+            cli.api_key.as_deref(),
+        )
+        .await;
+    }
+
     // Handle validate --file separately (doesn't need API key)
-    if let Commands::Workflows(ref cmd) = cli.command {
-        if let WorkflowsAction::Validate {
+    if let Commands::Workflows(ref cmd) = cli.command
+        && let WorkflowsAction::Validate {
             id: None,
             file: Some(ref path),
             warnings,
         } = cmd.action
-        {
-            return handle_validate_file(path, warnings);
-        }
+    {
+        return handle_validate_file(path, warnings);
+    }
+
+    // Handle lint --file / --list-rules separately (doesn't need API key)
+    if let Commands::Workflows(ref cmd) = cli.command
+        && let WorkflowsAction::Lint {
+            id: None,
+            ref file,
+            config: ref lint_config,
+            list_rules,
+        } = cmd.action
+    {
+        return handle_lint_local(file.as_deref(), lint_config.as_deref(), list_rules, &config);
+    }
+
+    // Handle promote --from separately (the active profile isn't used)
+    if let Commands::Workflows(ref cmd) = cli.command
+        && let WorkflowsAction::Promote(ref args) = cmd.action
+        && let Some(from) = &args.from
+    {
+        return handle_promote(profile_client(from, &config)?, args.clone(), &config).await;
     }
 
     // Validate configuration for API commands
@@ -80,33 +166,221 @@ async fn run() -> Result<()> {
         Commands::Credentials(cmd) => handle_credentials(&client, cmd.action, &config).await,
         Commands::Tags(cmd) => handle_tags(&client, cmd.action, &config).await,
         Commands::Health(cmd) => handle_health(&client, cmd.action, &config).await,
-        Commands::Config => unreachable!(),              // Handled above
+        Commands::Config(_) => unreachable!(),           // Handled above
+        Commands::Doctor => unreachable!(),              // Handled above
         Commands::InstallClaudeSkill { .. } => unreachable!(), // Handled above
+        Commands::MockServer { .. } => unreachable!(),         // Handled above
     }
 }
 
-fn handle_config(config: &n8n_cli::Config) -> Result<()> {
-    println!("Current configuration:");
-    println!("  Base URL: {}", config.base_url);
-    println!(
-        "  API Key:  {}",
-        if config.api_key.is_empty() {
-            "(not set)"
-        } else {
-            "(set)"
+/// Print each resolved setting with the layer it came from
+fn handle_config(config: &n8n_cli::Config, overrides: &CliOverrides) -> Result<()> {
+    if !config.quiet
+        && let Some(path) = config_file_path()
+    {
+        let note = if path.exists() { "" } else { " (not found)" };
+        eprintln!("Config file: {}{}", path.display(), note);
+    }
+    let settings = explain_config(config, overrides)?;
+    print_output(&settings, &config.output)
+}
+
+fn handle_profiles(action: &ProfilesAction, overrides: &CliOverrides) -> Result<()> {
+    let mut document = ConfigDocument::open()?;
+    let quiet = overrides.quiet;
+
+    match action {
+        ProfilesAction::List => {
+            let profiles = document.profiles()?;
+            return print_output(&profiles, &overrides.output_options());
+        }
+
+        ProfilesAction::Add {
+            name,
+            base_url,
+            options,
+            default,
+        } => {
+            let api_key = read_profile_api_key(options)?;
+            let mut profile = Profile::new(base_url);
+            apply_profile_options(&mut profile, options, api_key);
+            document.add_profile(name, &profile)?;
+            if *default {
+                document.set_default_profile(name)?;
+            }
+            document.save()?;
+            if !quiet {
+                eprintln!("Added profile '{}' to {}", name, document.path().display());
+            }
+        }
+
+        ProfilesAction::Edit {
+            name,
+            base_url,
+            options,
+            unset,
+        } => {
+            let api_key = read_profile_api_key(options)?;
+            document.update_profile(name, |profile| {
+                for key in unset {
+                    match key {
+                        ProfileKey::ApiKey => profile.api_key = None,
+                        ProfileKey::ApiKeyEnv => profile.api_key_env = None,
+                        ProfileKey::OutputFormat => profile.output_format = None,
+                        ProfileKey::Retry => profile.retry = None,
+                        ProfileKey::Proxy => profile.proxy = None,
+                        ProfileKey::CaCert => profile.ca_cert = None,
+                        ProfileKey::ClientCert => profile.client_cert = None,
+                        ProfileKey::ClientKey => profile.client_key = None,
+                        ProfileKey::InsecureSkipVerify => profile.insecure_skip_verify = None,
+                    }
+                }
+                if let Some(url) = base_url {
+                    profile.base_url = url.clone();
+                }
+                apply_profile_options(profile, options, api_key);
+            })?;
+            document.save()?;
+            if !quiet {
+                eprintln!("Updated profile '{}'", name);
+            }
         }
+
+        ProfilesAction::Use { name } => {
+            document.set_default_profile(name)?;
+            document.save()?;
+            if !quiet {
+                eprintln!("Default profile is now '{}'", name);
+            }
+        }
+
+        ProfilesAction::Remove { name, force } => {
+            // Fail on unknown profiles before asking
+            document.profile(name)?;
+            if !force && !confirm(&format!("Remove profile '{}'?", name), false)? {
+                return Err(N8nError::Cancelled);
+            }
+            let was_default = document.remove_profile(name)?;
+            document.save()?;
+            if !quiet {
+                eprintln!("Removed profile '{}'", name);
+                if was_default {
+                    eprintln!(
+                        "It was the default profile; choose another with `n8n config profiles use`"
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Read the API key from stdin if `--api-key-stdin` is given
+fn read_profile_api_key(options: &ProfileOptions) -> Result<Option<String>> {
+    if !options.api_key_stdin {
+        return Ok(None);
+    }
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(N8nError::StdinRead)?;
+    let key = input.trim();
+    if key.is_empty() {
+        return Err(N8nError::Config("No API key on stdin".to_string()));
+    }
+    Ok(Some(key.to_string()))
+}
+
+/// Apply the settings given on the command line to a profile
+fn apply_profile_options(profile: &mut Profile, options: &ProfileOptions, api_key: Option<String>) {
+    // A stored key would shadow api_key_env, so only one of them is kept
+    if let Some(key) = api_key {
+        profile.api_key = Some(key);
+        profile.api_key_env = None;
+    }
+    if let Some(var) = &options.api_key_env {
+        profile.api_key_env = Some(var.clone());
+        profile.api_key = None;
+    }
+    if let Some(format) = options.output_format {
+        profile.output_format = Some(format);
+    }
+    if let Some(proxy) = &options.proxy {
+        profile.proxy = Some(proxy.clone());
+    }
+    if let Some(path) = &options.ca_cert {
+        profile.ca_cert = Some(path.clone());
+    }
+    if let Some(path) = &options.client_cert {
+        profile.client_cert = Some(path.clone());
+    }
+    if let Some(path) = &options.client_key {
+        profile.client_key = Some(path.clone());
+    }
+    if options.insecure_skip_verify {
+        profile.insecure_skip_verify = Some(true);
+    }
+}
+
+/// Run the diagnostic checks and print one line per check
+async fn handle_doctor(overrides: CliOverrides) -> Result<()> {
+    let output = overrides.output_options();
+    let checks = doctor::run_doctor(overrides).await;
+    print_output(&checks, &output)?;
+
+    match checks.iter().filter(|c| c.status == CheckStatus::Fail).count() {
+        0 => Ok(()),
+        failed => Err(N8nError::DoctorFailed(failed)),
+    }
+}
+
+async fn handle_mock_server(
+    host: &str,
+    port: u16,
+    fixtures: Option<&Path>,
+    empty: bool,
+    api_key: Option<&str>,
+) -> Result<()> {
+    let fixtures = match fixtures {
+        Some(path) => Fixtures::load(path)?,
+        None if empty => Fixtures::default(),
+        None => Fixtures::sample(),
+    };
+    let addr = (host, port)
+        .to_socket_addrs()
+        .and_then(|mut addrs| {
+            addrs
+                .next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no address found"))
+        })
+        .map_err(|e| N8nError::MockServer {
+            addr: format!("{}:{}", host, port),
+            source: e,
+        })?;
+
+    let server = MockServer::bind(addr, fixtures, api_key.unwrap_or(MOCK_API_KEY))?;
+    // The URL goes to stdout so scripts can start the server on port 0
+    println!("{}", server.url());
+    io::stdout().flush().map_err(|e| N8nError::FileWrite {
+        path: "stdout".to_string(),
+        source: e,
+    })?;
+    eprintln!(
+        "Mock n8n API listening on {}\n\n  export N8N_BASE_URL={}\n  export N8N_API_KEY={}\n\nPress Ctrl+C to stop",
+        server.url(),
+        server.url(),
+        server.api_key()
     );
-    println!("  Output:   {:?}", config.output_format);
-    println!("  Timeout:  {}s", config.timeout_secs);
+
+    let _ = tokio::signal::ctrl_c().await;
     Ok(())
 }
 
 fn handle_validate_file(path: &Path, warnings: bool) -> Result<()> {
-    let content = read_file_or_stdin(path)?;
-    let workflow: TypedWorkflow =
-        serde_json::from_str(&content).map_err(N8nError::InvalidInput)?;
+    let workflow: TypedWorkflow = read_document(path)?;
 
-    let result = validate_workflow(&workflow);
+    let result = load_linter(None, &lint_start(Some(path))?)?.lint(&workflow);
 
     if result.is_valid() {
         println!("Workflow is valid.");
@@ -120,6 +394,108 @@ fn handle_validate_file(path: &Path, warnings: bool) -> Result<()> {
     }
 }
 
+/// List the references a rename rewrote
+fn print_rename_summary(report: &RenameReport) {
+    for location in &report.locations {
+        eprintln!("  {:<11} {}: {}", location.kind, location.node, location.location);
+    }
+}
+
+/// Warn that a renamed node's pinned data wasn't saved, even with `--quiet`
+fn warn_pin_data(report: &RenameReport) {
+    let pinned = report
+        .locations
+        .iter()
+        .any(|l| l.kind == RenameKind::PinData);
+    if pinned {
+        eprintln!(
+            "Warning: pinned data stays under '{}' on the server (the n8n API can't change it); re-pin '{}' in the editor",
+            report.old_name, report.new_name
+        );
+    }
+}
+
+/// Warn about nodes that an edit cut off from every trigger
+fn warn_unreachable(before: &TypedWorkflow, after: &TypedWorkflow) {
+    let nodes = graph::newly_unreachable(before, after);
+    if !nodes.is_empty() {
+        eprintln!(
+            "Warning: no longer reachable from a trigger: {}",
+            nodes.join(", ")
+        );
+    }
+}
+
+/// Working directory, as an absolute path whose ancestors can be searched
+fn current_dir() -> Result<PathBuf> {
+    std::env::current_dir().map_err(|e| N8nError::FileRead {
+        path: ".".to_string(),
+        source: e,
+    })
+}
+
+/// Directory to search for `.n8nlint.toml`: the linted file's, or the working one
+fn lint_start(file: Option<&Path>) -> Result<PathBuf> {
+    let cwd = current_dir()?;
+    Ok(match file.and_then(Path::parent) {
+        Some(dir) => cwd.join(dir),
+        None => cwd,
+    })
+}
+
+/// Build a linter from `--config` or the nearest `.n8nlint.toml` above `start`
+fn load_linter(lint_config: Option<&Path>, start: &Path) -> Result<Linter> {
+    let lint_config = match lint_config {
+        Some(path) => LintConfig::load(path)?,
+        None => LintConfig::discover(start)?,
+    };
+    Linter::new(lint_config)
+}
+
+fn handle_lint_local(
+    file: Option<&Path>,
+    lint_config: Option<&Path>,
+    list_rules: bool,
+    config: &n8n_cli::Config,
+) -> Result<()> {
+    // Look for .n8nlint.toml next to the linted file
+    let linter = load_linter(lint_config, &lint_start(file)?)?;
+
+    if list_rules {
+        return print_output(&linter.rule_infos(), &config.output);
+    }
+
+    let Some(path) = file else {
+        return Err(N8nError::Config(
+            "Either workflow ID or --file must be specified".to_string(),
+        ));
+    };
+    let workflow: TypedWorkflow = read_document(path)?;
+
+    report_lint(&linter, &workflow, config)
+}
+
+/// Print lint issues and fail if any of them is an error
+fn report_lint(linter: &Linter, workflow: &TypedWorkflow, config: &n8n_cli::Config) -> Result<()> {
+    let result = linter.lint(workflow);
+
+    if result.issues.is_empty()
+        && config.output.format == OutputFormat::Table
+        && config.output.query.is_none()
+    {
+        if !config.quiet {
+            println!("No issues found in '{}'.", workflow.name);
+        }
+    } else {
+        print_output(&result.issues, &config.output)?;
+    }
+
+    match result.errors().len() {
+        0 => Ok(()),
+        errors => Err(N8nError::LintFailed(errors)),
+    }
+}
+
 async fn handle_workflows(
     client: &N8nClient,
     action: WorkflowsAction,
@@ -144,27 +520,30 @@ async fn handle_workflows(
 
             if all {
                 let workflows = client.list_all_workflows(params).await?;
-                print_output(&workflows, config.output_format)?;
+                print_output(&workflows, &config.output)?;
             } else {
                 let response = client.list_workflows(&params).await?;
-                print_output(&response.data, config.output_format)?;
-                if let Some(next) = response.next_cursor {
-                    if !config.quiet {
-                        eprintln!("\nMore results available. Use --cursor {} to continue", next);
-                    }
+                print_output(&response.data, &config.output)?;
+                if let Some(next) = response.next_cursor
+                    && !config.quiet
+                {
+                    eprintln!("\nMore results available. Use --cursor {} to continue", next);
                 }
             }
         }
 
         WorkflowsAction::Get { id } => {
             let workflow = client.get_workflow(&id).await?;
-            print_single(&workflow, config.output_format)?;
+            print_single(&workflow, &config.output)?;
         }
 
-        WorkflowsAction::Create { file, activate } => {
-            let content = read_file_or_stdin(&file)?;
-            let workflow: WorkflowDefinition =
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?;
+        WorkflowsAction::Create {
+            file,
+            activate,
+            credential_map,
+        } => {
+            let mut workflow: WorkflowDefinition = read_document(&file)?;
+            resolve_credentials(client, &mut workflow, credential_map.as_deref(), config).await?;
 
             let created = client.create_workflow(&workflow).await?;
             if !config.quiet {
@@ -176,22 +555,45 @@ async fn handle_workflows(
                 if !config.quiet {
                     eprintln!("Activated workflow: {}", activated.id);
                 }
-                print_single(&activated, config.output_format)?;
+                print_single(&activated, &config.output)?;
             } else {
-                print_single(&created, config.output_format)?;
+                print_single(&created, &config.output)?;
             }
         }
 
-        WorkflowsAction::Update { id, file } => {
-            let content = read_file_or_stdin(&file)?;
-            let workflow: WorkflowDefinition =
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?;
-
-            let updated = client.update_workflow(&id, &workflow).await?;
+        WorkflowsAction::Update {
+            id,
+            file,
+            overwrite,
+            credential_map,
+        } => {
+            let value: Value = read_document(&file)?;
+            let mut workflow: WorkflowDefinition =
+                serde_json::from_value(value.clone()).map_err(N8nError::InvalidInput)?;
+            resolve_credentials(client, &mut workflow, credential_map.as_deref(), config).await?;
+
+            // Exported files carry the versionId they were read at
+            let expected_version = value.get("versionId").and_then(|v| v.as_str());
+            let updated = match expected_version {
+                _ if overwrite => client.update_workflow(&id, &workflow).await?,
+                Some(expected) => {
+                    client
+                        .update_workflow_if_version(&id, &workflow, Some(expected))
+                        .await?
+                }
+                None => {
+                    // Without a base version, only a server that tracks none can be updated safely
+                    let current = client.get_workflow(&id).await?;
+                    if current.version_id.is_some() {
+                        return Err(N8nError::UnknownBaseVersion { id });
+                    }
+                    client.update_workflow(&id, &workflow).await?
+                }
+            };
             if !config.quiet {
                 eprintln!("Updated workflow: {} ({})", updated.name, updated.id);
             }
-            print_single(&updated, config.output_format)?;
+            print_single(&updated, &config.output)?;
         }
 
         WorkflowsAction::Delete { id, force } => {
@@ -217,7 +619,7 @@ async fn handle_workflows(
             if !config.quiet {
                 eprintln!("Activated workflow: {} ({})", workflow.name, workflow.id);
             }
-            print_single(&workflow, config.output_format)?;
+            print_single(&workflow, &config.output)?;
         }
 
         WorkflowsAction::Deactivate { id } => {
@@ -225,7 +627,11 @@ async fn handle_workflows(
             if !config.quiet {
                 eprintln!("Deactivated workflow: {} ({})", workflow.name, workflow.id);
             }
-            print_single(&workflow, config.output_format)?;
+            print_single(&workflow, &config.output)?;
+        }
+
+        WorkflowsAction::Bulk(cmd) => {
+            handle_bulk(client, cmd.action, config).await?;
         }
 
         WorkflowsAction::Nodes(cmd) => {
@@ -240,6 +646,7 @@ async fn handle_workflows(
             id,
             editor,
             no_validate,
+            overwrite,
         } => {
             // Fetch workflow
             let detail = client.get_workflow(&id).await?;
@@ -248,22 +655,21 @@ async fn handle_workflows(
             // Open in editor
             let edited = edit_workflow(&workflow, editor.as_deref())?;
 
-            // Validate if requested
+            // Validate if requested, honoring .n8nlint.toml
             if !no_validate {
-                let result = validate_workflow(&edited);
+                let result = load_linter(None, &current_dir()?)?.lint(&edited);
                 if !result.is_valid() {
                     return Err(N8nError::ValidationFailed(result.format(true)));
                 }
             }
 
             // Update
-            let definition = edited.to_definition();
-            let updated = client.update_workflow(&id, &definition).await?;
+            let updated = save_workflow(client, &id, &workflow, &edited, overwrite).await?;
 
             if !config.quiet {
                 eprintln!("Updated workflow: {} ({})", updated.name, updated.id);
             }
-            print_single(&updated, config.output_format)?;
+            print_single(&updated, &config.output)?;
         }
 
         WorkflowsAction::Diff { id, with, file, full } => {
@@ -276,8 +682,7 @@ async fn handle_workflows(
                 let detail2 = client.get_workflow(&with_id).await?;
                 TypedWorkflow::from_detail(detail2)?
             } else if let Some(path) = file {
-                let content = read_file_or_stdin(&path)?;
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?
+                read_document(&path)?
             } else {
                 return Err(N8nError::Config(
                     "Either --with or --file must be specified".to_string(),
@@ -297,7 +702,13 @@ async fn handle_workflows(
             let detail = client.get_workflow(&id).await?;
             let workflow = TypedWorkflow::from_detail(detail)?;
 
-            let content = if pretty {
+            // YAML when asked for with -o yaml or a .yaml/.yml file name
+            let yaml = config.output.format == OutputFormat::Yaml
+                || file.as_deref().is_some_and(input::is_yaml_path);
+
+            let content = if yaml {
+                to_yaml(&workflow)?
+            } else if pretty {
                 serde_json::to_string_pretty(&workflow).map_err(N8nError::Serialize)?
             } else {
                 serde_json::to_string(&workflow).map_err(N8nError::Serialize)?
@@ -312,10 +723,65 @@ async fn handle_workflows(
                     eprintln!("Exported workflow to: {}", path.display());
                 }
             } else {
-                println!("{}", content);
+                println!("{}", content.trim_end());
+            }
+        }
+
+        WorkflowsAction::Pull { dir, tags, prune } => {
+            let report = sync::pull(client, &dir, tags.map(|t| t.join(",")), prune).await?;
+
+            if !config.quiet {
+                for file in &report.written {
+                    eprintln!("Wrote {}", dir.join(file).display());
+                }
+                for file in &report.removed {
+                    eprintln!("Removed {}", dir.join(file).display());
+                }
+                eprintln!(
+                    "Pulled {} workflow(s): {} written, {} unchanged, {} removed",
+                    report.written.len() + report.unchanged.len(),
+                    report.written.len(),
+                    report.unchanged.len(),
+                    report.removed.len()
+                );
             }
         }
 
+        WorkflowsAction::Push {
+            dir,
+            tags,
+            dry_run,
+            delete,
+        } => {
+            let plan = sync::plan_push(client, &dir, tags.map(|t| t.join(",")), delete).await?;
+
+            match config.output.format {
+                OutputFormat::Table if config.output.query.is_none() => plan.print_summary(),
+                _ => print_output(&plan.actions, &config.output)?,
+            }
+
+            if dry_run || !plan.has_changes() {
+                return Ok(());
+            }
+
+            sync::apply_push(client, &dir, &plan).await?;
+            if !config.quiet {
+                let count = |kind| plan.actions.iter().filter(|a| a.action == kind).count();
+                eprintln!(
+                    "Pushed {}: {} created, {} updated, {} deleted",
+                    dir.display(),
+                    count(SyncActionKind::Create),
+                    count(SyncActionKind::Update),
+                    count(SyncActionKind::Delete)
+                );
+            }
+        }
+
+        WorkflowsAction::Promote(args) => {
+            // With --from, this is handled in run() before the active profile is validated
+            handle_promote(client.clone(), args, config).await?;
+        }
+
         WorkflowsAction::Clone { id, name, activate } => {
             let detail = client.get_workflow(&id).await?;
             let mut workflow = TypedWorkflow::from_detail(detail)?;
@@ -337,9 +803,9 @@ async fn handle_workflows(
                 if !config.quiet {
                     eprintln!("Activated workflow: {}", activated.id);
                 }
-                print_single(&activated, config.output_format)?;
+                print_single(&activated, &config.output)?;
             } else {
-                print_single(&created, config.output_format)?;
+                print_single(&created, &config.output)?;
             }
         }
 
@@ -349,7 +815,7 @@ async fn handle_workflows(
                 let detail = client.get_workflow(&workflow_id).await?;
                 let workflow = TypedWorkflow::from_detail(detail)?;
 
-                let result = validate_workflow(&workflow);
+                let result = load_linter(None, &current_dir()?)?.lint(&workflow);
 
                 if result.is_valid() {
                     println!("Workflow '{}' is valid.", workflow.name);
@@ -367,6 +833,24 @@ async fn handle_workflows(
             }
         }
 
+        WorkflowsAction::Lint {
+            id,
+            config: lint_config,
+            list_rules,
+            ..
+        } => {
+            // file-only and --list-rules cases are handled at the top of run()
+            let linter = load_linter(lint_config.as_deref(), &current_dir()?)?;
+            if list_rules {
+                return print_output(&linter.rule_infos(), &config.output);
+            }
+            if let Some(workflow_id) = id {
+                let detail = client.get_workflow(&workflow_id).await?;
+                let workflow = TypedWorkflow::from_detail(detail)?;
+                report_lint(&linter, &workflow, config)?;
+            }
+        }
+
         WorkflowsAction::Run {
             id,
             data,
@@ -401,24 +885,14 @@ async fn handle_workflows(
                 }
             };
 
-            // Build webhook URL
-            let webhook_url = format!(
-                "{}/webhook{}{}",
-                config.base_url.trim_end_matches('/'),
-                if workflow.active { "" } else { "-test" },
-                if webhook_path.starts_with('/') {
-                    webhook_path.clone()
-                } else {
-                    format!("/{}", webhook_path)
-                }
-            );
+            let mode = WebhookMode::for_active(workflow.active);
+            let webhook_url = client.webhook_url(&webhook_path, mode);
 
             // Parse input data
             let body: Option<Value> = if let Some(json_data) = data {
                 Some(serde_json::from_str(&json_data).map_err(N8nError::InvalidInput)?)
             } else if let Some(path) = data_file {
-                let content = read_file_or_stdin(&path)?;
-                Some(serde_json::from_str(&content).map_err(N8nError::InvalidInput)?)
+                Some(read_document(&path)?)
             } else {
                 None
             };
@@ -432,46 +906,339 @@ async fn handle_workflows(
                 eprintln!("URL: {}", webhook_url);
             }
 
-            // Make webhook request
-            let http_client = reqwest::Client::new();
-            let mut request = match method.to_uppercase().as_str() {
-                "GET" => http_client.get(&webhook_url),
-                "POST" => http_client.post(&webhook_url),
-                "PUT" => http_client.put(&webhook_url),
-                "PATCH" => http_client.patch(&webhook_url),
-                "DELETE" => http_client.delete(&webhook_url),
-                _ => http_client.post(&webhook_url),
+            let method = match method.to_uppercase().as_str() {
+                "GET" => Method::GET,
+                "PUT" => Method::PUT,
+                "PATCH" => Method::PATCH,
+                "DELETE" => Method::DELETE,
+                _ => Method::POST,
             };
+            let response = client
+                .trigger_webhook(&webhook_path, mode, method, body.as_ref())
+                .await?;
 
-            if let Some(body_data) = body {
-                request = request.json(&body_data);
+            if !config.quiet {
+                eprintln!("Workflow triggered successfully ({})", response.status);
             }
+            if !no_wait {
+                print_single(&response.body, &config.output)?;
+            }
+        }
+    }
 
-            let response = request.send().await.map_err(N8nError::Request)?;
-            let status = response.status();
+    Ok(())
+}
 
-            if status.is_success() {
-                if !config.quiet {
-                    eprintln!("Workflow triggered successfully ({})", status);
-                }
+/// Promote a workflow from the `source` instance to the `--to` profile
+async fn handle_promote(
+    source: N8nClient,
+    args: PromoteArgs,
+    config: &n8n_cli::Config,
+) -> Result<()> {
+    let PromoteArgs {
+        id,
+        from: _,
+        to,
+        target_id,
+        dry_run,
+        full,
+        allow_unresolved,
+        credential_map,
+        force,
+    } = args;
+
+    let target = profile_client(&to, config)?;
+    if source.base_url() == target.base_url() {
+        return Err(N8nError::Config(format!(
+            "Profile '{}' points at the same instance as the source ({})",
+            to,
+            target.base_url()
+        )));
+    }
 
-                if !no_wait {
-                    let response_body: Value = response.json().await.unwrap_or(Value::Null);
-                    print_single(&response_body, config.output_format)?;
-                }
-            } else {
-                let error_text = response.text().await.unwrap_or_default();
-                return Err(N8nError::Config(format!(
-                    "Webhook request failed ({}): {}",
-                    status, error_text
-                )));
-            }
+    let map = match credential_map {
+        Some(path) => CredentialMap::load(&path)?,
+        None => CredentialMap::default(),
+    };
+    let mut links = PromotionLinks::load()?;
+    let promotion =
+        promote::plan_promotion(&source, &target, &id, target_id.as_deref(), &links, map).await?;
+
+    let table = config.output.format == OutputFormat::Table && config.output.query.is_none();
+    if table {
+        promotion.print_summary(full);
+    } else if dry_run {
+        print_single(&promotion.workflow, &config.output)?;
+    }
+
+    let unresolved = promotion.unresolved().len();
+    if dry_run {
+        return Ok(());
+    }
+    if unresolved > 0 && !allow_unresolved {
+        return Err(N8nError::UnresolvedCredentials(unresolved));
+    }
+    if !promotion.has_changes() {
+        if let Some(target_id) = promotion.target_id() {
+            links.record(source.base_url(), target.base_url(), &id, target_id);
+            links.save()?;
         }
+        return Ok(());
+    }
+    if !force && !confirm(&format!("Promote to '{}'?", to), false)? {
+        return Err(N8nError::Cancelled);
+    }
+
+    let promoted = promote::apply_promotion(&target, &promotion).await?;
+    links.record(source.base_url(), target.base_url(), &id, &promoted.id);
+    links.save()?;
+
+    if !config.quiet {
+        let verb = if promotion.target.is_some() {
+            "Updated"
+        } else {
+            "Created"
+        };
+        eprintln!(
+            "{} workflow on '{}': {} ({})",
+            verb, to, promoted.name, promoted.id
+        );
+    }
+    if !table {
+        print_single(&promoted, &config.output)?;
     }
 
     Ok(())
 }
 
+async fn handle_bulk(
+    client: &N8nClient,
+    action: BulkAction,
+    config: &n8n_cli::Config,
+) -> Result<()> {
+    let (operation, selection) = match action {
+        BulkAction::Activate { selection } => (BulkOperation::Activate, selection),
+        BulkAction::Deactivate { selection } => (BulkOperation::Deactivate, selection),
+        BulkAction::Delete { selection } => (BulkOperation::Delete, selection),
+        BulkAction::Tag { add, selection } => (
+            BulkOperation::AddTags(resolve_tags(client, &add).await?),
+            selection,
+        ),
+        BulkAction::Untag { remove, selection } => (
+            BulkOperation::RemoveTags(resolve_tags(client, &remove).await?),
+            selection,
+        ),
+    };
+    let BulkSelection {
+        active,
+        tags,
+        name,
+        stdin,
+        all: _,
+        concurrency,
+        dry_run,
+        force,
+    } = selection;
+
+    let ids = if stdin {
+        let mut buffer = String::new();
+        io::stdin()
+            .read_to_string(&mut buffer)
+            .map_err(N8nError::StdinRead)?;
+        Some(bulk::parse_ids(&buffer))
+    } else {
+        None
+    };
+
+    let params = WorkflowListParams {
+        limit: Some(100),
+        cursor: None,
+        active,
+        tags: tags.map(|t| t.join(",")),
+        name,
+    };
+    let listed = client.list_all_workflows(params).await?;
+    let (targets, missing) = bulk::select_workflows(listed, ids.as_deref());
+
+    for id in &missing {
+        eprintln!("Warning: no matching workflow with ID {}", id);
+    }
+    if targets.is_empty() && missing.is_empty() {
+        if !config.quiet {
+            eprintln!("No workflows matched.");
+        }
+        return Ok(());
+    }
+
+    // Workflows already in the requested state are reported as skipped
+    let pending: Vec<Workflow> = targets
+        .iter()
+        .filter(|w| !operation.is_noop(w))
+        .cloned()
+        .collect();
+
+    if dry_run {
+        print_output(&pending, &config.output)?;
+        return Ok(());
+    }
+
+    if !pending.is_empty() && (!force || !config.quiet) {
+        eprintln!(
+            "{} {} workflow(s):",
+            capitalize(operation.verb()),
+            pending.len()
+        );
+        for workflow in &pending {
+            eprintln!("  {}  {}", workflow.id, workflow.name);
+        }
+    }
+    if !pending.is_empty() && !force && !confirm("Continue?", stdin)? {
+        return Err(N8nError::Cancelled);
+    }
+
+    let mut results = bulk::run_bulk(client, operation.clone(), targets, concurrency.into()).await;
+    results.extend(
+        missing
+            .iter()
+            .map(|id| bulk::BulkResult::failed(id, "-", "No matching workflow".to_string())),
+    );
+    print_output(&results, &config.output)?;
+
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let failed = count(BulkStatus::Failed);
+    if !config.quiet {
+        eprintln!(
+            "{} {}, skipped {}, failed {}",
+            capitalize(operation.past_tense()),
+            count(BulkStatus::Done),
+            count(BulkStatus::Skipped),
+            failed
+        );
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(N8nError::BulkFailed {
+            failed,
+            total: results.len(),
+        }),
+    }
+}
+
+/// Point a workflow's credential references at credentials of the instance
+///
+/// Unresolved references are reported but left as they are.
+async fn resolve_credentials(
+    client: &N8nClient,
+    workflow: &mut WorkflowDefinition,
+    map_path: Option<&Path>,
+    config: &n8n_cli::Config,
+) -> Result<()> {
+    let map = match map_path {
+        Some(path) => CredentialMap::load(path)?,
+        None => CredentialMap::default(),
+    };
+    if !workflow
+        .nodes
+        .iter()
+        .any(|n| n.get("credentials").is_some())
+    {
+        return Ok(());
+    }
+
+    let params = CredentialListParams {
+        limit: Some(100),
+        ..Default::default()
+    };
+    let resolver = match client.list_all_credentials(params).await {
+        Ok(available) => CredentialResolver::new(available).with_map(map),
+        // Listing credentials needs more scopes than writing workflows
+        Err(N8nError::Api(e)) => {
+            if !config.quiet {
+                eprintln!(
+                    "Warning: Could not list credentials ({}); only --credential-map is applied",
+                    e.message
+                );
+            }
+            CredentialResolver::map_only(map)
+        }
+        Err(e) => return Err(e),
+    };
+
+    for c in resolver.resolve_definition(workflow) {
+        let name = c.name.as_deref().unwrap_or("-");
+        match c.status {
+            ResolutionStatus::Kept => {}
+            ResolutionStatus::Unresolved => eprintln!(
+                "Warning: {} '{}' on node '{}' not found on this instance",
+                c.credential_type,
+                c.to.as_deref().unwrap_or(name),
+                c.node
+            ),
+            _ if config.quiet => {}
+            _ => eprintln!(
+                "Credential {} '{}' on node '{}': {} -> {}",
+                c.credential_type,
+                name,
+                c.node,
+                c.from.as_deref().unwrap_or("-"),
+                c.to.as_deref().unwrap_or("-")
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Create a client for a named profile
+fn profile_client(profile: &str, config: &n8n_cli::Config) -> Result<N8nClient> {
+    let profile_config = load_profile_config(profile, config)?;
+    validate_config(&profile_config)?;
+    N8nClient::new(&profile_config)
+}
+
+/// Ask a yes/no question on the terminal (`/dev/tty` when stdin is already used for input)
+fn confirm(question: &str, use_tty: bool) -> Result<bool> {
+    eprint!("{} [y/N] ", question);
+    let mut input = String::new();
+    if use_tty {
+        let Ok(tty) = std::fs::File::open("/dev/tty") else {
+            eprintln!("\nNo terminal to confirm on; use --force to skip confirmation");
+            return Ok(false);
+        };
+        io::BufReader::new(tty)
+            .read_line(&mut input)
+            .map_err(N8nError::StdinRead)?;
+    } else {
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(N8nError::StdinRead)?;
+    }
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Resolve tag names or IDs against the server's tags
+async fn resolve_tags(client: &N8nClient, names: &[String]) -> Result<Vec<Tag>> {
+    let all_tags = client.list_tags().await?;
+    names
+        .iter()
+        .map(|name| {
+            all_tags
+                .iter()
+                .find(|t| &t.name == name || &t.id == name)
+                .cloned()
+                .ok_or_else(|| N8nError::Config(format!("Tag not found: {}", name)))
+        })
+        .collect()
+}
+
 async fn handle_nodes(
     client: &N8nClient,
     action: NodesAction,
@@ -481,7 +1248,7 @@ async fn handle_nodes(
         NodesAction::List { workflow_id } => {
             let detail = client.get_workflow(&workflow_id).await?;
             let workflow = TypedWorkflow::from_detail(detail)?;
-            print_output(&workflow.nodes, config.output_format)?;
+            print_output(&workflow.nodes, &config.output)?;
         }
 
         NodesAction::Get {
@@ -495,7 +1262,7 @@ async fn handle_nodes(
                 .find_node(&node_id)
                 .ok_or_else(|| N8nError::NodeNotFound(node_id.clone()))?;
 
-            print_single(node, config.output_format)?;
+            print_single(node, &config.output)?;
         }
 
         NodesAction::Add {
@@ -506,16 +1273,17 @@ async fn handle_nodes(
             config: node_config,
             config_file,
             disabled,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
             // Parse config
             let parameters: Value = if let Some(cfg) = node_config {
                 serde_json::from_str(&cfg).map_err(N8nError::InvalidInput)?
             } else if let Some(path) = config_file {
-                let content = read_file_or_stdin(&path)?;
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?
+                read_document(&path)?
             } else {
                 Value::Object(serde_json::Map::new())
             };
@@ -534,22 +1302,23 @@ async fn handle_nodes(
             workflow.add_node(node.clone());
 
             // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
                 eprintln!("Added node '{}' ({}) to workflow", node.name, node.id);
             }
-            print_single(&node, config.output_format)?;
+            print_single(&node, &config.output)?;
         }
 
         NodesAction::Remove {
             workflow_id,
             node_id,
             force,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
             // Check node exists
             let node_name = workflow
@@ -571,11 +1340,11 @@ async fn handle_nodes(
             workflow.remove_node(&node_id);
 
             // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
                 eprintln!("Removed node '{}' from workflow", node_name);
+                warn_unreachable(&base, &workflow);
             }
         }
 
@@ -587,19 +1356,26 @@ async fn handle_nodes(
             config: node_config,
             replace,
             disabled,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
-            let node = workflow
-                .find_node_mut(&node_id)
+            // Resolve the ID first: node_id may be the name being changed
+            let id = workflow
+                .find_node(&node_id)
+                .map(|n| n.id.clone())
                 .ok_or_else(|| N8nError::NodeNotFound(node_id.clone()))?;
 
-            let old_name = node.name.clone();
+            let report = match name {
+                Some(ref new_name) => Some(workflow.rename_node(&id, new_name)?),
+                None => None,
+            };
 
-            if let Some(ref new_name) = name {
-                node.name = new_name.clone();
-            }
+            let node = workflow
+                .find_node_mut(&id)
+                .ok_or_else(|| N8nError::NodeNotFound(node_id.clone()))?;
 
             if let Some((x, y)) = position {
                 node.position = Position::new(x, y);
@@ -627,19 +1403,51 @@ async fn handle_nodes(
 
             let node_name = node.name.clone();
 
-            // Update connection references if node was renamed
-            if let Some(ref new_name) = name {
-                if old_name != *new_name {
-                    workflow.rename_node_in_connections(&old_name, new_name);
+            // Update workflow
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
+
+            if !config.quiet {
+                eprintln!("Updated node '{}'", node_name);
+                if let Some(report) = &report {
+                    print_rename_summary(report);
                 }
             }
+            if let Some(report) = &report {
+                warn_pin_data(report);
+            }
+        }
 
-            // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+        NodesAction::Rename {
+            workflow_id,
+            node_id,
+            new_name,
+            overwrite,
+        } => {
+            let detail = client.get_workflow(&workflow_id).await?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
+
+            let report = workflow.rename_node(&node_id, &new_name)?;
+            if report.old_name == report.new_name {
+                return Err(N8nError::NoChanges);
+            }
+
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
-                eprintln!("Updated node '{}'", node_name);
+                eprintln!(
+                    "Renamed node '{}' to '{}' ({} reference(s) updated)",
+                    report.old_name,
+                    report.new_name,
+                    report.locations.len()
+                );
+            }
+            warn_pin_data(&report);
+            match config.output.format {
+                _ if config.output.query.is_some() => print_single(&report, &config.output)?,
+                OutputFormat::Table if report.locations.is_empty() => {}
+                OutputFormat::Table => print_output(&report.locations, &config.output)?,
+                _ => print_single(&report, &config.output)?,
             }
         }
 
@@ -647,9 +1455,11 @@ async fn handle_nodes(
             workflow_id,
             node_id,
             position,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
             let node = workflow
                 .find_node_mut(&node_id)
@@ -659,8 +1469,7 @@ async fn handle_nodes(
             let node_name = node.name.clone();
 
             // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
                 eprintln!(
@@ -704,7 +1513,7 @@ async fn handle_connections(
                 connections.retain(|c| c.target_node == to_name);
             }
 
-            print_output(&connections, config.output_format)?;
+            print_output(&connections, &config.output)?;
         }
 
         ConnectionsAction::Add {
@@ -714,9 +1523,11 @@ async fn handle_connections(
             output_index,
             input_index,
             r#type,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
             // Resolve node names (n8n uses names in connections)
             let from_name = workflow
@@ -738,8 +1549,7 @@ async fn handle_connections(
             workflow.add_connection(conn);
 
             // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
                 eprintln!("Added connection: {} -> {}", from_name, to_name);
@@ -751,9 +1561,11 @@ async fn handle_connections(
             from,
             to,
             force,
+            overwrite,
         } => {
             let detail = client.get_workflow(&workflow_id).await?;
-            let mut workflow = TypedWorkflow::from_detail(detail)?;
+            let base = TypedWorkflow::from_detail(detail)?;
+            let mut workflow = base.clone();
 
             // Resolve node names
             let from_name = workflow.get_node_name(&from).unwrap_or_else(|| from.clone());
@@ -779,11 +1591,11 @@ async fn handle_connections(
             }
 
             // Update workflow
-            let definition = workflow.to_definition();
-            client.update_workflow(&workflow_id, &definition).await?;
+            save_workflow(client, &workflow_id, &base, &workflow, overwrite).await?;
 
             if !config.quiet {
                 eprintln!("Removed connection: {} -> {}", from_name, to_name);
+                warn_unreachable(&base, &workflow);
             }
         }
     }
@@ -813,17 +1625,79 @@ async fn handle_executions(
             };
 
             let response = client.list_executions(&params).await?;
-            print_output(&response.data, config.output_format)?;
-            if let Some(next) = response.next_cursor {
-                if !config.quiet {
-                    eprintln!("\nMore results available. Use --cursor {} to continue", next);
+            print_output(&response.data, &config.output)?;
+            if let Some(next) = response.next_cursor
+                && !config.quiet
+            {
+                eprintln!("\nMore results available. Use --cursor {} to continue", next);
+            }
+        }
+
+        ExecutionsAction::Logs {
+            workflow_id,
+            status,
+            limit,
+            follow,
+            interval,
+        } => {
+            check_log_options(config)?;
+            let params = ExecutionListParams {
+                workflow_id,
+                status,
+                ..Default::default()
+            };
+            let mut tail = ExecutionTail::new(params);
+
+            print_log_header(config);
+            for event in tail.start(client, limit).await? {
+                print_log_event(&event, config)?;
+            }
+
+            if follow {
+                let interval = Duration::from_secs(interval.max(1));
+                loop {
+                    // Ctrl-C ends the tail cleanly, even in the middle of a poll
+                    let events = tokio::select! {
+                        _ = tokio::signal::ctrl_c() => break,
+                        events = async {
+                            tokio::time::sleep(interval).await;
+                            tail.poll(client).await
+                        } => events?,
+                    };
+                    for event in &events {
+                        print_log_event(event, config)?;
+                    }
                 }
             }
         }
 
         ExecutionsAction::Get { id, include_data } => {
             let execution = client.get_execution(&id, include_data).await?;
-            print_single(&execution, config.output_format)?;
+            print_single(&execution, &config.output)?;
+        }
+
+        ExecutionsAction::Inspect { id, node } => {
+            let execution = client.get_execution(&id, true).await?;
+            let data = execution.execution_data()?.unwrap_or_default();
+
+            match node {
+                Some(name) => {
+                    let items = data
+                        .node_output(&name)
+                        .ok_or_else(|| N8nError::NodeNotFound(name.clone()))?;
+                    print_single(&items, &config.output)?;
+                }
+                None => {
+                    print_output(&data.node_summaries(), &config.output)?;
+                    if !config.quiet
+                        && config.output.format == OutputFormat::Table
+                        && let Some(message) =
+                            data.result_data.error.as_ref().and_then(|e| e.message.as_ref())
+                    {
+                        eprintln!("\nExecution {} failed: {}", execution.id, message);
+                    }
+                }
+            }
         }
 
         ExecutionsAction::Delete { id } => {
@@ -838,12 +1712,13 @@ async fn handle_executions(
             if !config.quiet {
                 eprintln!("Retried execution: {}", execution.id);
             }
-            print_single(&execution, config.output_format)?;
+            print_single(&execution, &config.output)?;
         }
 
         ExecutionsAction::Run {
             workflow_id,
-            wait: _,
+            wait,
+            timeout,
             data,
         } => {
             let input_data = match data {
@@ -857,13 +1732,118 @@ async fn handle_executions(
             if !config.quiet {
                 eprintln!("Started execution: {}", execution.id);
             }
-            print_single(&execution, config.output_format)?;
+
+            if !wait {
+                print_single(&execution, &config.output)?;
+                return Ok(());
+            }
+
+            // Live status line on a terminal, one line per status change otherwise
+            let interactive = !config.quiet && io::stderr().is_terminal();
+            let mut last_status: Option<ExecutionStatus> = None;
+            let result = client
+                .wait_for_execution(
+                    &execution.id,
+                    timeout.map(Duration::from_secs),
+                    |current, elapsed| {
+                        if interactive {
+                            eprint!(
+                                "\r\x1b[2KWaiting for execution {}: {} ({}s)",
+                                current.id,
+                                current.status,
+                                elapsed.as_secs()
+                            );
+                        } else if !config.quiet && last_status.as_ref() != Some(&current.status) {
+                            eprintln!("Execution {}: {}", current.id, current.status);
+                        }
+                        last_status = Some(current.status.clone());
+                    },
+                )
+                .await;
+            if interactive {
+                eprintln!();
+            }
+
+            let finished = result?;
+            print_single(&finished, &config.output)?;
+            finished.into_result()?;
         }
     }
 
     Ok(())
 }
 
+/// Print the column header for `executions logs` in table mode
+fn print_log_header(config: &n8n_cli::Config) {
+    if config.output.format == OutputFormat::Table && !config.quiet {
+        println!(
+            "{:<16}  {:<10}  {:<18}  {:<9}  {:<8}  STOPPED",
+            "STARTED", "ID", "WORKFLOW", "STATUS", "MODE"
+        );
+    }
+}
+
+/// Reject output options that `executions logs` can't honour line by line
+fn check_log_options(config: &n8n_cli::Config) -> Result<()> {
+    let unsupported = |option: String| N8nError::UnsupportedOption {
+        option,
+        command: "executions logs".to_string(),
+    };
+    let format = config.output.format;
+    if matches!(
+        format,
+        OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Markdown
+    ) {
+        let name = clap::ValueEnum::to_possible_value(&format)
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default();
+        return Err(unsupported(format!("-o {}", name)));
+    }
+    if !config.output.columns.is_empty() {
+        return Err(unsupported("--columns".to_string()));
+    }
+    Ok(())
+}
+
+/// Print one `executions logs` event as a line (NDJSON for JSON formats)
+fn print_log_event(event: &TailEvent, config: &n8n_cli::Config) -> Result<()> {
+    match config.output.format {
+        OutputFormat::Table => {
+            let cell = |name| event.execution.cell(name);
+            let marker = match event.kind {
+                TailEventKind::New => "",
+                TailEventKind::Updated => "  (updated)",
+            };
+            println!(
+                "{:<16}  {:<10}  {:<18}  {:<9}  {:<8}  {}{}",
+                cell("started")?,
+                cell("id")?,
+                cell("workflow")?,
+                cell("status")?,
+                cell("mode")?,
+                cell("stopped")?,
+                marker
+            );
+        }
+        OutputFormat::Yaml => {
+            println!("---");
+            print_single(&event.execution, &OutputFormat::Yaml.into())?
+        }
+        OutputFormat::Template => print_single(&event.execution, &config.output)?,
+        OutputFormat::Json | OutputFormat::JsonPretty | OutputFormat::Ndjson => {
+            print_single(&event.execution, &OutputFormat::Ndjson.into())?
+        }
+        // Rejected by check_log_options
+        OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Markdown => {}
+    }
+
+    // Keep output flowing when piped
+    io::stdout().flush().map_err(|e| N8nError::FileWrite {
+        path: "stdout".to_string(),
+        source: e,
+    })
+}
+
 async fn handle_credentials(
     client: &N8nClient,
     action: CredentialsAction,
@@ -878,36 +1858,32 @@ async fn handle_credentials(
             };
 
             let response = client.list_credentials(&params).await?;
-            print_output(&response.data, config.output_format)?;
+            print_output(&response.data, &config.output)?;
         }
 
         CredentialsAction::Schema { type_name } => {
             let schema = client.get_credential_schema(&type_name).await?;
-            print_single(&schema, config.output_format)?;
+            print_single(&schema, &config.output)?;
         }
 
         CredentialsAction::Create { file } => {
-            let content = read_file_or_stdin(&file)?;
-            let credential: CredentialCreate =
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?;
+            let credential: CredentialCreate = read_document(&file)?;
 
             let created = client.create_credential(&credential).await?;
             if !config.quiet {
                 eprintln!("Created credential: {} ({})", created.name, created.id);
             }
-            print_single(&created, config.output_format)?;
+            print_single(&created, &config.output)?;
         }
 
         CredentialsAction::Update { id, file } => {
-            let content = read_file_or_stdin(&file)?;
-            let credential: CredentialCreate =
-                serde_json::from_str(&content).map_err(N8nError::InvalidInput)?;
+            let credential: CredentialCreate = read_document(&file)?;
 
             let updated = client.update_credential(&id, &credential).await?;
             if !config.quiet {
                 eprintln!("Updated credential: {} ({})", updated.name, updated.id);
             }
-            print_single(&updated, config.output_format)?;
+            print_single(&updated, &config.output)?;
         }
 
         CredentialsAction::Delete { id, force } => {
@@ -940,7 +1916,7 @@ async fn handle_tags(
     match action {
         TagsAction::List => {
             let tags = client.list_tags().await?;
-            print_output(&tags, config.output_format)?;
+            print_output(&tags, &config.output)?;
         }
 
         TagsAction::Create { name } => {
@@ -948,7 +1924,7 @@ async fn handle_tags(
             if !config.quiet {
                 eprintln!("Created tag: {} ({})", tag.name, tag.id);
             }
-            print_single(&tag, config.output_format)?;
+            print_single(&tag, &config.output)?;
         }
 
         TagsAction::Update { id, name } => {
@@ -956,7 +1932,7 @@ async fn handle_tags(
             if !config.quiet {
                 eprintln!("Updated tag: {} ({})", tag.name, tag.id);
             }
-            print_single(&tag, config.output_format)?;
+            print_single(&tag, &config.output)?;
         }
 
         TagsAction::Delete { id } => {
@@ -967,19 +1943,11 @@ async fn handle_tags(
         }
 
         TagsAction::Assign { workflow_id, tags } => {
-            // First, get existing tags to resolve names to IDs
-            let all_tags = client.list_tags().await?;
-            let mut tag_ids = Vec::new();
-
-            for tag_name in &tags {
-                if let Some(tag) =
-                    all_tags.iter().find(|t| &t.name == tag_name || &t.id == tag_name)
-                {
-                    tag_ids.push(tag.id.clone());
-                } else {
-                    return Err(N8nError::Config(format!("Tag not found: {}", tag_name)));
-                }
-            }
+            let tag_ids = resolve_tags(client, &tags)
+                .await?
+                .into_iter()
+                .map(|t| t.id)
+                .collect();
 
             client.assign_tags(&workflow_id, tag_ids).await?;
             if !config.quiet {
@@ -1023,6 +1991,35 @@ async fn handle_health(
     Ok(())
 }
 
+/// Write an edited workflow back, guarding against concurrent modification
+async fn save_workflow(
+    client: &N8nClient,
+    id: &str,
+    base: &TypedWorkflow,
+    edited: &TypedWorkflow,
+    overwrite: bool,
+) -> Result<WorkflowDetail> {
+    if overwrite {
+        return client.update_workflow(id, &edited.to_definition()).await;
+    }
+
+    let saved = client.save_workflow(id, base, edited).await?;
+    // Always shown, even with --quiet: the saved workflow isn't exactly the edit
+    if let Some(server_changes) = &saved.merged {
+        eprintln!("Workflow was changed on the server; merged with these concurrent changes:");
+        for item in server_changes.changed_items() {
+            eprintln!("  - {}", item);
+        }
+    }
+    Ok(saved.workflow)
+}
+
+/// Read and parse a JSON or YAML document from a file or stdin
+fn read_document<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = read_file_or_stdin(path)?;
+    input::parse_document(&content, path)
+}
+
 /// Read content from a file or stdin if path is "-"
 fn read_file_or_stdin(path: &Path) -> Result<String> {
     if path.as_os_str() == "-" {
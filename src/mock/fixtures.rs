@@ -0,0 +1,82 @@
+use crate::error::{N8nError, Result};
+use crate::input::parse_document;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+/// Initial data of a mock server
+///
+/// Items are plain JSON objects as the n8n API returns them. Missing fields
+/// (IDs, timestamps, `active`, `versionId`, ...) are filled in when seeding,
+/// so a fixture can be as short as `{"name": "Orders"}`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fixtures {
+    #[serde(default)]
+    pub workflows: Vec<Value>,
+    #[serde(default)]
+    pub executions: Vec<Value>,
+    #[serde(default)]
+    pub credentials: Vec<Value>,
+    #[serde(default)]
+    pub tags: Vec<Value>,
+}
+
+impl Fixtures {
+    /// Load fixtures from a JSON or YAML file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        parse_document(&content, path)
+    }
+
+    /// A small instance to try commands against
+    pub fn sample() -> Self {
+        let node = |name: &str, node_type: &str, x: i64| {
+            json!({
+                "id": format!("node-{}", name.to_lowercase()),
+                "name": name,
+                "type": node_type,
+                "typeVersion": 1,
+                "position": [x, 300],
+                "parameters": {}
+            })
+        };
+        let mut webhook = node("Webhook", "n8n-nodes-base.webhook", 250);
+        webhook["parameters"] = json!({ "path": "orders", "httpMethod": "POST" });
+        let mut slack = node("Slack", "n8n-nodes-base.slack", 450);
+        slack["credentials"] = json!({ "slackApi": { "id": "1", "name": "Slack bot" } });
+
+        Self {
+            workflows: vec![
+                json!({
+                    "id": "1",
+                    "name": "Order notifications",
+                    "active": true,
+                    "nodes": [webhook, slack],
+                    "connections": {
+                        "Webhook": { "main": [[{ "node": "Slack", "type": "main", "index": 0 }]] }
+                    },
+                    "tags": [{ "id": "1", "name": "production" }]
+                }),
+                json!({
+                    "id": "2",
+                    "name": "Nightly report",
+                    "nodes": [node("Schedule Trigger", "n8n-nodes-base.scheduleTrigger", 250)]
+                }),
+            ],
+            executions: vec![
+                json!({ "id": "1", "workflowId": "1", "status": "success" }),
+                json!({ "id": "2", "workflowId": "1", "status": "error" }),
+            ],
+            credentials: vec![json!({ "id": "1", "name": "Slack bot", "type": "slackApi" })],
+            tags: vec![
+                json!({ "id": "1", "name": "production" }),
+                json!({ "id": "2", "name": "staging" }),
+            ],
+        }
+    }
+}
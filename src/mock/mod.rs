@@ -0,0 +1,13 @@
+//! In-process mock of the n8n public API
+//!
+//! Serves workflows, executions, credentials and tags from memory, with the
+//! same cursor pagination as n8n. Used by the integration tests and by the
+//! hidden `n8n mock-server` command.
+
+mod fixtures;
+mod server;
+mod store;
+
+pub use fixtures::Fixtures;
+pub use server::{MockServer, MOCK_API_KEY};
+pub use store::{MockRequest, MockResponse, MockStore};
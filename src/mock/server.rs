@@ -0,0 +1,241 @@
+use crate::error::{N8nError, Result};
+use crate::mock::{Fixtures, MockRequest, MockResponse, MockStore};
+use serde_json::Value;
+use std::net::{SocketAddr, TcpListener as StdTcpListener};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+/// API key the mock server accepts unless another one is given
+pub const MOCK_API_KEY: &str = "n8n-mock-api-key";
+
+/// A mock n8n instance serving a `MockStore` over HTTP
+///
+/// The server runs on its own thread, so it can be used from synchronous
+/// tests as well as from async code. It stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    api_key: String,
+    store: Arc<Mutex<MockStore>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Start a server on a free local port, accepting `MOCK_API_KEY`
+    pub fn start(fixtures: Fixtures) -> Result<Self> {
+        let addr = SocketAddr::from(([127, 0, 0, 1], 0));
+        Self::bind(addr, fixtures, MOCK_API_KEY)
+    }
+
+    /// Start a server on `addr`, accepting requests with `api_key`
+    pub fn bind(addr: SocketAddr, fixtures: Fixtures, api_key: &str) -> Result<Self> {
+        let bind_error = |e| N8nError::MockServer {
+            addr: addr.to_string(),
+            source: e,
+        };
+        let listener = StdTcpListener::bind(addr).map_err(bind_error)?;
+        listener.set_nonblocking(true).map_err(bind_error)?;
+        let local_addr = listener.local_addr().map_err(bind_error)?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(bind_error)?;
+
+        let store = Arc::new(Mutex::new(MockStore::new(fixtures)));
+        let (shutdown, stopped) = oneshot::channel();
+        let state = (store.clone(), api_key.to_string());
+        std::thread::spawn(move || runtime.block_on(serve(listener, state, stopped)));
+
+        Ok(Self {
+            addr: local_addr,
+            api_key: api_key.to_string(),
+            store,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Base URL to configure the CLI with (e.g. `http://127.0.0.1:41234`)
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Current state, e.g. to check what a command changed
+    pub fn store(&self) -> MutexGuard<'_, MockStore> {
+        self.store.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+type State = (Arc<Mutex<MockStore>>, String);
+
+async fn serve(listener: StdTcpListener, state: State, mut stopped: oneshot::Receiver<()>) {
+    let Ok(listener) = TcpListener::from_std(listener) else {
+        return;
+    };
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                if let Ok((socket, _)) = accepted {
+                    tokio::spawn(handle_connection(socket, state.clone()));
+                }
+            }
+            _ = &mut stopped => break,
+        }
+    }
+}
+
+/// Answer one request per connection
+async fn handle_connection(socket: TcpStream, (store, api_key): State) {
+    let mut reader = BufReader::new(socket);
+    let response = match read_request(&mut reader).await {
+        Ok((request, key)) if request.path.starts_with("/api/") && key != api_key => MockResponse {
+            status: 401,
+            body: serde_json::json!({ "message": "unauthorized" }),
+        },
+        Ok((request, _)) => store
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .handle(&request),
+        Err(message) => MockResponse {
+            status: 400,
+            body: serde_json::json!({ "message": message }),
+        },
+    };
+
+    let body = response.body.to_string();
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\n\
+         Content-Length: {}\r\nDate: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason(response.status),
+        body.len(),
+        chrono::Utc::now().format("%a, %d %b %Y %H:%M:%S GMT")
+    );
+    let socket = reader.get_mut();
+    let _ = socket.write_all(head.as_bytes()).await;
+    let _ = socket.write_all(body.as_bytes()).await;
+    let _ = socket.shutdown().await;
+}
+
+/// Parse a request and its `X-N8N-API-KEY` header
+async fn read_request(
+    reader: &mut BufReader<TcpStream>,
+) -> std::result::Result<(MockRequest, String), String> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err("Malformed request line".to_string());
+    };
+    let url = url::Url::parse(&format!("http://mock{}", target)).map_err(|e| e.to_string())?;
+    let mut request = MockRequest {
+        method: method.to_string(),
+        path: url.path().to_string(),
+        query: url.query_pairs().into_owned().collect(),
+        body: None,
+    };
+
+    let mut api_key = String::new();
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        reader
+            .read_line(&mut line)
+            .await
+            .map_err(|e| e.to_string())?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("x-n8n-api-key") {
+                api_key = value.to_string();
+            } else if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().map_err(|_| "Invalid Content-Length")?;
+            }
+        }
+    }
+
+    if content_length > 0 {
+        let mut body = vec![0; content_length];
+        reader
+            .read_exact(&mut body)
+            .await
+            .map_err(|e| e.to_string())?;
+        let body: Value =
+            serde_json::from_slice(&body).map_err(|e| format!("Invalid JSON body: {}", e))?;
+        request.body = Some(body);
+    }
+    Ok((request, api_key))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        409 => "Conflict",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::N8nClient;
+    use crate::config::Config;
+
+    fn client(server: &MockServer, api_key: &str) -> N8nClient {
+        let config = Config {
+            base_url: server.url(),
+            api_key: api_key.to_string(),
+            ..Config::default()
+        };
+        N8nClient::new(&config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serves_client_requests() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let client = client(&server, MOCK_API_KEY);
+
+        let workflow = client.get_workflow("1").await.unwrap();
+        assert_eq!(workflow.name, "Order notifications");
+        assert_eq!(client.list_tags().await.unwrap().len(), 2);
+        assert_eq!(client.health_check().await.unwrap().status, "ok");
+
+        client.deactivate_workflow("1").await.unwrap();
+        assert_eq!(server.store().workflow("1").unwrap()["active"], false);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_wrong_api_key() {
+        let server = MockServer::start(Fixtures::default()).unwrap();
+        let err = client(&server, "wrong")
+            .get_workflow("1")
+            .await
+            .unwrap_err();
+        assert_eq!(err.exit_code(), 77);
+    }
+}
@@ -0,0 +1,821 @@
+use crate::mock::Fixtures;
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+
+/// Default page size of list endpoints
+const DEFAULT_LIMIT: usize = 100;
+
+/// Largest page size list endpoints accept
+const MAX_LIMIT: usize = 250;
+
+/// n8n version reported by `/rest/settings`
+const MOCK_VERSION: &str = "1.0.0-mock";
+
+/// Fields a workflow create or update request may contain
+const WORKFLOW_FIELDS: &[&str] = &["name", "nodes", "connections", "settings", "staticData"];
+
+/// A request to the mock API
+#[derive(Debug, Clone, Default)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path without the query string (e.g. `/api/v1/workflows`)
+    pub path: String,
+    /// Decoded query parameters
+    pub query: Vec<(String, String)>,
+    pub body: Option<Value>,
+}
+
+impl MockRequest {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A response of the mock API
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl MockResponse {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "message": message.into() }),
+        }
+    }
+
+    fn not_found(what: &str, id: &str) -> Self {
+        Self::error(404, format!("{} {} not found", what, id))
+    }
+}
+
+/// In-memory state of a mock n8n instance
+///
+/// Items are kept as JSON objects in creation order. IDs are numeric strings
+/// shared by all item kinds, continuing after the highest ID of the fixtures.
+#[derive(Debug, Clone, Default)]
+pub struct MockStore {
+    workflows: Vec<Value>,
+    executions: Vec<Value>,
+    credentials: Vec<Value>,
+    tags: Vec<Value>,
+    next_id: u64,
+}
+
+impl MockStore {
+    /// Seed a store, filling in fields the fixtures leave out
+    pub fn new(fixtures: Fixtures) -> Self {
+        let highest_id = [
+            &fixtures.workflows,
+            &fixtures.executions,
+            &fixtures.credentials,
+            &fixtures.tags,
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.get("id").and_then(Value::as_str))
+        .filter_map(|id| id.parse::<u64>().ok())
+        .max()
+        .unwrap_or(0);
+        let mut store = Self {
+            next_id: highest_id + 1,
+            ..Self::default()
+        };
+
+        for tag in fixtures.tags {
+            let tag = store.new_tag(tag);
+            store.tags.push(tag);
+        }
+        for workflow in fixtures.workflows {
+            let workflow = store.new_workflow(workflow);
+            store.workflows.push(workflow);
+        }
+        for execution in fixtures.executions {
+            let execution = store.new_execution(execution);
+            store.executions.push(execution);
+        }
+        for credential in fixtures.credentials {
+            let credential = store.new_credential(credential);
+            store.credentials.push(credential);
+        }
+        store
+    }
+
+    pub fn workflows(&self) -> &[Value] {
+        &self.workflows
+    }
+
+    pub fn executions(&self) -> &[Value] {
+        &self.executions
+    }
+
+    pub fn credentials(&self) -> &[Value] {
+        &self.credentials
+    }
+
+    pub fn tags(&self) -> &[Value] {
+        &self.tags
+    }
+
+    /// Workflow with this ID
+    pub fn workflow(&self, id: &str) -> Option<&Value> {
+        find(&self.workflows, id)
+    }
+
+    /// Move an execution to another status, e.g. to finish a running one
+    pub fn set_execution_status(&mut self, id: &str, status: &str) {
+        if let Some(execution) = self.executions.iter_mut().find(|e| e["id"] == id) {
+            let done = !matches!(status, "new" | "running" | "waiting");
+            execution["status"] = json!(status);
+            execution["finished"] = json!(status == "success");
+            execution["stoppedAt"] = if done { json!(now()) } else { Value::Null };
+        }
+    }
+
+    /// Answer a request (authentication is checked by the server)
+    pub fn handle(&mut self, request: &MockRequest) -> MockResponse {
+        let path: Vec<&str> = request
+            .path
+            .trim_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        match (request.method.as_str(), path.as_slice()) {
+            ("GET", ["healthz"]) | ("GET", ["healthz", "readiness"]) => {
+                MockResponse::ok(json!({ "status": "ok" }))
+            }
+            ("GET", ["rest", "settings"]) => {
+                MockResponse::ok(json!({ "data": { "versionCli": MOCK_VERSION } }))
+            }
+            (method, ["api", "v1", rest @ ..]) => self.handle_api(method, rest, request),
+            (_, [kind @ ("webhook" | "webhook-test"), rest @ ..]) => {
+                self.handle_webhook(*kind == "webhook-test", &rest.join("/"), request)
+            }
+            _ => MockResponse::error(404, format!("Cannot {} {}", request.method, request.path)),
+        }
+    }
+
+    fn handle_api(&mut self, method: &str, path: &[&str], request: &MockRequest) -> MockResponse {
+        let body = request.body.clone().unwrap_or(Value::Null);
+        match (method, path) {
+            ("GET", ["workflows"]) => self.list_workflows(request),
+            ("POST", ["workflows"]) => self.create_workflow(body),
+            ("GET", ["workflows", id]) => match self.workflow(id) {
+                Some(workflow) => MockResponse::ok(workflow.clone()),
+                None => MockResponse::not_found("Workflow", id),
+            },
+            ("PUT", ["workflows", id]) => self.update_workflow(id, body),
+            ("DELETE", ["workflows", id]) => match position(&self.workflows, id) {
+                Some(i) => MockResponse::ok(self.workflows.remove(i)),
+                None => MockResponse::not_found("Workflow", id),
+            },
+            ("POST", ["workflows", id, action @ ("activate" | "deactivate")]) => {
+                let active = *action == "activate";
+                self.modify_workflow(id, |workflow| {
+                    workflow["active"] = json!(active);
+                })
+            }
+            ("GET", ["workflows", id, "tags"]) => match self.workflow(id) {
+                Some(workflow) => MockResponse::ok(workflow["tags"].clone()),
+                None => MockResponse::not_found("Workflow", id),
+            },
+            ("PUT", ["workflows", id, "tags"]) => self.assign_tags(id, body),
+            ("POST", ["workflows", id, "execute"]) => self.execute_workflow(id, body),
+
+            ("GET", ["executions"]) => self.list_executions(request),
+            ("GET", ["executions", id]) => match find(&self.executions, id) {
+                Some(execution) => {
+                    let include_data = request.param("includeData") == Some("true");
+                    MockResponse::ok(without_data(execution, include_data))
+                }
+                None => MockResponse::not_found("Execution", id),
+            },
+            ("DELETE", ["executions", id]) => match position(&self.executions, id) {
+                Some(i) => MockResponse::ok(without_data(&self.executions.remove(i), false)),
+                None => MockResponse::not_found("Execution", id),
+            },
+            ("POST", ["executions", id, "retry"]) => self.retry_execution(id),
+
+            ("GET", ["credentials"]) => {
+                let items: Vec<Value> = self
+                    .credentials
+                    .iter()
+                    .filter(|c| request.param("type").is_none_or(|t| c["type"] == t))
+                    .map(|c| without_data(c, false))
+                    .collect();
+                paginate(items, request)
+            }
+            ("POST", ["credentials"]) => self.create_credential(body),
+            ("GET", ["credentials", "schema", credential_type]) => MockResponse::ok(json!({
+                "type": "object",
+                "title": credential_type,
+                "properties": {},
+                "additionalProperties": true
+            })),
+            ("PUT", ["credentials", id]) => {
+                let Some(credential) = find_mut(&mut self.credentials, id) else {
+                    return MockResponse::not_found("Credential", id);
+                };
+                merge(credential, &body, &["name", "type", "data"]);
+                credential["updatedAt"] = json!(now());
+                MockResponse::ok(without_data(credential, false))
+            }
+            ("DELETE", ["credentials", id]) => match position(&self.credentials, id) {
+                Some(i) => MockResponse::ok(without_data(&self.credentials.remove(i), false)),
+                None => MockResponse::not_found("Credential", id),
+            },
+
+            // The tags endpoint answers with a bare array
+            ("GET", ["tags"]) => MockResponse::ok(json!(self.tags)),
+            ("POST", ["tags"]) => self.create_tag(body),
+            ("GET", ["tags", id]) => match find(&self.tags, id) {
+                Some(tag) => MockResponse::ok(tag.clone()),
+                None => MockResponse::not_found("Tag", id),
+            },
+            ("PUT", ["tags", id]) => self.update_tag(id, body),
+            ("DELETE", ["tags", id]) => match position(&self.tags, id) {
+                Some(i) => {
+                    let tag = self.tags.remove(i);
+                    for workflow in &mut self.workflows {
+                        if let Some(tags) = workflow["tags"].as_array_mut() {
+                            tags.retain(|t| t["id"] != tag["id"]);
+                        }
+                    }
+                    MockResponse::ok(tag)
+                }
+                None => MockResponse::not_found("Tag", id),
+            },
+
+            _ => MockResponse::error(404, format!("Cannot {} {}", method, request.path)),
+        }
+    }
+
+    fn list_workflows(&self, request: &MockRequest) -> MockResponse {
+        let active = request.param("active").map(|a| a == "true");
+        let tags: Vec<&str> = request
+            .param("tags")
+            .map(|t| t.split(',').map(str::trim).collect())
+            .unwrap_or_default();
+        let name = request.param("name").map(str::to_lowercase);
+
+        let items = self
+            .workflows
+            .iter()
+            .filter(|w| active.is_none_or(|a| w["active"] == a))
+            .filter(|w| {
+                tags.is_empty()
+                    || w["tags"]
+                        .as_array()
+                        .is_some_and(|t| t.iter().any(|t| tags.iter().any(|n| t["name"] == *n)))
+            })
+            .filter(|w| {
+                name.as_ref().is_none_or(|n| {
+                    w["name"]
+                        .as_str()
+                        .is_some_and(|w| w.to_lowercase().contains(n))
+                })
+            })
+            .cloned()
+            .collect();
+        paginate(items, request)
+    }
+
+    fn create_workflow(&mut self, body: Value) -> MockResponse {
+        if let Err(response) = check_workflow_body(&body) {
+            return response;
+        }
+        let mut workflow = json!({});
+        merge(&mut workflow, &body, WORKFLOW_FIELDS);
+        let workflow = self.new_workflow(workflow);
+        self.workflows.push(workflow.clone());
+        MockResponse::ok(workflow)
+    }
+
+    fn update_workflow(&mut self, id: &str, body: Value) -> MockResponse {
+        if let Err(response) = check_workflow_body(&body) {
+            return response;
+        }
+        let version_id = self.generate_id();
+        self.modify_workflow(id, |workflow| {
+            merge(workflow, &body, WORKFLOW_FIELDS);
+            workflow["versionId"] = json!(format!("v{}", version_id));
+        })
+    }
+
+    fn assign_tags(&mut self, id: &str, body: Value) -> MockResponse {
+        // Accept both `[{"id": ...}]` and `{"tags": [{"id": ...}]}`
+        let requested = body.get("tags").unwrap_or(&body);
+        let Some(requested) = requested.as_array() else {
+            return MockResponse::error(400, "request/body must be array");
+        };
+
+        let mut tags = Vec::new();
+        for tag in requested {
+            let tag_id = tag["id"].as_str().unwrap_or_default();
+            match find(&self.tags, tag_id) {
+                Some(tag) => tags.push(json!({ "id": tag["id"], "name": tag["name"] })),
+                None => return MockResponse::not_found("Tag", tag_id),
+            }
+        }
+
+        let response = MockResponse::ok(json!(tags));
+        match self.modify_workflow(id, |workflow| workflow["tags"] = json!(tags)) {
+            MockResponse { status: 200, .. } => response,
+            error => error,
+        }
+    }
+
+    fn execute_workflow(&mut self, workflow_id: &str, body: Value) -> MockResponse {
+        if self.workflow(workflow_id).is_none() {
+            return MockResponse::not_found("Workflow", workflow_id);
+        }
+        let mut execution = json!({
+            "workflowId": workflow_id,
+            "mode": "manual",
+            "data": { "resultData": { "runData": {} } }
+        });
+        // Keep the input so tests can check what was sent
+        if let Some(data) = body.get("data").filter(|d| !d.is_null()) {
+            execution["data"]["input"] = data.clone();
+        }
+        let execution = self.new_execution(execution);
+        self.executions.push(execution.clone());
+        MockResponse::ok(execution)
+    }
+
+    /// Start the workflow whose Webhook node listens on `path`
+    ///
+    /// Production webhooks only reach active workflows. Test webhooks are
+    /// always accepted, as if the editor were listening.
+    fn handle_webhook(&mut self, test: bool, path: &str, request: &MockRequest) -> MockResponse {
+        let workflow_id = self
+            .workflows
+            .iter()
+            .filter(|w| test || w["active"] == true)
+            .find(|w| webhook_paths(w).iter().any(|p| p.trim_matches('/') == path))
+            .and_then(|w| w["id"].as_str())
+            .map(str::to_string);
+        let Some(workflow_id) = workflow_id else {
+            return MockResponse::error(
+                404,
+                format!(
+                    "The requested webhook \"{} {}\" is not registered.",
+                    request.method, path
+                ),
+            );
+        };
+
+        let mut execution = json!({ "workflowId": workflow_id, "mode": "webhook" });
+        if let Some(body) = &request.body {
+            execution["data"] = json!({ "input": body });
+        }
+        let execution = self.new_execution(execution);
+        self.executions.push(execution);
+        MockResponse::ok(json!({ "message": "Workflow was started" }))
+    }
+
+    fn list_executions(&self, request: &MockRequest) -> MockResponse {
+        let include_data = request.param("includeData") == Some("true");
+        // Newest first, like n8n
+        let items = self
+            .executions
+            .iter()
+            .rev()
+            .filter(|e| {
+                request
+                    .param("workflowId")
+                    .is_none_or(|id| e["workflowId"] == id)
+            })
+            .filter(|e| request.param("status").is_none_or(|s| e["status"] == s))
+            .map(|e| without_data(e, include_data))
+            .collect();
+        paginate(items, request)
+    }
+
+    fn retry_execution(&mut self, id: &str) -> MockResponse {
+        let Some(original) = find(&self.executions, id) else {
+            return MockResponse::not_found("Execution", id);
+        };
+        let execution = json!({
+            "workflowId": original["workflowId"],
+            "mode": "retry",
+            "retryOf": id
+        });
+        let execution = self.new_execution(execution);
+        self.executions.push(execution.clone());
+        MockResponse::ok(execution)
+    }
+
+    fn create_credential(&mut self, body: Value) -> MockResponse {
+        for field in ["name", "type"] {
+            if !body[field].is_string() {
+                return MockResponse::error(
+                    400,
+                    format!("request/body must have required property '{}'", field),
+                );
+            }
+        }
+        let mut credential = json!({});
+        merge(&mut credential, &body, &["name", "type", "data"]);
+        let credential = self.new_credential(credential);
+        self.credentials.push(credential.clone());
+        MockResponse::ok(without_data(&credential, false))
+    }
+
+    fn create_tag(&mut self, body: Value) -> MockResponse {
+        let Some(name) = body["name"].as_str() else {
+            return MockResponse::error(400, "request/body must have required property 'name'");
+        };
+        if self.tags.iter().any(|t| t["name"] == name) {
+            return MockResponse::error(409, "Tag already exists");
+        }
+        let tag = self.new_tag(json!({ "name": name }));
+        self.tags.push(tag.clone());
+        MockResponse::ok(tag)
+    }
+
+    fn update_tag(&mut self, id: &str, body: Value) -> MockResponse {
+        let Some(name) = body["name"].as_str() else {
+            return MockResponse::error(400, "request/body must have required property 'name'");
+        };
+        if self.tags.iter().any(|t| t["name"] == name && t["id"] != id) {
+            return MockResponse::error(409, "Tag already exists");
+        }
+        let Some(tag) = find_mut(&mut self.tags, id) else {
+            return MockResponse::not_found("Tag", id);
+        };
+        tag["name"] = json!(name);
+        tag["updatedAt"] = json!(now());
+        let tag = tag.clone();
+
+        for workflow in &mut self.workflows {
+            if let Some(tags) = workflow["tags"].as_array_mut() {
+                for t in tags.iter_mut().filter(|t| t["id"] == id) {
+                    t["name"] = json!(name);
+                }
+            }
+        }
+        MockResponse::ok(tag)
+    }
+
+    /// Change a workflow and bump its `updatedAt`
+    fn modify_workflow(&mut self, id: &str, change: impl FnOnce(&mut Value)) -> MockResponse {
+        match find_mut(&mut self.workflows, id) {
+            Some(workflow) => {
+                change(workflow);
+                workflow["updatedAt"] = json!(now());
+                MockResponse::ok(workflow.clone())
+            }
+            None => MockResponse::not_found("Workflow", id),
+        }
+    }
+
+    fn new_workflow(&mut self, workflow: Value) -> Value {
+        let mut workflow = self.with_id(workflow);
+        let version_id = format!("v{}", self.generate_id());
+        let now = now();
+        defaults(
+            &mut workflow,
+            &[
+                ("active", json!(false)),
+                ("nodes", json!([])),
+                ("connections", json!({})),
+                ("settings", json!({})),
+                ("tags", json!([])),
+                ("versionId", json!(version_id)),
+                ("createdAt", json!(now)),
+                ("updatedAt", json!(now)),
+            ],
+        );
+        if workflow["settings"].is_null() {
+            workflow["settings"] = json!({});
+        }
+        workflow
+    }
+
+    fn new_execution(&mut self, execution: Value) -> Value {
+        let mut execution = self.with_id(execution);
+        let now = now();
+        defaults(
+            &mut execution,
+            &[
+                ("status", json!("success")),
+                ("mode", json!("manual")),
+                ("startedAt", json!(now)),
+            ],
+        );
+        let status = execution["status"].as_str().unwrap_or_default().to_string();
+        let running = matches!(status.as_str(), "new" | "running" | "waiting");
+        defaults(
+            &mut execution,
+            &[
+                ("finished", json!(status == "success")),
+                ("stoppedAt", if running { Value::Null } else { json!(now) }),
+            ],
+        );
+        execution
+    }
+
+    fn new_credential(&mut self, credential: Value) -> Value {
+        let mut credential = self.with_id(credential);
+        let now = now();
+        defaults(
+            &mut credential,
+            &[("createdAt", json!(now)), ("updatedAt", json!(now))],
+        );
+        credential
+    }
+
+    fn new_tag(&mut self, tag: Value) -> Value {
+        self.new_credential(tag)
+    }
+
+    /// Give an item an ID if it has none
+    fn with_id(&mut self, item: Value) -> Value {
+        let mut item = match item {
+            Value::Object(map) => Value::Object(map),
+            _ => Value::Object(Map::new()),
+        };
+        if !item["id"].is_string() {
+            item["id"] = json!(self.generate_id().to_string());
+        }
+        item
+    }
+
+    fn generate_id(&mut self) -> u64 {
+        let id = self.next_id.max(1);
+        self.next_id = id + 1;
+        id
+    }
+}
+
+/// Page of a list response, with the offset of the next page as cursor
+fn paginate(items: Vec<Value>, request: &MockRequest) -> MockResponse {
+    let limit = match request.param("limit").map(str::parse::<usize>) {
+        None => DEFAULT_LIMIT,
+        Some(Ok(limit)) if (1..=MAX_LIMIT).contains(&limit) => limit,
+        Some(_) => {
+            return MockResponse::error(
+                400,
+                format!("request/query/limit must be between 1 and {}", MAX_LIMIT),
+            )
+        }
+    };
+    let offset = match request.param("cursor").map(str::parse::<usize>) {
+        None => 0,
+        Some(Ok(offset)) => offset,
+        Some(Err(_)) => return MockResponse::error(400, "An invalid cursor was provided"),
+    };
+
+    let next = offset + limit;
+    let next_cursor = (next < items.len()).then(|| next.to_string());
+    let data: Vec<Value> = items.into_iter().skip(offset).take(limit).collect();
+    MockResponse::ok(json!({ "data": data, "nextCursor": next_cursor }))
+}
+
+/// Reject workflow bodies n8n would reject
+fn check_workflow_body(body: &Value) -> Result<(), MockResponse> {
+    let Some(fields) = body.as_object() else {
+        return Err(MockResponse::error(400, "request/body must be object"));
+    };
+    if !body["name"].is_string() {
+        return Err(MockResponse::error(
+            400,
+            "request/body must have required property 'name'",
+        ));
+    }
+    // Read-only fields like `active` or `tags` are rejected by the real API
+    if let Some(field) = fields
+        .keys()
+        .find(|k| !WORKFLOW_FIELDS.contains(&k.as_str()))
+    {
+        return Err(MockResponse::error(
+            400,
+            format!(
+                "request/body must NOT have additional properties ({})",
+                field
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn find<'a>(items: &'a [Value], id: &str) -> Option<&'a Value> {
+    items.iter().find(|item| item["id"] == id)
+}
+
+fn find_mut<'a>(items: &'a mut [Value], id: &str) -> Option<&'a mut Value> {
+    items.iter_mut().find(|item| item["id"] == id)
+}
+
+fn position(items: &[Value], id: &str) -> Option<usize> {
+    items.iter().position(|item| item["id"] == id)
+}
+
+/// Copy `fields` present in `from` into `to`
+/// Paths the webhook and form trigger nodes of a workflow listen on
+fn webhook_paths(workflow: &Value) -> Vec<String> {
+    let nodes = workflow["nodes"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    nodes
+        .iter()
+        .filter(|node| {
+            let node_type = node["type"].as_str().unwrap_or_default();
+            node_type.contains("webhook") || node_type.contains("formTrigger")
+        })
+        .map(|node| {
+            node["parameters"]["path"]
+                .as_str()
+                .or(workflow["id"].as_str())
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect()
+}
+
+fn merge(to: &mut Value, from: &Value, fields: &[&str]) {
+    for field in fields {
+        if let Some(value) = from.get(*field) {
+            to[*field] = value.clone();
+        }
+    }
+}
+
+/// Set fields that are missing
+fn defaults(item: &mut Value, fields: &[(&str, Value)]) {
+    for (field, value) in fields {
+        if item.get(*field).is_none() {
+            item[*field] = value.clone();
+        }
+    }
+}
+
+/// Copy of an item with its `data` removed unless requested
+fn without_data(item: &Value, include_data: bool) -> Value {
+    let mut item = item.clone();
+    if !include_data && let Some(fields) = item.as_object_mut() {
+        fields.remove("data");
+    }
+    item
+}
+
+/// Current time in the format n8n uses
+fn now() -> String {
+    Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(
+        method: &str,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> MockRequest {
+        MockRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body,
+        }
+    }
+
+    #[test]
+    fn test_cursor_pagination() {
+        let fixtures = Fixtures {
+            workflows: (0..5)
+                .map(|i| json!({ "name": format!("wf{}", i) }))
+                .collect(),
+            ..Default::default()
+        };
+        let mut store = MockStore::new(fixtures);
+
+        let mut names = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut query = vec![("limit", "2")];
+            if let Some(c) = &cursor {
+                query.push(("cursor", c));
+            }
+            let response = store.handle(&request("GET", "/api/v1/workflows", &query, None));
+            assert_eq!(response.status, 200);
+            for w in response.body["data"].as_array().unwrap() {
+                names.push(w["name"].as_str().unwrap().to_string());
+            }
+            match response.body["nextCursor"].as_str() {
+                Some(next) => cursor = Some(next.to_string()),
+                None => break,
+            }
+        }
+        assert_eq!(names, ["wf0", "wf1", "wf2", "wf3", "wf4"]);
+
+        let response = store.handle(&request(
+            "GET",
+            "/api/v1/workflows",
+            &[("limit", "500")],
+            None,
+        ));
+        assert_eq!(response.status, 400);
+    }
+
+    #[test]
+    fn test_workflow_lifecycle() {
+        let mut store = MockStore::new(Fixtures::sample());
+
+        let created = store.handle(&request(
+            "POST",
+            "/api/v1/workflows",
+            &[],
+            Some(json!({ "name": "New", "nodes": [], "connections": {}, "settings": {} })),
+        ));
+        assert_eq!(created.status, 200);
+        let id = created.body["id"].as_str().unwrap().to_string();
+        assert_eq!(created.body["active"], false);
+        assert!(store.workflow(&id).is_some());
+
+        // Read-only fields are refused like on a real instance
+        let rejected = store.handle(&request(
+            "PUT",
+            &format!("/api/v1/workflows/{}", id),
+            &[],
+            Some(json!({ "name": "New", "active": true })),
+        ));
+        assert_eq!(rejected.status, 400);
+
+        let updated = store.handle(&request(
+            "PUT",
+            &format!("/api/v1/workflows/{}", id),
+            &[],
+            Some(json!({ "name": "Renamed" })),
+        ));
+        assert_eq!(updated.body["name"], "Renamed");
+        assert_ne!(updated.body["versionId"], created.body["versionId"]);
+
+        let tagged = store.handle(&request(
+            "PUT",
+            &format!("/api/v1/workflows/{}/tags", id),
+            &[],
+            Some(json!({ "tags": [{ "id": "2" }] })),
+        ));
+        assert_eq!(tagged.body, json!([{ "id": "2", "name": "staging" }]));
+        let staging = store.handle(&request(
+            "GET",
+            "/api/v1/workflows",
+            &[("tags", "staging")],
+            None,
+        ));
+        assert_eq!(staging.body["data"].as_array().unwrap().len(), 1);
+
+        let path = format!("/api/v1/workflows/{}", id);
+        assert_eq!(
+            store.handle(&request("DELETE", &path, &[], None)).status,
+            200
+        );
+        assert_eq!(store.handle(&request("GET", &path, &[], None)).status, 404);
+    }
+
+    #[test]
+    fn test_executions_filter_and_data() {
+        let mut store = MockStore::new(Fixtures::sample());
+
+        let run = store.handle(&request(
+            "POST",
+            "/api/v1/workflows/2/execute",
+            &[],
+            Some(json!({ "data": { "x": 1 } })),
+        ));
+        let id = run.body["id"].as_str().unwrap().to_string();
+        assert_eq!(run.body["status"], "success");
+
+        let errors = store.handle(&request(
+            "GET",
+            "/api/v1/executions",
+            &[("status", "error")],
+            None,
+        ));
+        assert_eq!(errors.body["data"].as_array().unwrap().len(), 1);
+
+        let path = format!("/api/v1/executions/{}", id);
+        let plain = store.handle(&request("GET", &path, &[], None));
+        assert!(plain.body.get("data").is_none());
+        let full = store.handle(&request("GET", &path, &[("includeData", "true")], None));
+        assert_eq!(full.body["data"]["input"]["x"], 1);
+    }
+}
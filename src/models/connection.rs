@@ -1,5 +1,4 @@
-use crate::models::common::truncate;
-use crate::output::Outputable;
+use crate::output::{Column, Outputable};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -149,16 +148,16 @@ impl Connection {
 }
 
 impl Outputable for Connection {
-    fn headers() -> Vec<&'static str> {
-        vec!["FROM NODE", "OUTPUT", "TO NODE", "INPUT"]
-    }
-
-    fn row(&self) -> Vec<String> {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            truncate(&self.source_node, 25),
-            format!("{}[{}]", self.source_type, self.source_output),
-            truncate(&self.target_node, 25),
-            format!("{}[{}]", self.target_type, self.target_input),
+            Column::new("from", "FROM NODE", |c: &Self| c.source_node.clone()).truncate(25),
+            Column::new("output", "OUTPUT", |c: &Self| {
+                format!("{}[{}]", c.source_type, c.source_output)
+            }),
+            Column::new("to", "TO NODE", |c: &Self| c.target_node.clone()).truncate(25),
+            Column::new("input", "INPUT", |c: &Self| {
+                format!("{}[{}]", c.target_type, c.target_input)
+            }),
         ]
     }
 }
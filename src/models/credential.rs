@@ -1,5 +1,5 @@
-use crate::models::common::{format_timestamp_str, truncate};
-use crate::output::Outputable;
+use crate::models::common::format_timestamp_str;
+use crate::output::{Column, Outputable, SortKey};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -17,16 +17,20 @@ pub struct Credential {
 }
 
 impl Outputable for Credential {
-    fn headers() -> Vec<&'static str> {
-        vec!["ID", "NAME", "TYPE", "UPDATED"]
-    }
-
-    fn row(&self) -> Vec<String> {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            self.id.clone(),
-            truncate(&self.name, 30),
-            self.credential_type.clone(),
-            format_timestamp_str(&self.updated_at),
+            Column::new("id", "ID", |c: &Self| c.id.clone()),
+            Column::new("name", "NAME", |c: &Self| c.name.clone()).truncate(30),
+            Column::new("type", "TYPE", |c: &Self| c.credential_type.clone()),
+            Column::new("updated", "UPDATED", |c: &Self| {
+                format_timestamp_str(&c.updated_at)
+            })
+            .sort_by(|c: &Self| SortKey::Text(c.updated_at.clone())),
+            Column::new("created", "CREATED", |c: &Self| {
+                format_timestamp_str(&c.created_at)
+            })
+            .sort_by(|c: &Self| SortKey::Text(c.created_at.clone()))
+            .optional(),
         ]
     }
 }
@@ -1,5 +1,8 @@
-use crate::models::common::format_timestamp_str;
-use crate::output::Outputable;
+use crate::error::{N8nError, Result};
+use crate::models::common::{format_duration_ms, format_timestamp_str, yes_no};
+use crate::models::execution_data::ExecutionData;
+use crate::output::{Column, Outputable, SortKey};
+use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -16,6 +19,13 @@ pub enum ExecutionStatus {
     Unknown,
 }
 
+impl ExecutionStatus {
+    /// Whether the execution has stopped and won't change anymore
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Success | Self::Error | Self::Canceled)
+    }
+}
+
 impl std::fmt::Display for ExecutionStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -46,22 +56,46 @@ pub struct Execution {
     pub mode: String,
 }
 
-impl Outputable for Execution {
-    fn headers() -> Vec<&'static str> {
-        vec!["ID", "WORKFLOW", "STATUS", "MODE", "STARTED", "STOPPED"]
+impl Execution {
+    /// Run time in milliseconds (`None` while running)
+    pub fn duration_ms(&self) -> Option<i64> {
+        let started = DateTime::parse_from_rfc3339(&self.started_at).ok()?;
+        let stopped = DateTime::parse_from_rfc3339(self.stopped_at.as_deref()?).ok()?;
+        Some((stopped - started).num_milliseconds())
     }
+}
 
-    fn row(&self) -> Vec<String> {
+impl Outputable for Execution {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            self.id.clone(),
-            self.workflow_id.clone(),
-            self.status.to_string(),
-            self.mode.clone(),
-            format_timestamp_str(&self.started_at),
-            self.stopped_at
-                .as_ref()
-                .map(|s| format_timestamp_str(s))
-                .unwrap_or_else(|| "-".to_string()),
+            Column::new("id", "ID", |e: &Self| e.id.clone()).sort_by(|e: &Self| {
+                match e.id.parse::<f64>() {
+                    Ok(n) => SortKey::Number(n),
+                    Err(_) => SortKey::Text(e.id.clone()),
+                }
+            }),
+            Column::new("workflow", "WORKFLOW", |e: &Self| e.workflow_id.clone()),
+            Column::new("status", "STATUS", |e: &Self| e.status.to_string()),
+            Column::new("mode", "MODE", |e: &Self| e.mode.clone()),
+            Column::new("started", "STARTED", |e: &Self| {
+                format_timestamp_str(&e.started_at)
+            })
+            .sort_by(|e: &Self| SortKey::Text(e.started_at.clone())),
+            Column::new("stopped", "STOPPED", |e: &Self| {
+                e.stopped_at
+                    .as_ref()
+                    .map(|s| format_timestamp_str(s))
+                    .unwrap_or_else(|| "-".to_string())
+            })
+            .sort_by(|e: &Self| SortKey::text(e.stopped_at.as_deref())),
+            Column::new("duration", "DURATION", |e: &Self| {
+                e.duration_ms()
+                    .map(|ms| format_duration_ms(ms.max(0) as u64))
+                    .unwrap_or_else(|| "-".to_string())
+            })
+            .sort_by(|e: &Self| SortKey::Number(e.duration_ms().unwrap_or(-1) as f64))
+            .optional(),
+            Column::new("finished", "FINISHED", |e: &Self| yes_no(e.finished)).optional(),
         ]
     }
 }
@@ -83,6 +117,36 @@ pub struct ExecutionDetail {
     pub data: Option<Value>,
 }
 
+impl ExecutionDetail {
+    /// Parse the run data (requires the execution to be fetched with data)
+    pub fn execution_data(&self) -> Result<Option<ExecutionData>> {
+        match &self.data {
+            Some(data) if !data.is_null() => serde_json::from_value(data.clone())
+                .map(Some)
+                .map_err(N8nError::InvalidInput),
+            _ => Ok(None),
+        }
+    }
+
+    /// Whether the execution has finished (statuses unknown to the CLI count once stopped)
+    pub fn is_done(&self) -> bool {
+        self.status.is_terminal()
+            || (self.status == ExecutionStatus::Unknown && self.stopped_at.is_some())
+    }
+
+    /// The execution if it succeeded, else the error `executions run --wait` exits with
+    pub fn into_result(self) -> Result<Self> {
+        match self.status {
+            ExecutionStatus::Success => Ok(self),
+            ExecutionStatus::Canceled => Err(N8nError::ExecutionCanceled(self.id)),
+            status => Err(N8nError::ExecutionFailed {
+                id: self.id,
+                status: status.to_string(),
+            }),
+        }
+    }
+}
+
 /// Request body for executing a workflow
 #[derive(Debug, Clone, Serialize)]
 pub struct ExecuteRequest {
@@ -0,0 +1,273 @@
+use crate::models::common::format_duration_ms;
+use crate::output::{Column, Outputable, SortKey};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Execution payload returned with `includeData=true` (`ExecutionDetail.data`)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ExecutionData {
+    #[serde(rename = "resultData", default)]
+    pub result_data: ResultData,
+}
+
+/// Results of an execution
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ResultData {
+    /// Runs per node name (a node runs more than once inside loops)
+    #[serde(rename = "runData", default)]
+    pub run_data: HashMap<String, Vec<NodeRun>>,
+
+    #[serde(rename = "lastNodeExecuted", default, skip_serializing_if = "Option::is_none")]
+    pub last_node_executed: Option<String>,
+
+    /// Workflow-level error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<NodeError>,
+}
+
+/// A single run of a node
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NodeRun {
+    /// Start time in milliseconds since the epoch
+    #[serde(rename = "startTime", default)]
+    pub start_time: Option<i64>,
+
+    /// Duration in milliseconds
+    #[serde(rename = "executionTime", default)]
+    pub execution_time: Option<u64>,
+
+    #[serde(rename = "executionStatus", default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<String>,
+
+    /// Where the input items came from (one entry per input)
+    #[serde(default)]
+    pub source: Vec<Option<RunSource>>,
+
+    /// Output items per connection type and output index
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<HashMap<String, Vec<Option<Vec<Value>>>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<NodeError>,
+}
+
+/// Reference to the node output that fed a run
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RunSource {
+    #[serde(rename = "previousNode")]
+    pub previous_node: String,
+
+    #[serde(rename = "previousNodeOutput", default)]
+    pub previous_node_output: Option<usize>,
+
+    #[serde(rename = "previousNodeRun", default)]
+    pub previous_node_run: Option<usize>,
+}
+
+/// Error raised by a node
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NodeError {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Catch-all for node-specific fields (httpCode, stack, ...)
+    #[serde(flatten)]
+    pub extra: Value,
+}
+
+impl NodeRun {
+    /// Items emitted on the main outputs
+    pub fn output_items(&self) -> Vec<&Value> {
+        self.main_outputs()
+            .iter()
+            .flat_map(|output| output.iter().flatten())
+            .collect()
+    }
+
+    /// Number of items on a main output
+    pub fn output_count(&self, index: usize) -> usize {
+        self.main_outputs()
+            .get(index)
+            .and_then(|output| output.as_ref())
+            .map(Vec::len)
+            .unwrap_or(0)
+    }
+
+    fn main_outputs(&self) -> &[Option<Vec<Value>>] {
+        self.data
+            .as_ref()
+            .and_then(|d| d.get("main"))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Status of the run, derived from the error when n8n doesn't record one
+    pub fn status(&self) -> String {
+        match (&self.execution_status, &self.error) {
+            (Some(status), _) => status.clone(),
+            (None, Some(_)) => "error".to_string(),
+            (None, None) => "success".to_string(),
+        }
+    }
+}
+
+impl ExecutionData {
+    /// Per-node run summaries, ordered by start time
+    pub fn node_summaries(&self) -> Vec<NodeRunSummary> {
+        let runs = &self.result_data.run_data;
+        let mut summaries = Vec::new();
+
+        for (name, node_runs) in runs {
+            for (index, run) in node_runs.iter().enumerate() {
+                // Items in = items the source outputs emitted in the referenced run
+                let items_in = run
+                    .source
+                    .iter()
+                    .flatten()
+                    .map(|src| {
+                        runs.get(&src.previous_node)
+                            .and_then(|prev| prev.get(src.previous_node_run.unwrap_or(0)))
+                            .map(|prev| prev.output_count(src.previous_node_output.unwrap_or(0)))
+                            .unwrap_or(0)
+                    })
+                    .sum();
+
+                summaries.push(NodeRunSummary {
+                    node: name.clone(),
+                    run: index,
+                    status: run.status(),
+                    started_at: run
+                        .start_time
+                        .and_then(DateTime::<Utc>::from_timestamp_millis),
+                    duration_ms: run.execution_time,
+                    items_in,
+                    items_out: run.output_items().len(),
+                    error: run.error.as_ref().and_then(|e| e.message.clone()),
+                });
+            }
+        }
+
+        summaries.sort_by(|a, b| {
+            a.started_at
+                .cmp(&b.started_at)
+                .then_with(|| a.node.cmp(&b.node))
+                .then_with(|| a.run.cmp(&b.run))
+        });
+        summaries
+    }
+
+    /// Output items of a node across all of its runs
+    pub fn node_output(&self, node: &str) -> Option<Vec<Value>> {
+        self.result_data.run_data.get(node).map(|runs| {
+            runs.iter()
+                .flat_map(|run| run.output_items())
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+/// Per-node summary of an execution (one row per run)
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeRunSummary {
+    pub node: String,
+    pub run: usize,
+    pub status: String,
+    #[serde(rename = "startedAt")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: Option<u64>,
+    #[serde(rename = "itemsIn")]
+    pub items_in: usize,
+    #[serde(rename = "itemsOut")]
+    pub items_out: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Outputable for NodeRunSummary {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("node", "NODE", |s: &Self| s.node.clone()).truncate(30),
+            Column::new("run", "RUN", |s: &Self| s.run.to_string())
+                .sort_by(|s: &Self| SortKey::Number(s.run as f64)),
+            Column::new("status", "STATUS", |s: &Self| s.status.clone()),
+            Column::new("started", "STARTED", |s: &Self| {
+                s.started_at
+                    .map(|t| t.format("%H:%M:%S%.3f").to_string())
+                    .unwrap_or_else(|| "-".to_string())
+            })
+            .sort_by(|s: &Self| {
+                SortKey::Number(s.started_at.map_or(0, |t| t.timestamp_millis()) as f64)
+            }),
+            Column::new("duration", "DURATION", |s: &Self| {
+                s.duration_ms
+                    .map(format_duration_ms)
+                    .unwrap_or_else(|| "-".to_string())
+            })
+            .sort_by(|s: &Self| SortKey::Number(s.duration_ms.unwrap_or(0) as f64)),
+            Column::new("in", "IN", |s: &Self| s.items_in.to_string())
+                .sort_by(|s: &Self| SortKey::Number(s.items_in as f64)),
+            Column::new("out", "OUT", |s: &Self| s.items_out.to_string())
+                .sort_by(|s: &Self| SortKey::Number(s.items_out as f64)),
+            Column::new("error", "ERROR", |s: &Self| {
+                s.error.clone().unwrap_or_default()
+            })
+            .truncate(40),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_node_summaries_count_items() {
+        let data: ExecutionData = serde_json::from_value(json!({
+            "resultData": {
+                "runData": {
+                    "Trigger": [{
+                        "startTime": 1700000000000i64,
+                        "executionTime": 3,
+                        "source": [],
+                        "data": { "main": [[{ "json": { "a": 1 } }, { "json": { "a": 2 } }]] }
+                    }],
+                    "IF": [{
+                        "startTime": 1700000000010i64,
+                        "executionTime": 1,
+                        "source": [{ "previousNode": "Trigger" }],
+                        "data": { "main": [[{ "json": { "a": 1 } }], null] }
+                    }],
+                    "HTTP": [{
+                        "startTime": 1700000000020i64,
+                        "executionTime": 250,
+                        "source": [{ "previousNode": "IF", "previousNodeOutput": 0 }],
+                        "error": { "message": "404 Not Found", "httpCode": "404" }
+                    }]
+                },
+                "lastNodeExecuted": "HTTP"
+            }
+        }))
+        .unwrap();
+
+        let summaries = data.node_summaries();
+        let names: Vec<_> = summaries.iter().map(|s| s.node.as_str()).collect();
+        assert_eq!(names, vec!["Trigger", "IF", "HTTP"]);
+
+        assert_eq!(summaries[1].items_in, 2);
+        assert_eq!(summaries[1].items_out, 1);
+        assert_eq!(summaries[2].items_in, 1);
+        assert_eq!(summaries[2].status, "error");
+        assert_eq!(summaries[2].error.as_deref(), Some("404 Not Found"));
+
+        assert_eq!(data.node_output("Trigger").unwrap().len(), 2);
+        assert!(data.node_output("Missing").is_none());
+    }
+}
@@ -0,0 +1,459 @@
+//! Scanning of n8n expressions in node parameters
+//!
+//! A parameter string starting with `=` is an expression: text with embedded
+//! `{{ ... }}` blocks of JavaScript. Code node parameters (`jsCode`, ...) are
+//! JavaScript throughout. Both can reference other nodes by name, as
+//! `$('Name')`, `$node["Name"]` or `$items("Name")`.
+
+use serde_json::Value;
+use std::ops::Range;
+
+/// Parameters whose whole value is code rather than an expression
+const CODE_PARAMETERS: &[&str] = &["jsCode", "functionCode", "functionItemCode"];
+
+/// Call prefixes that take a node name as their first argument
+const REFERENCE_PREFIXES: &[&str] = &["$(", "$node[", "$items("];
+
+/// A reference to a node by name inside expression code
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeReference {
+    /// Referenced node name (unescaped)
+    pub name: String,
+    /// Byte range of the quoted name, without the quotes
+    pub span: Range<usize>,
+    /// Quote character used around the name
+    pub quote: char,
+}
+
+/// Result of scanning an expression string
+#[derive(Debug, Default)]
+struct Scan {
+    /// Byte ranges of the code inside each `{{ ... }}` block
+    blocks: Vec<Range<usize>>,
+    errors: Vec<String>,
+}
+
+/// Whether a parameter string is an n8n expression
+pub fn is_expression(value: &str) -> bool {
+    value.starts_with('=')
+}
+
+/// Syntax problems in a parameter string (empty for plain strings)
+pub fn check_expression(value: &str) -> Vec<String> {
+    if !is_expression(value) {
+        return vec![];
+    }
+    scan(value).errors
+}
+
+/// Node references in a parameter string
+///
+/// `key` is the parameter name, used to recognize code parameters.
+pub fn node_references(key: &str, value: &str) -> Vec<NodeReference> {
+    code_spans(key, value)
+        .into_iter()
+        .flat_map(|span| {
+            let offset = span.start;
+            references_in(&value[span])
+                .into_iter()
+                .map(move |mut r| {
+                    r.span = r.span.start + offset..r.span.end + offset;
+                    r
+                })
+        })
+        .collect()
+}
+
+/// Visit every string in a parameters tree with its path and key
+pub fn visit_strings(value: &Value, path: &str, f: &mut impl FnMut(&str, &str, &str)) {
+    visit(value, path, "", f);
+}
+
+fn visit(value: &Value, path: &str, key: &str, f: &mut impl FnMut(&str, &str, &str)) {
+    match value {
+        Value::String(s) => f(path, key, s),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                visit(item, &format!("{}[{}]", path, i), key, f);
+            }
+        }
+        Value::Object(map) => {
+            for (k, v) in map {
+                visit(v, &format!("{}.{}", path, k), k, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrite references to `old_name` in a parameters tree
+///
+/// Returns the path of every rewritten reference (one entry per reference).
+pub fn rename_references(
+    value: &mut Value,
+    path: &str,
+    old_name: &str,
+    new_name: &str,
+) -> Vec<String> {
+    let mut paths = Vec::new();
+    rename_in(value, path, "", old_name, new_name, &mut paths);
+    paths
+}
+
+fn rename_in(
+    value: &mut Value,
+    path: &str,
+    key: &str,
+    old_name: &str,
+    new_name: &str,
+    paths: &mut Vec<String>,
+) {
+    match value {
+        Value::String(s) => {
+            let refs: Vec<_> = node_references(key, s)
+                .into_iter()
+                .filter(|r| r.name == old_name)
+                .collect();
+
+            // Replace back to front so earlier spans stay valid
+            for r in refs.iter().rev() {
+                s.replace_range(r.span.clone(), &escape(new_name, r.quote));
+            }
+            paths.extend(refs.iter().map(|_| path.to_string()));
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                rename_in(item, &format!("{}[{}]", path, i), key, old_name, new_name, paths);
+            }
+        }
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                rename_in(v, &format!("{}.{}", path, k), k, old_name, new_name, paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Byte ranges of a parameter string that are evaluated as code
+fn code_spans(key: &str, value: &str) -> Vec<Range<usize>> {
+    if CODE_PARAMETERS.contains(&key) {
+        std::iter::once(0..value.len()).collect()
+    } else if is_expression(value) {
+        scan(value).blocks
+    } else {
+        vec![]
+    }
+}
+
+/// Split an expression into `{{ ... }}` blocks and report unbalanced braces
+///
+/// Braces, quotes and regex literals inside a block are tracked, so object
+/// literals and strings containing `}}` don't end the block early. When that
+/// tracking can't find the end (e.g. a quote inside a comment), the next `}}`
+/// closes the block, as in n8n. A `}}` outside a block is literal text when it
+/// closes braces of the text itself (e.g. the end of a JSON body).
+fn scan(s: &str) -> Scan {
+    let bytes = s.as_bytes();
+    let mut result = Scan::default();
+    // Braces opened by the literal text around the blocks
+    let mut text_depth = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"{{") {
+            let start = i + 2;
+            let (end, errors) = match block_end(bytes, start) {
+                Some(tracked) => tracked,
+                None => match s[start..].find("}}") {
+                    Some(offset) => (start + offset, vec![]),
+                    None => {
+                        // No later block can be closed either
+                        result
+                            .errors
+                            .push(format!("Unclosed '{{{{' at position {}", i));
+                        break;
+                    }
+                },
+            };
+            result.blocks.push(start..end);
+            result.errors.extend(errors);
+            i = end + 2;
+            continue;
+        }
+
+        match bytes[i] {
+            b'{' => text_depth += 1,
+            b'}' if text_depth > 0 => text_depth -= 1,
+            b'}' if bytes.get(i + 1) == Some(&b'}') => {
+                result
+                    .errors
+                    .push(format!("Unmatched '}}}}' at position {}", i));
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    result
+}
+
+/// Find the `}}` closing a block whose code starts at `start`
+///
+/// Returns its position and any unmatched `}` inside the block, or `None`
+/// when the block runs to the end of the string.
+fn block_end(bytes: &[u8], start: usize) -> Option<(usize, Vec<String>)> {
+    let mut errors = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut regex = false;
+    let mut class = false;
+    // Last significant byte, to tell a regex literal from a division
+    let mut prev: Option<u8> = None;
+    let mut j = start;
+
+    while j < bytes.len() {
+        let c = bytes[j];
+        if let Some(q) = quote {
+            if c == b'\\' {
+                j += 1;
+            } else if c == q {
+                quote = None;
+            }
+        } else if regex {
+            if c == b'\\' {
+                j += 1;
+            } else if class {
+                class = c != b']';
+            } else if c == b'[' {
+                class = true;
+            } else if c == b'/' {
+                regex = false;
+            }
+        } else if matches!(c, b'\'' | b'"' | b'`') {
+            quote = Some(c);
+        } else if c == b'/' && starts_regex(prev, bytes.get(j + 1)) {
+            regex = true;
+        } else if c == b'{' {
+            depth += 1;
+        } else if c == b'}' {
+            if depth > 0 {
+                depth -= 1;
+            } else if bytes.get(j + 1) == Some(&b'}') {
+                return Some((j, errors));
+            } else {
+                errors.push(format!("Unmatched '}}' at position {}", j));
+            }
+        }
+
+        if !c.is_ascii_whitespace() {
+            prev = Some(c);
+        }
+        j += 1;
+    }
+
+    None
+}
+
+/// Whether a `/` after `prev` starts a regex literal rather than a division
+fn starts_regex(prev: Option<u8>, next: Option<&u8>) -> bool {
+    // `//` and `/*` start comments
+    if matches!(next, Some(b'/' | b'*')) {
+        return false;
+    }
+    match prev {
+        None => true,
+        Some(p) => b"(,=:[!&|?{};+-*%<>~^".contains(&p),
+    }
+}
+
+/// Find node references in a piece of code
+fn references_in(code: &str) -> Vec<NodeReference> {
+    let bytes = code.as_bytes();
+    let mut refs = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let Some(prefix) = REFERENCE_PREFIXES
+            .iter()
+            .find(|p| bytes[i..].starts_with(p.as_bytes()))
+        else {
+            i += 1;
+            continue;
+        };
+
+        // `foo$(` is a different identifier
+        let standalone = i == 0 || !is_ident_byte(bytes[i - 1]);
+        i += prefix.len();
+        if !standalone {
+            continue;
+        }
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let Some(&q) = bytes.get(i) else { break };
+        if !matches!(q, b'\'' | b'"' | b'`') {
+            continue;
+        }
+
+        // Read the quoted name
+        let start = i + 1;
+        let mut j = start;
+        let mut name = String::new();
+        let mut closed = false;
+        while j < bytes.len() {
+            let c = bytes[j];
+            if c == b'\\' && j + 1 < bytes.len() {
+                j += 1;
+            } else if c == q {
+                closed = true;
+                break;
+            }
+            // Names may contain multi-byte characters
+            let len = utf8_len(bytes[j]);
+            name.push_str(&code[j..j + len]);
+            j += len;
+        }
+
+        if closed && !(q == b'`' && name.contains("${")) {
+            refs.push(NodeReference {
+                name,
+                span: start..j,
+                quote: q as char,
+            });
+        }
+        i = j + 1;
+    }
+
+    refs
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0xF0..=0xFF => 4,
+        0xE0..=0xEF => 3,
+        0xC0..=0xDF => 2,
+        _ => 1,
+    }
+}
+
+/// Escape a node name for use inside the given quotes
+fn escape(name: &str, quote: char) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c == '\\' || c == quote {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_expression() {
+        assert!(check_expression("={{ $json.a }} and {{ { b: 1 } }}").is_empty());
+        assert!(check_expression("={{ '}}' + $json.a }}").is_empty());
+        assert!(check_expression("plain {{ text").is_empty());
+
+        assert_eq!(
+            check_expression("={{ $json.a }"),
+            vec!["Unclosed '{{' at position 1"]
+        );
+        // JSON bodies end in literal `}}`
+        assert!(check_expression(r#"={"a": {"b": "{{ $json.x }}"}}"#).is_empty());
+
+        // Stray closing braces
+        assert_eq!(
+            check_expression("={{ $json.a } }}"),
+            vec!["Unmatched '}' at position 12"]
+        );
+        assert_eq!(
+            check_expression("={{ $json.a }} }}"),
+            vec!["Unmatched '}}' at position 15"]
+        );
+    }
+
+    #[test]
+    fn test_check_expression_quotes() {
+        // Quotes inside regex literals don't open strings
+        assert!(check_expression(r#"={{ $json.x.replace(/'/g, "") }}"#).is_empty());
+        assert!(check_expression(r"={{ $json.x.split(/[/']/) }}").is_empty());
+        assert!(check_expression("={{ $json.total / 2 }}'s half").is_empty());
+        // Untracked syntax falls back to the next `}}`
+        assert!(check_expression("={{ $json.a /* it's */ }} and {{ $json.b }}").is_empty());
+    }
+
+    #[test]
+    fn test_node_references() {
+        let refs = node_references(
+            "value",
+            r#"={{ $('Fetch Data').item.json.id }} {{ $node["It's \"here\""].json }}"#,
+        );
+        let names: Vec<_> = refs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Fetch Data", r#"It's "here""#]);
+
+        // Only code is scanned
+        assert!(node_references("value", "$('Not an expression')").is_empty());
+        assert_eq!(
+            node_references("jsCode", "const a = $('Code Ref').all();")[0].name,
+            "Code Ref"
+        );
+    }
+
+    #[test]
+    fn test_rename_references() {
+        let mut params = json!({
+            "url": "={{ $('Old').item.json.url }}",
+            "body": { "items": ["={{ $node[\"Old\"].json.x + $('Other').item }}"] },
+            "jsCode": "return $items('Old');",
+            "text": "$('Old') is not an expression"
+        });
+
+        let paths = rename_references(&mut params, "parameters", "Old", "New 'one'");
+        assert_eq!(
+            paths,
+            vec![
+                "parameters.body.items[0]",
+                "parameters.jsCode",
+                "parameters.url"
+            ]
+        );
+        assert_eq!(params["url"], r"={{ $('New \'one\'').item.json.url }}");
+        assert_eq!(
+            params["body"]["items"][0],
+            "={{ $node[\"New 'one'\"].json.x + $('Other').item }}"
+        );
+        assert_eq!(params["jsCode"], r"return $items('New \'one\'');");
+        assert_eq!(params["text"], "$('Old') is not an expression");
+    }
+
+    #[test]
+    fn test_rename_references_after_apostrophes() {
+        let mut params = json!({
+            "value": "={{ $('Old').item.json.x.replace(/'/g, '') }} {{ $('Old').item.json.y }}",
+            "note": "={{ $json.a // it's\n }} {{ $node[\"Old\"].json }}"
+        });
+
+        let paths = rename_references(&mut params, "parameters", "Old", "New");
+        assert_eq!(paths.len(), 3);
+        assert_eq!(
+            params["value"],
+            "={{ $('New').item.json.x.replace(/'/g, '') }} {{ $('New').item.json.y }}"
+        );
+        assert_eq!(
+            params["note"],
+            "={{ $json.a // it's\n }} {{ $node[\"New\"].json }}"
+        );
+    }
+}
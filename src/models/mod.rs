@@ -2,6 +2,8 @@ mod common;
 pub mod connection;
 mod credential;
 mod execution;
+mod execution_data;
+pub mod expression;
 pub mod node;
 mod tag;
 mod workflow;
@@ -10,6 +12,7 @@ pub use common::*;
 pub use connection::*;
 pub use credential::*;
 pub use execution::*;
+pub use execution_data::*;
 pub use node::*;
 pub use tag::*;
 pub use workflow::*;
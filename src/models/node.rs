@@ -1,5 +1,5 @@
-use crate::models::common::truncate;
-use crate::output::Outputable;
+use crate::models::common::yes_no;
+use crate::output::{Column, Outputable, SortKey};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
@@ -172,17 +172,24 @@ impl Node {
 }
 
 impl Outputable for Node {
-    fn headers() -> Vec<&'static str> {
-        vec!["ID", "NAME", "TYPE", "POSITION", "DISABLED"]
-    }
-
-    fn row(&self) -> Vec<String> {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            truncate(&self.id, 20),
-            truncate(&self.name, 30),
-            truncate(&self.node_type, 35),
-            format!("{},{}", self.position.x, self.position.y),
-            if self.disabled { "yes" } else { "no" }.to_string(),
+            Column::new("id", "ID", |n: &Self| n.id.clone()).truncate(20),
+            Column::new("name", "NAME", |n: &Self| n.name.clone()).truncate(30),
+            Column::new("type", "TYPE", |n: &Self| n.node_type.clone()).truncate(35),
+            Column::new("position", "POSITION", |n: &Self| {
+                format!("{},{}", n.position.x, n.position.y)
+            })
+            .sort_by(|n: &Self| SortKey::Number(n.position.x as f64)),
+            Column::new("disabled", "DISABLED", |n: &Self| yes_no(n.disabled)),
+            Column::new("version", "VERSION", |n: &Self| n.type_version.to_string())
+                .sort_by(|n: &Self| SortKey::Number(n.type_version))
+                .optional(),
+            Column::new("notes", "NOTES", |n: &Self| {
+                n.notes.clone().unwrap_or_default()
+            })
+            .truncate(40)
+            .optional(),
         ]
     }
 }
@@ -1,5 +1,5 @@
 use crate::models::common::format_timestamp_str;
-use crate::output::Outputable;
+use crate::output::{Column, Outputable, SortKey};
 use serde::{Deserialize, Serialize};
 
 /// Tag
@@ -14,22 +14,30 @@ pub struct Tag {
 }
 
 impl Outputable for Tag {
-    fn headers() -> Vec<&'static str> {
-        vec!["ID", "NAME", "UPDATED"]
-    }
-
-    fn row(&self) -> Vec<String> {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            self.id.clone(),
-            self.name.clone(),
-            self.updated_at
-                .as_ref()
-                .map(|s| format_timestamp_str(s))
-                .unwrap_or_else(|| "-".to_string()),
+            Column::new("id", "ID", |t: &Self| t.id.clone()),
+            Column::new("name", "NAME", |t: &Self| t.name.clone()),
+            Column::new("updated", "UPDATED", |t: &Self| {
+                timestamp_or_dash(&t.updated_at)
+            })
+            .sort_by(|t: &Self| SortKey::text(t.updated_at.as_deref())),
+            Column::new("created", "CREATED", |t: &Self| {
+                timestamp_or_dash(&t.created_at)
+            })
+            .sort_by(|t: &Self| SortKey::text(t.created_at.as_deref()))
+            .optional(),
         ]
     }
 }
 
+fn timestamp_or_dash(timestamp: &Option<String>) -> String {
+    timestamp
+        .as_ref()
+        .map(|s| format_timestamp_str(s))
+        .unwrap_or_else(|| "-".to_string())
+}
+
 /// Tag creation request
 #[derive(Debug, Clone, Serialize)]
 pub struct TagCreate {
@@ -1,8 +1,9 @@
 use crate::error::{N8nError, Result};
-use crate::models::common::{format_timestamp_str, truncate};
+use crate::models::common::{format_timestamp_str, yes_no};
 use crate::models::connection::{Connection, ConnectionsMap};
+use crate::models::expression;
 use crate::models::node::{Node, Position};
-use crate::output::Outputable;
+use crate::output::{Column, Outputable, SortKey};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -27,25 +28,29 @@ pub struct WorkflowTag {
     pub name: String,
 }
 
-impl Outputable for Workflow {
-    fn headers() -> Vec<&'static str> {
-        vec!["ID", "NAME", "ACTIVE", "TAGS", "UPDATED"]
-    }
-
-    fn row(&self) -> Vec<String> {
-        let tags = self
-            .tags
+impl Workflow {
+    /// Tag names, comma-separated
+    fn tag_names(&self) -> String {
+        self.tags
             .iter()
             .map(|t| t.name.as_str())
             .collect::<Vec<_>>()
-            .join(", ");
+            .join(", ")
+    }
+}
 
+impl Outputable for Workflow {
+    fn columns() -> Vec<Column<Self>> {
         vec![
-            self.id.clone(),
-            truncate(&self.name, 40),
-            if self.active { "yes" } else { "no" }.to_string(),
-            truncate(&tags, 20),
-            format_timestamp_str(&self.updated_at),
+            Column::new("id", "ID", |w: &Self| w.id.clone()),
+            Column::new("name", "NAME", |w: &Self| w.name.clone()).truncate(40),
+            Column::new("active", "ACTIVE", |w: &Self| yes_no(w.active)),
+            Column::new("tags", "TAGS", Self::tag_names).truncate(20),
+            Column::new("updated", "UPDATED", |w: &Self| format_timestamp_str(&w.updated_at))
+                .sort_by(|w: &Self| SortKey::Text(w.updated_at.clone())),
+            Column::new("created", "CREATED", |w: &Self| format_timestamp_str(&w.created_at))
+                .sort_by(|w: &Self| SortKey::Text(w.created_at.clone()))
+                .optional(),
         ]
     }
 }
@@ -80,6 +85,8 @@ pub struct WorkflowDetail {
     pub tags: Vec<WorkflowTag>,
     #[serde(rename = "versionId", default)]
     pub version_id: Option<String>,
+    #[serde(rename = "pinData", default, skip_serializing_if = "Option::is_none")]
+    pub pin_data: Option<Value>,
 }
 
 /// Common workflow settings
@@ -166,6 +173,10 @@ pub struct TypedWorkflow {
     pub tags: Vec<WorkflowTag>,
     #[serde(rename = "versionId", default, skip_serializing_if = "Option::is_none")]
     pub version_id: Option<String>,
+    /// Pinned test data, keyed by node name (kept in exports and sync files;
+    /// the public API rejects it in create and update requests)
+    #[serde(rename = "pinData", default, skip_serializing_if = "Option::is_none")]
+    pub pin_data: Option<serde_json::Map<String, Value>>,
 }
 
 impl TypedWorkflow {
@@ -180,6 +191,11 @@ impl TypedWorkflow {
         let settings: WorkflowSettings =
             serde_json::from_value(detail.settings).unwrap_or_default();
 
+        let pin_data = match detail.pin_data {
+            Some(Value::Object(map)) if !map.is_empty() => Some(map),
+            _ => None,
+        };
+
         Ok(Self {
             id: Some(detail.id),
             name: detail.name,
@@ -189,6 +205,7 @@ impl TypedWorkflow {
             settings,
             tags: detail.tags,
             version_id: detail.version_id,
+            pin_data,
         })
     }
 
@@ -314,4 +331,203 @@ impl TypedWorkflow {
             }
         }
     }
+
+    /// Rename a node in expression and code references of all node parameters
+    ///
+    /// Rewrites `$('Old')`, `$node["Old"]` and `$items("Old")`; returns the
+    /// number of references changed.
+    pub fn rename_node_in_expressions(&mut self, old_name: &str, new_name: &str) -> usize {
+        self.nodes
+            .iter_mut()
+            .map(|node| {
+                expression::rename_references(&mut node.parameters, "parameters", old_name, new_name)
+                    .len()
+            })
+            .sum()
+    }
+
+    /// Rename a node everywhere it is referenced
+    ///
+    /// Updates the node itself, connections, expression references in all
+    /// parameters and pinned data. Nothing is changed if the rename fails.
+    /// Pinned data only reaches exports and sync files: the API doesn't
+    /// accept it on update.
+    pub fn rename_node(&mut self, node_id: &str, new_name: &str) -> Result<RenameReport> {
+        let old_name = self
+            .get_node_name(node_id)
+            .ok_or_else(|| N8nError::NodeNotFound(node_id.to_string()))?;
+
+        if new_name.trim().is_empty() {
+            return Err(N8nError::InvalidNodeName(new_name.to_string()));
+        }
+        if new_name != old_name && self.nodes.iter().any(|n| n.name == new_name) {
+            return Err(N8nError::DuplicateNodeName(new_name.to_string()));
+        }
+
+        let mut report = RenameReport {
+            old_name: old_name.clone(),
+            new_name: new_name.to_string(),
+            locations: Vec::new(),
+        };
+        if new_name == old_name {
+            return Ok(report);
+        }
+
+        // Connections (reported before renaming, by the other end)
+        for conn in self.connections_flat() {
+            if conn.source_node == old_name || conn.target_node == old_name {
+                let rename = |n: &str| if n == old_name { new_name } else { n }.to_string();
+                report.locations.push(RenameLocation {
+                    kind: RenameKind::Connection,
+                    node: rename(&conn.source_node),
+                    location: format!(
+                        "{} -> {}",
+                        rename(&conn.source_node),
+                        rename(&conn.target_node)
+                    ),
+                });
+            }
+        }
+        self.rename_node_in_connections(&old_name, new_name);
+
+        if let Some(node) = self.nodes.iter_mut().find(|n| n.name == old_name) {
+            node.name = new_name.to_string();
+        }
+
+        // Expressions in every node, including the renamed one
+        for node in &mut self.nodes {
+            let paths =
+                expression::rename_references(&mut node.parameters, "parameters", &old_name, new_name);
+            for path in paths {
+                report.locations.push(RenameLocation {
+                    kind: RenameKind::Expression,
+                    node: node.name.clone(),
+                    location: path,
+                });
+            }
+        }
+
+        if let Some(pin_data) = &mut self.pin_data
+            && let Some(data) = pin_data.remove(&old_name)
+        {
+            pin_data.insert(new_name.to_string(), data);
+            report.locations.push(RenameLocation {
+                kind: RenameKind::PinData,
+                node: new_name.to_string(),
+                location: format!("pinData.{}", new_name),
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+/// What `TypedWorkflow::rename_node` changed
+#[derive(Debug, Clone, Serialize)]
+pub struct RenameReport {
+    #[serde(rename = "oldName")]
+    pub old_name: String,
+    #[serde(rename = "newName")]
+    pub new_name: String,
+    pub locations: Vec<RenameLocation>,
+}
+
+/// Kind of reference rewritten by a rename
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RenameKind {
+    Connection,
+    Expression,
+    PinData,
+}
+
+impl std::fmt::Display for RenameKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connection => write!(f, "connection"),
+            Self::Expression => write!(f, "expression"),
+            Self::PinData => write!(f, "pinData"),
+        }
+    }
+}
+
+/// A single rewritten reference
+#[derive(Debug, Clone, Serialize)]
+pub struct RenameLocation {
+    pub kind: RenameKind,
+    /// Node holding the reference
+    pub node: String,
+    /// Connection, parameter path or pinData key
+    pub location: String,
+}
+
+impl Outputable for RenameLocation {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("kind", "KIND", |l: &Self| l.kind.to_string()),
+            Column::new("node", "NODE", |l: &Self| l.node.clone()).truncate(30),
+            Column::new("location", "LOCATION", |l: &Self| l.location.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn workflow() -> TypedWorkflow {
+        let mut fetch = Node::new("1".into(), "Fetch".into(), "n8n-nodes-base.httpRequest".into());
+        fetch.parameters = json!({ "url": "https://example.com" });
+        let mut set = Node::new("2".into(), "Set".into(), "n8n-nodes-base.set".into());
+        set.parameters = json!({ "value": "={{ $('Fetch').item.json.id }}" });
+
+        let mut workflow = TypedWorkflow {
+            id: None,
+            name: "Test".to_string(),
+            active: false,
+            nodes: vec![fetch, set],
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: json!({ "Fetch": [{ "json": { "id": 1 } }] }).as_object().cloned(),
+        };
+        workflow.add_connection(Connection::new("Fetch".into(), "Set".into()));
+        workflow
+    }
+
+    #[test]
+    fn test_rename_node_updates_all_references() {
+        let mut workflow = workflow();
+        let report = workflow.rename_node("1", "Fetch Orders").unwrap();
+
+        let kinds: Vec<_> = report.locations.iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![RenameKind::Connection, RenameKind::Expression, RenameKind::PinData]
+        );
+        assert_eq!(report.locations[1].location, "parameters.value");
+
+        assert_eq!(workflow.nodes[0].name, "Fetch Orders");
+        assert_eq!(workflow.connections_flat()[0].source_node, "Fetch Orders");
+        assert_eq!(
+            workflow.nodes[1].parameters["value"],
+            "={{ $('Fetch Orders').item.json.id }}"
+        );
+        assert!(workflow.pin_data.unwrap().contains_key("Fetch Orders"));
+    }
+
+    #[test]
+    fn test_rename_node_rejects_duplicate_name() {
+        let mut workflow = workflow();
+        let before = serde_json::to_value(&workflow).unwrap();
+
+        assert!(matches!(
+            workflow.rename_node("Fetch", "Set"),
+            Err(N8nError::DuplicateNodeName(_))
+        ));
+        assert_eq!(serde_json::to_value(&workflow).unwrap(), before);
+    }
 }
@@ -0,0 +1,252 @@
+use crate::error::{N8nError, Result};
+use crate::models::truncate;
+use crate::output::{OutputFormat, OutputOptions, Outputable};
+use std::cmp::Ordering;
+
+/// A named column of list output
+pub struct Column<T> {
+    /// Name used with `--columns` and `--sort-by`
+    pub name: &'static str,
+    /// Header shown in tabular output
+    pub header: &'static str,
+    value: fn(&T) -> String,
+    sort_key: Option<fn(&T) -> SortKey>,
+    width: Option<usize>,
+    default: bool,
+}
+
+impl<T> Column<T> {
+    /// Column shown by default
+    pub fn new(name: &'static str, header: &'static str, value: fn(&T) -> String) -> Self {
+        Self {
+            name,
+            header,
+            value,
+            sort_key: None,
+            width: None,
+            default: true,
+        }
+    }
+
+    /// Only shown when selected with `--columns`
+    pub fn optional(mut self) -> Self {
+        self.default = false;
+        self
+    }
+
+    /// Truncate values longer than `width` (unless `--wide`)
+    pub fn truncate(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sort by a key other than the displayed text
+    pub fn sort_by(mut self, key: fn(&T) -> SortKey) -> Self {
+        self.sort_key = Some(key);
+        self
+    }
+
+    /// Displayed value, truncated unless `wide`
+    pub fn cell(&self, item: &T, wide: bool) -> String {
+        let value = (self.value)(item);
+        match self.width {
+            Some(width) if !wide => truncate(&value, width),
+            _ => value,
+        }
+    }
+
+    fn key(&self, item: &T) -> SortKey {
+        match self.sort_key {
+            Some(key) => key(item),
+            None => SortKey::Text((self.value)(item)),
+        }
+    }
+
+    /// Whether this column is shown without `--columns`
+    pub fn is_default(&self) -> bool {
+        self.default
+    }
+}
+
+/// Value a column sorts by
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+impl SortKey {
+    /// Text key for optional values (missing values sort first)
+    pub fn text(value: Option<&str>) -> Self {
+        Self::Text(value.unwrap_or_default().to_string())
+    }
+}
+
+/// Headers and cell values of the selected columns
+pub struct TableData {
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl TableData {
+    /// Lay out items with the columns selected in the options
+    ///
+    /// Only tables truncate long values; CSV, TSV and Markdown are meant to be
+    /// read by other programs and always get them in full.
+    pub fn new<T: Outputable>(items: &[&T], options: &OutputOptions) -> Result<Self> {
+        let wide = options.wide || options.format != OutputFormat::Table;
+        let all = T::columns();
+        let columns: Vec<&Column<T>> = if options.columns.is_empty() {
+            all.iter().filter(|c| c.default).collect()
+        } else {
+            options
+                .columns
+                .iter()
+                .map(|name| find_column(&all, name))
+                .collect::<Result<_>>()?
+        };
+
+        Ok(Self {
+            headers: columns.iter().map(|c| c.header).collect(),
+            rows: items
+                .iter()
+                .map(|item| columns.iter().map(|c| c.cell(item, wide)).collect())
+                .collect(),
+        })
+    }
+}
+
+/// Order items as requested by `--sort-by` and `--reverse`
+pub fn sort_items<'a, T: Outputable>(
+    items: &'a [T],
+    options: &OutputOptions,
+) -> Result<Vec<&'a T>> {
+    let mut sorted: Vec<&T> = items.iter().collect();
+
+    if let Some(name) = &options.sort_by {
+        let all = T::columns();
+        let column = find_column(&all, name)?;
+        let mut keyed: Vec<(SortKey, &T)> = sorted
+            .iter()
+            .map(|item| (column.key(item), *item))
+            .collect();
+        keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        sorted = keyed.into_iter().map(|(_, item)| item).collect();
+    }
+    if options.reverse {
+        sorted.reverse();
+    }
+    Ok(sorted)
+}
+
+pub(super) fn find_column<'a, T>(columns: &'a [Column<T>], name: &str) -> Result<&'a Column<T>> {
+    columns
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| N8nError::UnknownColumn {
+            name: name.to_string(),
+            available: columns
+                .iter()
+                .map(|c| c.name)
+                .collect::<Vec<_>>()
+                .join(", "),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    impl Outputable for Item {
+        fn columns() -> Vec<Column<Self>> {
+            vec![
+                Column::new("name", "NAME", |i: &Item| i.name.clone()).truncate(6),
+                Column::new("count", "COUNT", |i: &Item| i.count.to_string())
+                    .sort_by(|i| SortKey::Number(i.count as f64))
+                    .optional(),
+            ]
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                name: "beta".into(),
+                count: 10,
+            },
+            Item {
+                name: "alpha-long".into(),
+                count: 9,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_default_columns_and_wide() {
+        let items = items();
+        let refs: Vec<&Item> = items.iter().collect();
+
+        let table = TableData::new(&refs, &OutputOptions::default()).unwrap();
+        assert_eq!(table.headers, vec!["NAME"]);
+        assert_eq!(table.rows[1], vec!["alp..."]);
+
+        let options = OutputOptions {
+            columns: vec!["COUNT".into(), "name".into()],
+            wide: true,
+            ..Default::default()
+        };
+        let table = TableData::new(&refs, &options).unwrap();
+        assert_eq!(table.headers, vec!["COUNT", "NAME"]);
+        assert_eq!(table.rows[1], vec!["9", "alpha-long"]);
+
+        let options = OutputOptions {
+            format: OutputFormat::Csv,
+            ..Default::default()
+        };
+        let table = TableData::new(&refs, &options).unwrap();
+        assert_eq!(table.rows[1], vec!["alpha-long"]);
+    }
+
+    #[test]
+    fn test_sort_items() {
+        let items = items();
+        let names = |options: &OutputOptions| -> Vec<String> {
+            sort_items(&items, options)
+                .unwrap()
+                .iter()
+                .map(|i| i.name.clone())
+                .collect()
+        };
+
+        // Numeric keys don't sort as text ("10" < "9")
+        let by_count = OutputOptions {
+            sort_by: Some("count".into()),
+            ..Default::default()
+        };
+        assert_eq!(names(&by_count), vec!["alpha-long", "beta"]);
+
+        let reversed = OutputOptions {
+            sort_by: Some("name".into()),
+            reverse: true,
+            ..Default::default()
+        };
+        assert_eq!(names(&reversed), vec!["beta", "alpha-long"]);
+    }
+
+    #[test]
+    fn test_unknown_column() {
+        let options = OutputOptions {
+            sort_by: Some("size".into()),
+            ..Default::default()
+        };
+        let err = sort_items(&items(), &options).unwrap_err();
+        assert!(err.to_string().contains("Available columns: name, count"));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::Result;
+use crate::output::TableData;
+
+/// Print rows as CSV (RFC 4180 quoting)
+pub fn print_csv(data: &TableData, headers: bool) -> Result<()> {
+    print!("{}", render(data, headers, ",", csv_field));
+    Ok(())
+}
+
+/// Print rows as tab-separated values
+pub fn print_tsv(data: &TableData, headers: bool) -> Result<()> {
+    print!("{}", render(data, headers, "\t", tsv_field));
+    Ok(())
+}
+
+fn render(data: &TableData, headers: bool, separator: &str, field: fn(&str) -> String) -> String {
+    let mut out = String::new();
+    let mut line = |cells: &[&str]| {
+        let cells: Vec<String> = cells.iter().map(|c| field(c)).collect();
+        out.push_str(&cells.join(separator));
+        out.push_str("\r\n");
+    };
+
+    if headers {
+        line(&data.headers);
+    }
+    for row in &data.rows {
+        line(&row.iter().map(String::as_str).collect::<Vec<_>>());
+    }
+    out
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    let needs_quotes =
+        value.contains([',', '"', '\n', '\r']) || value.starts_with(' ') || value.ends_with(' ');
+    if needs_quotes {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escape tabs, line breaks and backslashes, which TSV can't quote
+fn tsv_field(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field(" padded"), "\" padded\"");
+    }
+
+    #[test]
+    fn test_tsv_field_escaping() {
+        assert_eq!(tsv_field("a\tb\nc\\d"), "a\\tb\\nc\\\\d");
+    }
+}
@@ -1,5 +1,8 @@
-use crate::error::Result;
+use crate::error::{N8nError, Result};
+use crate::output::columns::{find_column, sort_items, Column, TableData};
+use crate::output::{Query, Template};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Output format options
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Deserialize, Serialize)]
@@ -11,30 +14,183 @@ pub enum OutputFormat {
     #[serde(rename = "json-pretty")]
     #[clap(name = "json-pretty")]
     JsonPretty,
+    /// One compact JSON document per line
+    Ndjson,
+    Yaml,
+    Csv,
+    Tsv,
+    /// GitHub-flavored Markdown table
+    Markdown,
+    /// Custom template (selected with `--format`)
+    #[value(skip)]
+    #[serde(skip)]
+    Template,
+}
+
+/// Output settings shared by all commands
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    pub format: OutputFormat,
+    /// Leave out the header row of tabular formats
+    pub no_headers: bool,
+    /// Columns to show, by name (empty = the type's default columns)
+    pub columns: Vec<String>,
+    /// Column to sort list output by
+    pub sort_by: Option<String>,
+    /// Reverse the order of list output
+    pub reverse: bool,
+    /// Don't truncate long values
+    pub wide: bool,
+    /// Print only the part of the output selected by this query
+    pub query: Option<Query>,
+    /// Print query results that are strings without JSON quoting
+    pub raw: bool,
+    /// Template for `OutputFormat::Template`
+    pub template: Option<Template>,
+}
+
+impl From<OutputFormat> for OutputOptions {
+    fn from(format: OutputFormat) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
 }
 
 /// Trait for types that can be formatted for output
-pub trait Outputable: Serialize {
-    /// Column headers for table output
-    fn headers() -> Vec<&'static str>;
+pub trait Outputable: Serialize + Sized {
+    /// All columns, in display order (see [`Column::optional`])
+    fn columns() -> Vec<Column<Self>>;
 
-    /// Row values matching headers order
-    fn row(&self) -> Vec<String>;
+    /// Headers of the default columns
+    fn headers() -> Vec<&'static str> {
+        Self::columns()
+            .iter()
+            .filter(|c| c.is_default())
+            .map(|c| c.header)
+            .collect()
+    }
+
+    /// Values of the default columns, matching headers order
+    fn row(&self) -> Vec<String> {
+        Self::columns()
+            .iter()
+            .filter(|c| c.is_default())
+            .map(|c| c.cell(self, false))
+            .collect()
+    }
+
+    /// Value of the column called `name`, not truncated
+    fn cell(&self, name: &str) -> Result<String> {
+        let columns = Self::columns();
+        Ok(find_column(&columns, name)?.cell(self, true))
+    }
 }
 
 /// Format and print a list of items
-pub fn print_output<T: Outputable>(items: &[T], format: OutputFormat) -> Result<()> {
-    match format {
-        OutputFormat::Table => super::table::print_table(items),
-        OutputFormat::Json => super::json::print_json(items, false),
-        OutputFormat::JsonPretty => super::json::print_json(items, true),
+///
+/// Items are sorted as requested in the options. Structured formats (JSON,
+/// YAML) always contain every field; column selection applies to the others.
+pub fn print_output<T: Outputable>(items: &[T], options: &OutputOptions) -> Result<()> {
+    let items = sort_items(items, options)?;
+    if let Some(query) = &options.query {
+        return print_query(query, &items, options);
+    }
+    let headers = !options.no_headers;
+    match options.format {
+        OutputFormat::Json => super::json::print_json(&items, false),
+        OutputFormat::JsonPretty => super::json::print_json(&items, true),
+        OutputFormat::Ndjson => super::json::print_ndjson(&items),
+        OutputFormat::Yaml => super::yaml::print_yaml(&items),
+        OutputFormat::Table => {
+            super::table::print_table(&TableData::new(&items, options)?, headers)
+        }
+        OutputFormat::Csv => {
+            super::delimited::print_csv(&TableData::new(&items, options)?, headers)
+        }
+        OutputFormat::Tsv => {
+            super::delimited::print_tsv(&TableData::new(&items, options)?, headers)
+        }
+        OutputFormat::Markdown => {
+            super::markdown::print_markdown(&TableData::new(&items, options)?, headers)
+        }
+        OutputFormat::Template => {
+            for item in items {
+                print_template(item, options)?;
+            }
+            Ok(())
+        }
     }
 }
 
 /// Format and print a single item
-pub fn print_single<T: Serialize>(item: &T, format: OutputFormat) -> Result<()> {
-    match format {
-        OutputFormat::Table | OutputFormat::Json => super::json::print_json_single(item, false),
+///
+/// Single items have no row layout, so tabular formats fall back to JSON.
+pub fn print_single<T: Serialize>(item: &T, options: &OutputOptions) -> Result<()> {
+    if let Some(query) = &options.query {
+        return print_query(query, item, options);
+    }
+    match options.format {
+        OutputFormat::Table
+        | OutputFormat::Json
+        | OutputFormat::Ndjson
+        | OutputFormat::Csv
+        | OutputFormat::Tsv
+        | OutputFormat::Markdown => super::json::print_json_single(item, false),
         OutputFormat::JsonPretty => super::json::print_json_single(item, true),
+        OutputFormat::Yaml => super::yaml::print_yaml_single(item),
+        OutputFormat::Template => print_template(item, options),
+    }
+}
+
+/// Render one item through the `--format` template, followed by a newline
+fn print_template<T: Serialize>(item: &T, options: &OutputOptions) -> Result<()> {
+    let Some(template) = &options.template else {
+        return super::json::print_json_single(item, false);
+    };
+    let value = serde_json::to_value(item).map_err(N8nError::Serialize)?;
+    println!("{}", template.render(&value));
+    Ok(())
+}
+
+/// Print the result of a query against the serialized output
+///
+/// Queries that can match several values yield an array; with `--raw` (or
+/// `-o ndjson`) its elements are printed one per line. Tabular formats have
+/// nothing to lay out, so they print JSON.
+fn print_query<T: Serialize + ?Sized>(
+    query: &Query,
+    output: &T,
+    options: &OutputOptions,
+) -> Result<()> {
+    let value = serde_json::to_value(output).map_err(N8nError::Serialize)?;
+    let result = query.evaluate(&value);
+    let lines = match (&result, query.is_definite()) {
+        (Value::Array(items), false) => items.iter().collect(),
+        _ => vec![&result],
+    };
+
+    if options.raw {
+        for value in lines {
+            match value {
+                Value::String(s) => println!("{}", s),
+                other => super::json::print_json_single(other, false)?,
+            }
+        }
+        return Ok(());
+    }
+
+    match options.format {
+        OutputFormat::Yaml => super::yaml::print_yaml_single(&result),
+        OutputFormat::Ndjson => super::json::print_ndjson(&lines),
+        OutputFormat::Table | OutputFormat::JsonPretty => {
+            super::json::print_json_single(&result, true)
+        }
+        OutputFormat::Json
+        | OutputFormat::Csv
+        | OutputFormat::Tsv
+        | OutputFormat::Markdown
+        | OutputFormat::Template => super::json::print_json_single(&result, false),
     }
 }
@@ -26,3 +26,11 @@ pub fn print_json_single<T: Serialize>(item: &T, pretty: bool) -> Result<()> {
     println!("{}", output);
     Ok(())
 }
+
+/// Print items as newline-delimited JSON (one item per line)
+pub fn print_ndjson<T: Serialize>(items: &[T]) -> Result<()> {
+    for item in items {
+        print_json_single(item, false)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::error::Result;
+use crate::output::TableData;
+
+/// Print rows as a GitHub-flavored Markdown table
+///
+/// Without headers only the rows are printed, for appending to an existing table.
+pub fn print_markdown(data: &TableData, headers: bool) -> Result<()> {
+    print!("{}", render(data, headers));
+    Ok(())
+}
+
+fn render(data: &TableData, headers: bool) -> String {
+    let mut out = String::new();
+
+    if headers {
+        out.push_str(&row(data.headers.iter().map(|h| cell(h))));
+        out.push_str(&row(data.headers.iter().map(|_| "---".to_string())));
+    }
+    for cells in &data.rows {
+        out.push_str(&row(cells.iter().map(|c| cell(c))));
+    }
+    out
+}
+
+fn row(cells: impl Iterator<Item = String>) -> String {
+    format!("| {} |\n", cells.collect::<Vec<_>>().join(" | "))
+}
+
+/// Escape pipes and keep multi-line values on one table line
+fn cell(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_escaping() {
+        assert_eq!(cell("a|b"), "a\\|b");
+        assert_eq!(cell("one\ntwo"), "one<br>two");
+    }
+}
@@ -1,5 +1,15 @@
+mod columns;
+mod delimited;
 mod format;
 mod json;
+mod markdown;
+mod query;
 mod table;
+mod template;
+mod yaml;
 
-pub use format::{OutputFormat, Outputable, print_output, print_single};
+pub use columns::{Column, SortKey, TableData};
+pub use query::Query;
+pub use template::Template;
+pub use format::{OutputFormat, OutputOptions, Outputable, print_output, print_single};
+pub use yaml::to_yaml;
@@ -0,0 +1,587 @@
+//! JSONPath subset for `--query`
+//!
+//! Supported: `$` (optional), `.name`, `['name']`, `[0]`, `[-1]`, `[1:3]`,
+//! `[0,2]`, `.*` / `[*]`, `..name` (recursive descent) and filters like
+//! `[?(@.type == 'n8n-nodes-base.code' && @.disabled != true)]`. The jq
+//! spelling `.nodes[].name` is accepted as well.
+
+use crate::error::{N8nError, Result};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// A parsed query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    /// `..` - apply to the value and all its descendants
+    recursive: bool,
+    selector: Selector,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>),
+    Union(Vec<Selector>),
+    Filter(Filter),
+}
+
+/// `||` of `&&` groups of conditions
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Filter {
+    any: Vec<Vec<Condition>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition {
+    /// Path relative to `@`
+    path: Vec<Selector>,
+    /// `None` tests for existence
+    comparison: Option<(Op, Value)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Query {
+    /// Parse a query expression
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut parser = Parser {
+            chars: source.trim().chars().collect(),
+            pos: 0,
+        };
+        let segments = parser.query().map_err(|message| N8nError::InvalidQuery {
+            query: source.to_string(),
+            message,
+        })?;
+        Ok(Self { segments })
+    }
+
+    /// Whether the query selects at most one value (no wildcards, slices or filters)
+    pub fn is_definite(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|s| !s.recursive && matches!(s.selector, Selector::Name(_) | Selector::Index(_)))
+    }
+
+    /// All values matched by the query, in document order
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![root];
+        for segment in &self.segments {
+            let mut next = Vec::new();
+            for value in current {
+                if segment.recursive {
+                    for v in descendants(value) {
+                        segment.selector.apply(v, &mut next);
+                    }
+                } else {
+                    segment.selector.apply(value, &mut next);
+                }
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Result of the query: the matched value for definite queries (null if
+    /// missing), otherwise an array of all matches
+    pub fn evaluate(&self, root: &Value) -> Value {
+        let matches = self.select(root);
+        if self.is_definite() {
+            matches.into_iter().next().cloned().unwrap_or(Value::Null)
+        } else {
+            Value::Array(matches.into_iter().cloned().collect())
+        }
+    }
+}
+
+impl Selector {
+    fn apply<'a>(&self, value: &'a Value, out: &mut Vec<&'a Value>) {
+        match self {
+            Self::Name(name) => out.extend(value.get(name)),
+            Self::Index(index) => {
+                if let Value::Array(items) = value {
+                    out.extend(resolve_index(*index, items.len()).and_then(|i| items.get(i)));
+                }
+            }
+            Self::Wildcard => out.extend(children(value)),
+            Self::Slice(start, end) => {
+                if let Value::Array(items) = value {
+                    let len = items.len();
+                    let start = start.map_or(0, |s| clamp_index(s, len));
+                    let end = end.map_or(len, |e| clamp_index(e, len));
+                    if start < end {
+                        out.extend(&items[start..end]);
+                    }
+                }
+            }
+            Self::Union(selectors) => {
+                for selector in selectors {
+                    selector.apply(value, out);
+                }
+            }
+            Self::Filter(filter) => out.extend(children(value).filter(|v| filter.matches(v))),
+        }
+    }
+}
+
+impl Filter {
+    fn matches(&self, value: &Value) -> bool {
+        self.any
+            .iter()
+            .any(|all| all.iter().all(|condition| condition.matches(value)))
+    }
+}
+
+impl Condition {
+    fn matches(&self, value: &Value) -> bool {
+        let mut current = vec![value];
+        for selector in &self.path {
+            let mut next = Vec::new();
+            for v in current {
+                selector.apply(v, &mut next);
+            }
+            current = next;
+        }
+
+        let Some(found) = current.first() else {
+            // A missing field only satisfies `!=`
+            return matches!(self.comparison, Some((Op::Ne, _)));
+        };
+        let Some((op, expected)) = &self.comparison else {
+            return true;
+        };
+
+        let ordering = compare(found, expected);
+        match op {
+            Op::Eq => ordering == Some(Ordering::Equal),
+            Op::Ne => ordering != Some(Ordering::Equal),
+            Op::Lt => ordering == Some(Ordering::Less),
+            Op::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            Op::Gt => ordering == Some(Ordering::Greater),
+            Op::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        }
+    }
+}
+
+/// Compare numbers numerically and strings lexically; other values only for equality
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64()?.partial_cmp(&y.as_f64()?),
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        _ if a == b => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn children(value: &Value) -> Box<dyn Iterator<Item = &Value> + '_> {
+    match value {
+        Value::Array(items) => Box::new(items.iter()),
+        Value::Object(map) => Box::new(map.values()),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+/// The value and all values nested in it, depth-first
+fn descendants(value: &Value) -> Vec<&Value> {
+    let mut out = vec![value];
+    let mut i = 0;
+    while i < out.len() {
+        let nested: Vec<&Value> = children(out[i]).collect();
+        out.splice(i + 1..i + 1, nested);
+        i += 1;
+    }
+    out
+}
+
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    }
+}
+
+fn clamp_index(index: i64, len: usize) -> usize {
+    resolve_index(index, len).unwrap_or(0).min(len)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+type ParseResult<T> = std::result::Result<T, String>;
+
+impl Parser {
+    fn query(&mut self) -> ParseResult<Vec<Segment>> {
+        self.eat('$');
+        let mut segments = Vec::new();
+
+        while let Some(c) = self.peek() {
+            let segment = match c {
+                '.' if self.peek_at(1) == Some('.') => {
+                    self.pos += 2;
+                    let selector = if self.peek() == Some('[') {
+                        self.bracket()?
+                    } else {
+                        self.dot_member()?
+                    };
+                    Segment {
+                        recursive: true,
+                        selector,
+                    }
+                }
+                '.' => {
+                    self.pos += 1;
+                    // jq style `.[0]` and a lone `.` for the whole value
+                    match self.peek() {
+                        None => break,
+                        Some('[') => continue,
+                        _ => Segment {
+                            recursive: false,
+                            selector: self.dot_member()?,
+                        },
+                    }
+                }
+                '[' => Segment {
+                    recursive: false,
+                    selector: self.bracket()?,
+                },
+                c => return Err(format!("unexpected '{}' at position {}", c, self.pos)),
+            };
+            segments.push(segment);
+        }
+        Ok(segments)
+    }
+
+    /// `name` or `*` after a dot
+    fn dot_member(&mut self) -> ParseResult<Selector> {
+        if self.eat('*') {
+            return Ok(Selector::Wildcard);
+        }
+        let name = self.identifier();
+        if name.is_empty() {
+            return Err(format!("expected a field name at position {}", self.pos));
+        }
+        Ok(Selector::Name(name))
+    }
+
+    /// `[...]` selector
+    fn bracket(&mut self) -> ParseResult<Selector> {
+        self.expect('[')?;
+        self.skip_ws();
+
+        let selector = if self.eat('*') || self.peek() == Some(']') {
+            Selector::Wildcard
+        } else if self.eat('?') {
+            self.skip_ws();
+            let parenthesized = self.eat('(');
+            let filter = self.filter()?;
+            if parenthesized {
+                self.skip_ws();
+                self.expect(')')?;
+            }
+            Selector::Filter(filter)
+        } else {
+            let mut selectors = vec![self.bracket_item()?];
+            self.skip_ws();
+            while self.eat(',') {
+                self.skip_ws();
+                selectors.push(self.bracket_item()?);
+                self.skip_ws();
+            }
+            if selectors.len() == 1 {
+                selectors.remove(0)
+            } else {
+                Selector::Union(selectors)
+            }
+        };
+
+        self.skip_ws();
+        self.expect(']')?;
+        Ok(selector)
+    }
+
+    /// Quoted name, index or slice inside brackets
+    fn bracket_item(&mut self) -> ParseResult<Selector> {
+        match self.peek() {
+            Some('\'' | '"') => Ok(Selector::Name(self.string()?)),
+            _ => {
+                let start = self.optional_integer()?;
+                self.skip_ws();
+                if self.eat(':') {
+                    self.skip_ws();
+                    let end = self.optional_integer()?;
+                    Ok(Selector::Slice(start, end))
+                } else {
+                    start
+                        .map(Selector::Index)
+                        .ok_or_else(|| format!("expected a name or index at position {}", self.pos))
+                }
+            }
+        }
+    }
+
+    fn filter(&mut self) -> ParseResult<Filter> {
+        let mut any = vec![vec![self.condition()?]];
+        loop {
+            self.skip_ws();
+            if self.eat_str("&&") {
+                any.last_mut().unwrap().push(self.condition()?);
+            } else if self.eat_str("||") {
+                any.push(vec![self.condition()?]);
+            } else {
+                return Ok(Filter { any });
+            }
+        }
+    }
+
+    fn condition(&mut self) -> ParseResult<Condition> {
+        self.skip_ws();
+        self.expect('@')?;
+
+        let mut path = Vec::new();
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.pos += 1;
+                    path.push(self.dot_member()?);
+                }
+                Some('[') => path.push(self.bracket()?),
+                _ => break,
+            }
+        }
+
+        self.skip_ws();
+        let op = if self.eat_str("==") {
+            Op::Eq
+        } else if self.eat_str("!=") {
+            Op::Ne
+        } else if self.eat_str("<=") {
+            Op::Le
+        } else if self.eat_str(">=") {
+            Op::Ge
+        } else if self.eat('<') {
+            Op::Lt
+        } else if self.eat('>') {
+            Op::Gt
+        } else {
+            return Ok(Condition {
+                path,
+                comparison: None,
+            });
+        };
+
+        self.skip_ws();
+        let value = self.literal()?;
+        Ok(Condition {
+            path,
+            comparison: Some((op, value)),
+        })
+    }
+
+    fn literal(&mut self) -> ParseResult<Value> {
+        if matches!(self.peek(), Some('\'' | '"')) {
+            return Ok(Value::String(self.string()?));
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.'))
+        {
+            self.pos += 1;
+        }
+        let word: String = self.chars[start..self.pos].iter().collect();
+        match word.as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            "null" => Ok(Value::Null),
+            _ => serde_json::from_str::<serde_json::Number>(&word)
+                .map(Value::Number)
+                .map_err(|_| format!("expected a value at position {}", start)),
+        }
+    }
+
+    fn string(&mut self) -> ParseResult<String> {
+        let quote = self.peek().unwrap_or('\'');
+        let start = self.pos;
+        self.pos += 1;
+        let mut out = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == quote {
+                return Ok(out);
+            }
+            if c == '\\' {
+                match self.peek() {
+                    Some(escaped) => {
+                        out.push(escaped);
+                        self.pos += 1;
+                    }
+                    None => break,
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        Err(format!("unterminated string at position {}", start))
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '$'))
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn optional_integer(&mut self) -> ParseResult<Option<i64>> {
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        if digits.is_empty() {
+            return Ok(None);
+        }
+        digits
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("invalid index '{}' at position {}", digits, start))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let len = s.chars().count();
+        if self.chars.len() >= self.pos + len
+            && self.chars[self.pos..self.pos + len]
+                .iter()
+                .copied()
+                .eq(s.chars())
+        {
+            self.pos += len;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> ParseResult<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(found) => Err(format!(
+                    "expected '{}' at position {}, found '{}'",
+                    c, self.pos, found
+                )),
+                None => Err(format!("expected '{}' at end of query", c)),
+            }
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn workflow() -> Value {
+        json!({
+            "name": "Orders",
+            "nodes": [
+                { "name": "Trigger", "type": "n8n-nodes-base.webhook", "position": [0, 0] },
+                { "name": "Fetch", "type": "n8n-nodes-base.httpRequest", "position": [200, 0],
+                  "parameters": { "url": "https://example.com" } },
+                { "name": "Code", "type": "n8n-nodes-base.code", "disabled": true, "position": [400, 0] }
+            ]
+        })
+    }
+
+    fn eval(query: &str) -> Value {
+        Query::parse(query).unwrap().evaluate(&workflow())
+    }
+
+    #[test]
+    fn test_definite_paths() {
+        assert_eq!(eval("$.name"), json!("Orders"));
+        assert_eq!(eval("$['nodes'][1].name"), json!("Fetch"));
+        assert_eq!(eval(".nodes[-1].position[0]"), json!(400));
+        assert_eq!(eval("$.missing"), Value::Null);
+        assert_eq!(eval("."), workflow());
+    }
+
+    #[test]
+    fn test_wildcards_slices_and_recursion() {
+        assert_eq!(eval("$.nodes[*].name"), json!(["Trigger", "Fetch", "Code"]));
+        assert_eq!(eval(".nodes[].name"), json!(["Trigger", "Fetch", "Code"]));
+        assert_eq!(eval("$.nodes[0:2].name"), json!(["Trigger", "Fetch"]));
+        assert_eq!(eval("$.nodes[0,2].name"), json!(["Trigger", "Code"]));
+        assert_eq!(eval("$..url"), json!(["https://example.com"]));
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(
+            eval("$.nodes[?(@.type == 'n8n-nodes-base.code')].name"),
+            json!(["Code"])
+        );
+        assert_eq!(eval("$.nodes[?(@.disabled)].name"), json!(["Code"]));
+        assert_eq!(
+            eval("$.nodes[?(@.disabled != true && @.position[0] >= 100)].name"),
+            json!(["Fetch"])
+        );
+        assert_eq!(
+            eval("$.nodes[?(@.name == 'Trigger' || @.name == \"Code\")].name"),
+            json!(["Trigger", "Code"])
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Query::parse("$.nodes[").is_err());
+        assert!(Query::parse("$.nodes[?(@.a == )]").is_err());
+        assert!(Query::parse("nodes").is_err());
+        assert!(Query::parse("$['unterminated]").is_err());
+    }
+}
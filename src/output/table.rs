@@ -1,10 +1,10 @@
 use crate::error::Result;
-use crate::output::Outputable;
+use crate::output::TableData;
 use comfy_table::{presets::UTF8_FULL, ContentArrangement, Table};
 
-/// Print items as a formatted table
-pub fn print_table<T: Outputable>(items: &[T]) -> Result<()> {
-    if items.is_empty() {
+/// Print rows as a formatted table
+pub fn print_table(data: &TableData, headers: bool) -> Result<()> {
+    if data.rows.is_empty() {
         println!("No results found.");
         return Ok(());
     }
@@ -12,11 +12,13 @@ pub fn print_table<T: Outputable>(items: &[T]) -> Result<()> {
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
-        .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_header(T::headers());
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    if headers {
+        table.set_header(&data.headers);
+    }
 
-    for item in items {
-        table.add_row(item.row());
+    for row in &data.rows {
+        table.add_row(row);
     }
 
     println!("{table}");
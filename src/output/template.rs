@@ -0,0 +1,617 @@
+//! Go-template style output (`--format`)
+//!
+//! Templates are text with `{{ ... }}` actions, rendered against the JSON form
+//! of each item:
+//!
+//! - `{{.name}}`, `{{.settings.timezone}}`, `{{.}}` - field access; on an
+//!   array, a field is taken from every element (`{{.tags.name}}`)
+//! - `{{$.name}}` - field of the item itself, inside `range`
+//! - `{{if COND}} ... {{else if COND}} ... {{else}} ... {{end}}`
+//! - `{{range .nodes}} ... {{else}} ... {{end}}` - `.` is each element
+//! - functions: `eq`, `ne`, `not`, `and`, `or`, `join`, `len`, `json`,
+//!   `upper`, `lower`, `default`, e.g. `{{join .tags.name ", "}}`
+//!
+//! `\t`, `\n` and `\\` in the text are unescaped, so shell-quoted templates
+//! like `'{{.id}}\t{{.name}}'` produce tabs.
+
+use crate::error::{N8nError, Result};
+use serde_json::Value;
+
+/// A parsed output template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Output(Expr),
+    If {
+        condition: Expr,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+    Range {
+        list: Expr,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    /// Field path from `.` (`from_root` for `$`)
+    Field {
+        from_root: bool,
+        path: Vec<String>,
+    },
+    Literal(Value),
+    Call(Function, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Eq,
+    Ne,
+    Not,
+    And,
+    Or,
+    Join,
+    Len,
+    Json,
+    Upper,
+    Lower,
+    Default,
+}
+
+impl Function {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "not" => Self::Not,
+            "and" => Self::And,
+            "or" => Self::Or,
+            "join" => Self::Join,
+            "len" => Self::Len,
+            "json" => Self::Json,
+            "upper" => Self::Upper,
+            "lower" => Self::Lower,
+            "default" => Self::Default,
+            _ => return None,
+        })
+    }
+
+    /// Whether `n` arguments are accepted
+    fn accepts(self, n: usize) -> bool {
+        match self {
+            Self::Not | Self::Len | Self::Json | Self::Upper | Self::Lower => n == 1,
+            Self::Eq | Self::Ne | Self::Join | Self::Default => n == 2,
+            Self::And | Self::Or => n >= 2,
+        }
+    }
+}
+
+/// Block an action opens or continues
+enum Action {
+    Expr(Expr),
+    If(Expr),
+    ElseIf(Expr),
+    Range(Expr),
+    Else,
+    End,
+}
+
+impl Template {
+    /// Parse a template
+    pub fn parse(source: &str) -> Result<Self> {
+        let invalid = |message: String| N8nError::InvalidTemplate {
+            template: source.to_string(),
+            message,
+        };
+
+        let mut parts = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find("{{") {
+            parts.push(Part::Text(unescape(&rest[..start])));
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| invalid("unclosed '{{'".to_string()))?;
+            parts.push(Part::Action(
+                parse_action(after[..end].trim()).map_err(invalid)?,
+            ));
+            rest = &after[end + 2..];
+        }
+        parts.push(Part::Text(unescape(rest)));
+
+        let mut parts = parts.into_iter();
+        let (nodes, terminator) = parse_block(&mut parts).map_err(invalid)?;
+        match terminator {
+            None => Ok(Self { nodes }),
+            Some(_) => Err(invalid(
+                "'else' or 'end' without 'if' or 'range'".to_string(),
+            )),
+        }
+    }
+
+    /// Render the template for one value
+    pub fn render(&self, value: &Value) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, value, value, &mut out);
+        out
+    }
+}
+
+enum Part {
+    Text(String),
+    Action(Action),
+}
+
+/// How a block ended
+enum Terminator {
+    Else,
+    ElseIf(Expr),
+    End,
+}
+
+/// Parse nodes up to the `else`/`end` that closes the current block
+fn parse_block(
+    parts: &mut impl Iterator<Item = Part>,
+) -> std::result::Result<(Vec<Node>, Option<Terminator>), String> {
+    let mut nodes = Vec::new();
+
+    while let Some(part) = parts.next() {
+        let action = match part {
+            Part::Text(text) => {
+                if !text.is_empty() {
+                    nodes.push(Node::Text(text));
+                }
+                continue;
+            }
+            Part::Action(action) => action,
+        };
+
+        match action {
+            Action::Expr(expr) => nodes.push(Node::Output(expr)),
+            Action::If(condition) => nodes.push(parse_if(condition, parts)?),
+            Action::Range(list) => {
+                let (body, terminator) = parse_block(parts)?;
+                let otherwise = match terminator {
+                    Some(Terminator::End) => vec![],
+                    Some(Terminator::Else) => expect_end(parts)?,
+                    Some(Terminator::ElseIf(_)) => {
+                        return Err("'else if' is not allowed in 'range'".to_string());
+                    }
+                    None => return Err("'range' without 'end'".to_string()),
+                };
+                nodes.push(Node::Range {
+                    list,
+                    body,
+                    otherwise,
+                });
+            }
+            Action::Else => return Ok((nodes, Some(Terminator::Else))),
+            Action::ElseIf(condition) => return Ok((nodes, Some(Terminator::ElseIf(condition)))),
+            Action::End => return Ok((nodes, Some(Terminator::End))),
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn parse_if(
+    condition: Expr,
+    parts: &mut impl Iterator<Item = Part>,
+) -> std::result::Result<Node, String> {
+    let (then, terminator) = parse_block(parts)?;
+    let otherwise = match terminator {
+        Some(Terminator::End) => vec![],
+        Some(Terminator::Else) => expect_end(parts)?,
+        // `else if` nests an `if` that shares our `end`
+        Some(Terminator::ElseIf(next)) => vec![parse_if(next, parts)?],
+        None => return Err("'if' without 'end'".to_string()),
+    };
+    Ok(Node::If {
+        condition,
+        then,
+        otherwise,
+    })
+}
+
+/// Parse an `else` branch, which must be closed by `end`
+fn expect_end(parts: &mut impl Iterator<Item = Part>) -> std::result::Result<Vec<Node>, String> {
+    match parse_block(parts)? {
+        (nodes, Some(Terminator::End)) => Ok(nodes),
+        (_, Some(_)) => Err("unexpected 'else' after 'else'".to_string()),
+        (_, None) => Err("'else' without 'end'".to_string()),
+    }
+}
+
+fn parse_action(source: &str) -> std::result::Result<Action, String> {
+    let tokens = tokenize(source)?;
+    let (keyword, args) = match tokens.split_first() {
+        Some((Token::Word(w), args)) => (w.as_str(), args),
+        Some(_) => ("", tokens.as_slice()),
+        None => return Err("empty action '{{}}'".to_string()),
+    };
+
+    Ok(match keyword {
+        "if" => Action::If(parse_expr(args)?),
+        "range" => Action::Range(parse_expr(args)?),
+        "end" if args.is_empty() => Action::End,
+        "else" if args.is_empty() => Action::Else,
+        "else" => match args.split_first() {
+            Some((Token::Word(w), rest)) if w == "if" => Action::ElseIf(parse_expr(rest)?),
+            _ => return Err("expected 'if' after 'else'".to_string()),
+        },
+        _ => Action::Expr(parse_expr(&tokens)?),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Field(bool, Vec<String>),
+    Literal(Value),
+    Open,
+    Close,
+}
+
+fn tokenize(source: &str) -> std::result::Result<Vec<Token>, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            '"' | '`' => {
+                let quote = c;
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err("unterminated string".to_string()),
+                        Some(&c) if c == quote => break,
+                        Some('\\') if quote == '"' => {
+                            i += 1;
+                            match chars.get(i) {
+                                Some('n') => value.push('\n'),
+                                Some('t') => value.push('\t'),
+                                Some(&c) => value.push(c),
+                                None => return Err("unterminated string".to_string()),
+                            }
+                        }
+                        Some(&c) => value.push(c),
+                    }
+                    i += 1;
+                }
+                i += 1;
+                tokens.push(Token::Literal(Value::String(value)));
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && !chars[i].is_whitespace() && !"()\"`".contains(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(word_token(&word)?);
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn word_token(word: &str) -> std::result::Result<Token, String> {
+    let field = |from_root: bool, path: &str| {
+        let path: Vec<String> = path
+            .split('.')
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect();
+        Token::Field(from_root, path)
+    };
+
+    if word == "$" {
+        return Ok(Token::Field(true, vec![]));
+    }
+    if let Some(path) = word.strip_prefix("$.") {
+        return Ok(field(true, path));
+    }
+    if word.starts_with('.') {
+        return Ok(field(false, word));
+    }
+    match word {
+        "true" => return Ok(Token::Literal(Value::Bool(true))),
+        "false" => return Ok(Token::Literal(Value::Bool(false))),
+        "nil" | "null" => return Ok(Token::Literal(Value::Null)),
+        _ => {}
+    }
+    if let Ok(number) = serde_json::from_str::<serde_json::Number>(word) {
+        return Ok(Token::Literal(Value::Number(number)));
+    }
+    if word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Ok(Token::Word(word.to_string()));
+    }
+    Err(format!("unexpected '{}'", word))
+}
+
+/// Parse a whole action: an operand or a function call
+fn parse_expr(tokens: &[Token]) -> std::result::Result<Expr, String> {
+    let mut pos = 0;
+    let expr = parse_command(tokens, &mut pos)?;
+    if pos < tokens.len() {
+        return Err("unexpected arguments (use a function, e.g. 'eq .a .b')".to_string());
+    }
+    Ok(expr)
+}
+
+fn parse_command(tokens: &[Token], pos: &mut usize) -> std::result::Result<Expr, String> {
+    match tokens.get(*pos) {
+        Some(Token::Word(name)) => {
+            let function =
+                Function::parse(name).ok_or_else(|| format!("unknown function '{}'", name))?;
+            *pos += 1;
+            let mut args = Vec::new();
+            while *pos < tokens.len() && tokens[*pos] != Token::Close {
+                args.push(parse_operand(tokens, pos)?);
+            }
+            if !function.accepts(args.len()) {
+                return Err(format!("wrong number of arguments for '{}'", name));
+            }
+            Ok(Expr::Call(function, args))
+        }
+        _ => parse_operand(tokens, pos),
+    }
+}
+
+fn parse_operand(tokens: &[Token], pos: &mut usize) -> std::result::Result<Expr, String> {
+    let token = tokens.get(*pos).ok_or("missing value")?;
+    *pos += 1;
+    match token {
+        Token::Field(from_root, path) => Ok(Expr::Field {
+            from_root: *from_root,
+            path: path.clone(),
+        }),
+        Token::Literal(value) => Ok(Expr::Literal(value.clone())),
+        Token::Open => {
+            let expr = parse_command(tokens, pos)?;
+            if tokens.get(*pos) != Some(&Token::Close) {
+                return Err("missing ')'".to_string());
+            }
+            *pos += 1;
+            Ok(expr)
+        }
+        Token::Close => Err("unexpected ')'".to_string()),
+        Token::Word(name) => Err(format!(
+            "function '{}' must be in parentheses when used as an argument",
+            name
+        )),
+    }
+}
+
+fn render_nodes(nodes: &[Node], dot: &Value, root: &Value, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Output(expr) => out.push_str(&display(&evaluate(expr, dot, root))),
+            Node::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let branch = if truthy(&evaluate(condition, dot, root)) {
+                    then
+                } else {
+                    otherwise
+                };
+                render_nodes(branch, dot, root, out);
+            }
+            Node::Range {
+                list,
+                body,
+                otherwise,
+            } => {
+                let items: Vec<Value> = match evaluate(list, dot, root) {
+                    Value::Array(items) => items,
+                    Value::Object(map) => map.into_iter().map(|(_, v)| v).collect(),
+                    _ => vec![],
+                };
+                if items.is_empty() {
+                    render_nodes(otherwise, dot, root, out);
+                }
+                for item in &items {
+                    render_nodes(body, item, root, out);
+                }
+            }
+        }
+    }
+}
+
+fn evaluate(expr: &Expr, dot: &Value, root: &Value) -> Value {
+    match expr {
+        Expr::Field { from_root, path } => {
+            let start = if *from_root { root } else { dot };
+            path.iter()
+                .fold(start.clone(), |value, key| field(&value, key))
+        }
+        Expr::Literal(value) => value.clone(),
+        Expr::Call(function, args) => {
+            let args: Vec<Value> = args.iter().map(|a| evaluate(a, dot, root)).collect();
+            call(*function, args)
+        }
+    }
+}
+
+/// Field of an object, or of every element of an array
+fn field(value: &Value, key: &str) -> Value {
+    match value {
+        Value::Object(map) => map.get(key).cloned().unwrap_or(Value::Null),
+        Value::Array(items) => Value::Array(items.iter().map(|v| field(v, key)).collect()),
+        _ => Value::Null,
+    }
+}
+
+fn call(function: Function, mut args: Vec<Value>) -> Value {
+    match function {
+        Function::Eq => Value::Bool(args[0] == args[1]),
+        Function::Ne => Value::Bool(args[0] != args[1]),
+        Function::Not => Value::Bool(!truthy(&args[0])),
+        Function::And => Value::Bool(args.iter().all(truthy)),
+        Function::Or => Value::Bool(args.iter().any(truthy)),
+        Function::Join => {
+            let separator = display(&args[1]);
+            match &args[0] {
+                Value::Array(items) => Value::String(
+                    items
+                        .iter()
+                        .map(display)
+                        .collect::<Vec<_>>()
+                        .join(&separator),
+                ),
+                other => Value::String(display(other)),
+            }
+        }
+        Function::Len => {
+            let len = match &args[0] {
+                Value::Array(items) => items.len(),
+                Value::Object(map) => map.len(),
+                Value::String(s) => s.chars().count(),
+                _ => 0,
+            };
+            Value::from(len)
+        }
+        Function::Json => Value::String(args[0].to_string()),
+        Function::Upper => Value::String(display(&args[0]).to_uppercase()),
+        Function::Lower => Value::String(display(&args[0]).to_lowercase()),
+        Function::Default => {
+            let value = args.pop().unwrap_or(Value::Null);
+            if truthy(&value) {
+                value
+            } else {
+                args.pop().unwrap_or(Value::Null)
+            }
+        }
+    }
+}
+
+/// Empty values, zero and false are false
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+/// Text for a value: strings as-is, null as nothing, the rest as JSON
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(template: &str, value: &Value) -> String {
+        Template::parse(template).unwrap().render(value)
+    }
+
+    fn workflow() -> Value {
+        json!({
+            "id": "wf1",
+            "name": "Orders",
+            "active": false,
+            "tags": [{ "name": "prod" }, { "name": "billing" }],
+            "nodes": [{ "name": "Trigger" }, { "name": "Fetch" }]
+        })
+    }
+
+    #[test]
+    fn test_fields_and_escapes() {
+        assert_eq!(render(r"{{.id}}\t{{ .name }}", &workflow()), "wf1\tOrders");
+        assert_eq!(render("{{.missing}}|{{.active}}", &workflow()), "|false");
+        assert_eq!(render("{{len .nodes}}", &workflow()), "2");
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let template = r#"{{if .active}}on{{else if eq .name "Orders"}}orders{{else}}off{{end}}"#;
+        assert_eq!(render(template, &workflow()), "orders");
+        assert_eq!(render("{{if not .active}}off{{end}}", &workflow()), "off");
+        assert_eq!(render(r#"{{default "-" .missing}}"#, &workflow()), "-");
+    }
+
+    #[test]
+    fn test_joins_and_range() {
+        assert_eq!(
+            render(r#"{{join .tags.name ", "}}"#, &workflow()),
+            "prod, billing"
+        );
+        assert_eq!(
+            render(r"{{range .nodes}}{{$.id}}/{{.name}} {{end}}", &workflow()),
+            "wf1/Trigger wf1/Fetch "
+        );
+        assert_eq!(
+            render("{{range .missing}}x{{else}}none{{end}}", &workflow()),
+            "none"
+        );
+        assert_eq!(
+            render(r#"{{upper (join .tags.name "+")}}"#, &workflow()),
+            "PROD+BILLING"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Template::parse("{{.id").is_err());
+        assert!(Template::parse("{{if .active}}yes").is_err());
+        assert!(Template::parse("{{end}}").is_err());
+        assert!(Template::parse("{{bogus .id}}").is_err());
+        assert!(Template::parse("{{eq .id}}").is_err());
+        assert!(Template::parse("{{.id .name}}").is_err());
+    }
+}
@@ -0,0 +1,21 @@
+use crate::error::{N8nError, Result};
+use serde::Serialize;
+
+/// Serialize a value as a YAML document
+///
+/// Multi-line strings (Code node bodies, notes) become block scalars.
+pub fn to_yaml<T: Serialize + ?Sized>(item: &T) -> Result<String> {
+    serde_norway::to_string(item).map_err(N8nError::YamlSerialize)
+}
+
+/// Print items as a YAML sequence
+pub fn print_yaml<T: Serialize>(items: &[T]) -> Result<()> {
+    print!("{}", to_yaml(items)?);
+    Ok(())
+}
+
+/// Print a single item as a YAML document
+pub fn print_yaml_single<T: Serialize>(item: &T) -> Result<()> {
+    print!("{}", to_yaml(item)?);
+    Ok(())
+}
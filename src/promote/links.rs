@@ -0,0 +1,110 @@
+use crate::config::config_file_path;
+use crate::error::{N8nError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Name of the file next to `config.toml` that stores promotion links
+pub const LINKS_FILE: &str = "promotions.json";
+
+/// Remembers which target workflow each promoted workflow became
+///
+/// Links are keyed by the source and target base URLs, so a promoted
+/// workflow is still found after it's renamed on either side.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PromotionLinks {
+    /// "source URL -> target URL" -> source workflow ID -> target workflow ID
+    #[serde(default)]
+    pub links: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl PromotionLinks {
+    /// Location of the links file
+    pub fn path() -> Option<PathBuf> {
+        config_file_path().and_then(|p| p.parent().map(|dir| dir.join(LINKS_FILE)))
+    }
+
+    /// Load stored links (empty if none were saved yet)
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path().filter(|p| p.exists()) else {
+            return Ok(Self::default());
+        };
+
+        let content = fs::read_to_string(&path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        serde_json::from_str(&content).map_err(|e| N8nError::InvalidFile {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+
+    /// Write the links file, creating the config directory if needed
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        let write_error = |e| N8nError::FileWrite {
+            path: path.display().to_string(),
+            source: e,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(N8nError::Serialize)?;
+        fs::write(&path, format!("{}\n", content)).map_err(write_error)
+    }
+
+    /// Target workflow a source workflow was last promoted to
+    pub fn target_for(&self, source_url: &str, target_url: &str, source_id: &str) -> Option<&str> {
+        self.links
+            .get(&link_key(source_url, target_url))
+            .and_then(|ids| ids.get(source_id))
+            .map(String::as_str)
+    }
+
+    /// Remember the target workflow of a promotion
+    pub fn record(&mut self, source_url: &str, target_url: &str, source_id: &str, target_id: &str) {
+        self.links
+            .entry(link_key(source_url, target_url))
+            .or_default()
+            .insert(source_id.to_string(), target_id.to_string());
+    }
+}
+
+fn link_key(source_url: &str, target_url: &str) -> String {
+    format!("{} -> {}", source_url, target_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_are_per_instance_pair() {
+        let mut links = PromotionLinks::default();
+        links.record("http://dev", "http://prod", "a", "x");
+        links.record("http://dev", "http://staging", "a", "y");
+        links.record("http://dev", "http://prod", "a", "z");
+
+        assert_eq!(
+            links.target_for("http://dev", "http://prod", "a"),
+            Some("z")
+        );
+        assert_eq!(
+            links.target_for("http://dev", "http://staging", "a"),
+            Some("y")
+        );
+        assert_eq!(links.target_for("http://prod", "http://dev", "z"), None);
+
+        let json = serde_json::to_string(&links).unwrap();
+        let parsed: PromotionLinks = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.target_for("http://dev", "http://prod", "a"),
+            Some("z")
+        );
+    }
+}
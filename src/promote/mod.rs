@@ -0,0 +1,5 @@
+mod links;
+mod plan;
+
+pub use links::{PromotionLinks, LINKS_FILE};
+pub use plan::{apply_promotion, plan_promotion, Promotion, TargetMatch};
@@ -0,0 +1,353 @@
+use crate::client::endpoints::credentials::CredentialListParams;
+use crate::client::endpoints::workflows::WorkflowListParams;
+use crate::client::N8nClient;
+use crate::credentials::{
+    CredentialMap, CredentialResolution, CredentialResolver, ResolutionStatus,
+};
+use crate::diff::WorkflowDiff;
+use crate::error::{N8nError, Result};
+use crate::models::{TypedWorkflow, WorkflowDetail, WorkflowTag};
+use crate::promote::PromotionLinks;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// How the target workflow of a promotion was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetMatch {
+    /// Given with `--target-id`
+    Id,
+    /// Stored by an earlier promotion
+    Link,
+    /// Only workflow on the target with the same name
+    Name,
+}
+
+impl std::fmt::Display for TargetMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "--target-id"),
+            Self::Link => write!(f, "earlier promotion"),
+            Self::Name => write!(f, "name"),
+        }
+    }
+}
+
+/// A workflow prepared for promotion to another instance
+#[derive(Debug)]
+pub struct Promotion {
+    pub source_id: String,
+    /// Source workflow with credential references resolved for the target
+    pub workflow: TypedWorkflow,
+    /// Current version on the target (absent if it will be created)
+    pub target: Option<TypedWorkflow>,
+    pub matched_by: Option<TargetMatch>,
+    /// Differences from the target's current version
+    pub diff: Option<WorkflowDiff>,
+    pub credentials: Vec<CredentialResolution>,
+}
+
+impl Promotion {
+    /// ID of the existing target workflow
+    pub fn target_id(&self) -> Option<&str> {
+        self.target.as_ref().and_then(|t| t.id.as_deref())
+    }
+
+    /// Credential references with no match on the target
+    pub fn unresolved(&self) -> Vec<&CredentialResolution> {
+        self.credentials
+            .iter()
+            .filter(|c| c.status == ResolutionStatus::Unresolved)
+            .collect()
+    }
+
+    /// Check if promoting would change the target
+    pub fn has_changes(&self) -> bool {
+        let Some(target) = &self.target else {
+            return true;
+        };
+        serde_json::to_value(target.to_definition()).ok()
+            != serde_json::to_value(self.workflow.to_definition()).ok()
+            || target.active != self.workflow.active
+            || tag_names(&target.tags) != tag_names(&self.workflow.tags)
+    }
+
+    /// Print what the promotion would do
+    pub fn print_summary(&self, full: bool) {
+        match (&self.target, self.target_id()) {
+            (Some(target), Some(id)) => {
+                println!(
+                    "~ update {} ({}, matched by {})",
+                    id,
+                    target.name,
+                    self.matched_by.map(|m| m.to_string()).unwrap_or_default()
+                );
+                if !self.has_changes() {
+                    println!("Everything up to date.");
+                    return;
+                }
+
+                let (old_tags, new_tags) =
+                    (tag_names(&target.tags), tag_names(&self.workflow.tags));
+                if old_tags != new_tags {
+                    println!("  Tags: [{}] -> [{}]", join(&old_tags), join(&new_tags));
+                }
+                if let Some(diff) = self.diff.as_ref().filter(|d| !d.is_empty()) {
+                    if full {
+                        diff.print_full();
+                    } else {
+                        diff.print_summary();
+                    }
+                }
+            }
+            _ => println!(
+                "+ create {} (active: {}, tags: [{}])",
+                self.workflow.name,
+                self.workflow.active,
+                join(&tag_names(&self.workflow.tags))
+            ),
+        }
+
+        let changed: Vec<_> = self
+            .credentials
+            .iter()
+            .filter(|c| c.status != ResolutionStatus::Kept)
+            .collect();
+        if !changed.is_empty() {
+            println!("\nCredentials:");
+            for c in changed {
+                let name = c.name.as_deref().unwrap_or("-");
+                match c.status {
+                    ResolutionStatus::Unresolved => println!(
+                        "  ! {} '{}' on node '{}' not found on the target",
+                        c.credential_type,
+                        c.to.as_deref().unwrap_or(name),
+                        c.node
+                    ),
+                    _ => println!(
+                        "  ~ {} '{}': {} -> {}",
+                        c.credential_type,
+                        name,
+                        c.from.as_deref().unwrap_or("-"),
+                        c.to.as_deref().unwrap_or("-")
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Prepare promoting a workflow from `source` to `target`
+///
+/// The target workflow is `target_id` if given, else the one linked by an
+/// earlier promotion, else the only target workflow with the same name.
+/// Credential references go through `map` before being matched by type and
+/// name; source IDs never decide a match on the target.
+pub async fn plan_promotion(
+    source: &N8nClient,
+    target: &N8nClient,
+    id: &str,
+    target_id: Option<&str>,
+    links: &PromotionLinks,
+    map: CredentialMap,
+) -> Result<Promotion> {
+    let mut workflow = TypedWorkflow::from_detail(source.get_workflow(id).await?)?;
+
+    let available = target
+        .list_all_credentials(CredentialListParams {
+            limit: Some(100),
+            ..Default::default()
+        })
+        .await?;
+    let credentials = CredentialResolver::new(available)
+        .with_map(map)
+        .names_only()
+        .resolve(&mut workflow);
+
+    let linked = links.target_for(source.base_url(), target.base_url(), id);
+    let found = find_target(target, &workflow.name, target_id, linked).await?;
+    let (target_workflow, matched_by) = match found {
+        Some((workflow, matched_by)) => (Some(workflow), Some(matched_by)),
+        None => (None, None),
+    };
+
+    Ok(Promotion {
+        source_id: id.to_string(),
+        diff: target_workflow
+            .as_ref()
+            .map(|t| WorkflowDiff::compare(t, &workflow)),
+        workflow,
+        target: target_workflow,
+        matched_by,
+        credentials,
+    })
+}
+
+async fn find_target(
+    client: &N8nClient,
+    name: &str,
+    target_id: Option<&str>,
+    linked: Option<&str>,
+) -> Result<Option<(TypedWorkflow, TargetMatch)>> {
+    if let Some(id) = target_id {
+        let detail = client.get_workflow(id).await?;
+        return Ok(Some((TypedWorkflow::from_detail(detail)?, TargetMatch::Id)));
+    }
+
+    if let Some(id) = linked {
+        match client.get_workflow(id).await {
+            Ok(detail) => {
+                return Ok(Some((
+                    TypedWorkflow::from_detail(detail)?,
+                    TargetMatch::Link,
+                )))
+            }
+            // Deleted on the target since: fall back to the name
+            Err(N8nError::Api(e)) if e.code == 404 => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let params = WorkflowListParams {
+        limit: Some(250),
+        name: Some(name.to_string()),
+        ..Default::default()
+    };
+    let same_name: Vec<_> = client
+        .list_all_workflows(params)
+        .await?
+        .into_iter()
+        .filter(|w| w.name == name)
+        .collect();
+
+    match same_name.as_slice() {
+        [] => Ok(None),
+        [only] => {
+            let detail = client.get_workflow(&only.id).await?;
+            Ok(Some((
+                TypedWorkflow::from_detail(detail)?,
+                TargetMatch::Name,
+            )))
+        }
+        _ => Err(N8nError::AmbiguousWorkflowName {
+            name: name.to_string(),
+            ids: same_name
+                .iter()
+                .map(|w| w.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }),
+    }
+}
+
+/// Create or update the target workflow, then match its tags and active state
+pub async fn apply_promotion(client: &N8nClient, promotion: &Promotion) -> Result<WorkflowDetail> {
+    let definition = promotion.workflow.to_definition();
+    let saved = match (&promotion.target, promotion.target_id()) {
+        // Refuse to overwrite changes made on the target after planning
+        (Some(target), Some(id)) => {
+            client
+                .update_workflow_if_version(id, &definition, target.version_id.as_deref())
+                .await?
+        }
+        _ => client.create_workflow(&definition).await?,
+    };
+
+    sync_tags(client, &saved, &promotion.workflow.tags).await?;
+
+    if saved.active != promotion.workflow.active {
+        if promotion.workflow.active {
+            client.activate_workflow(&saved.id).await?;
+        } else {
+            client.deactivate_workflow(&saved.id).await?;
+        }
+    }
+
+    client.get_workflow(&saved.id).await
+}
+
+/// Give a workflow the tags with these names, creating missing tags
+async fn sync_tags(
+    client: &N8nClient,
+    workflow: &WorkflowDetail,
+    tags: &[WorkflowTag],
+) -> Result<()> {
+    if tag_names(&workflow.tags) == tag_names(tags) {
+        return Ok(());
+    }
+
+    let existing = client.list_tags().await?;
+    let mut ids = Vec::new();
+    for tag in tags {
+        let id = match existing.iter().find(|t| t.name == tag.name) {
+            Some(t) => t.id.clone(),
+            None => client.create_tag(&tag.name).await?.id,
+        };
+        ids.push(id);
+    }
+    client.assign_tags(&workflow.id, ids).await
+}
+
+fn tag_names(tags: &[WorkflowTag]) -> BTreeSet<&str> {
+    tags.iter().map(|t| t.name.as_str()).collect()
+}
+
+fn join(names: &BTreeSet<&str>) -> String {
+    names.iter().copied().collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Node;
+    use std::collections::HashMap;
+
+    fn workflow(active: bool, tags: &[&str]) -> TypedWorkflow {
+        TypedWorkflow {
+            id: Some("t1".to_string()),
+            name: "Orders".to_string(),
+            active,
+            nodes: vec![Node::new("1".into(), "Start".into(), "type".into())],
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: tags
+                .iter()
+                .map(|t| WorkflowTag {
+                    id: format!("id-{}", t),
+                    name: t.to_string(),
+                })
+                .collect(),
+            version_id: None,
+            pin_data: None,
+        }
+    }
+
+    fn promotion(source: TypedWorkflow, target: Option<TypedWorkflow>) -> Promotion {
+        Promotion {
+            source_id: "s1".to_string(),
+            diff: target.as_ref().map(|t| WorkflowDiff::compare(t, &source)),
+            workflow: source,
+            target,
+            matched_by: Some(TargetMatch::Name),
+            credentials: vec![],
+        }
+    }
+
+    #[test]
+    fn test_has_changes() {
+        assert!(promotion(workflow(false, &[]), None).has_changes());
+        assert!(!promotion(workflow(true, &["a"]), Some(workflow(true, &["a"]))).has_changes());
+
+        // Tags are compared by name (IDs differ between instances)
+        let mut target = workflow(true, &["a"]);
+        target.tags[0].id = "other".to_string();
+        assert!(!promotion(workflow(true, &["a"]), Some(target)).has_changes());
+
+        assert!(promotion(workflow(true, &["a"]), Some(workflow(false, &["a"]))).has_changes());
+        assert!(promotion(workflow(true, &["a", "b"]), Some(workflow(true, &["a"]))).has_changes());
+
+        let mut source = workflow(true, &[]);
+        source.nodes[0].credentials = Some(serde_json::json!({ "slackApi": { "id": "2" } }));
+        assert!(promotion(source, Some(workflow(true, &[]))).has_changes());
+    }
+}
@@ -0,0 +1,550 @@
+use crate::client::endpoints::workflows::WorkflowListParams;
+use crate::client::N8nClient;
+use crate::diff::WorkflowDiff;
+use crate::error::{N8nError, Result};
+use crate::models::TypedWorkflow;
+use crate::output::{Column, Outputable};
+use crate::sync::manifest::{file_name_for, SyncManifest, MANIFEST_FILE};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Render a workflow as normalized, stably sorted JSON suitable for version control
+///
+/// Server-assigned fields (`id`, `versionId`) are stripped, nodes are sorted by name
+/// and object keys are emitted in sorted order.
+pub fn normalize_workflow(workflow: &TypedWorkflow) -> Result<String> {
+    let mut workflow = workflow.clone();
+    workflow.id = None;
+    workflow.version_id = None;
+    workflow.nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    workflow.tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // serde_json::Value keeps object keys sorted, which makes the output stable
+    let value = serde_json::to_value(&workflow).map_err(N8nError::Serialize)?;
+    let content = serde_json::to_string_pretty(&value).map_err(N8nError::Serialize)?;
+    Ok(format!("{}\n", content))
+}
+
+/// Result of pulling workflows into a directory
+#[derive(Debug, Default)]
+pub struct PullReport {
+    pub written: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Mirror workflows from the server into `dir`, one file per workflow
+///
+/// When `prune` is set, tracked files whose workflow no longer exists on the
+/// server are deleted. Workflows outside the tag filter are kept.
+pub async fn pull(
+    client: &N8nClient,
+    dir: &Path,
+    tags: Option<String>,
+    prune: bool,
+) -> Result<PullReport> {
+    fs::create_dir_all(dir).map_err(|e| N8nError::FileWrite {
+        path: dir.display().to_string(),
+        source: e,
+    })?;
+
+    let mut manifest = SyncManifest::load(dir)?;
+    let mut report = PullReport::default();
+    // Untracked local files (e.g. new workflows waiting for a push) are never overwritten
+    let mut taken: HashSet<String> = manifest.workflows.keys().cloned().collect();
+    taken.extend(workflow_files(dir)?);
+
+    let filtered = tags.is_some();
+    let params = WorkflowListParams {
+        limit: Some(250),
+        tags,
+        ..Default::default()
+    };
+    let summaries = client.list_all_workflows(params).await?;
+
+    for summary in &summaries {
+        let detail = client.get_workflow(&summary.id).await?;
+        let workflow = TypedWorkflow::from_detail(detail)?;
+
+        // Keep the existing file for known IDs so renames don't create duplicates
+        let file = match manifest.file_for_id(&summary.id) {
+            Some(file) => file.to_string(),
+            None => {
+                let file = file_name_for(&workflow.name, &taken);
+                taken.insert(file.clone());
+                manifest.workflows.insert(file.clone(), summary.id.clone());
+                file
+            }
+        };
+
+        let path = dir.join(&file);
+        let content = normalize_workflow(&workflow)?;
+        let existing = fs::read_to_string(&path).ok();
+        if existing.as_deref() == Some(content.as_str()) {
+            report.unchanged.push(file);
+            continue;
+        }
+
+        fs::write(&path, content).map_err(|e| N8nError::FileWrite {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        report.written.push(file);
+    }
+
+    if prune {
+        // Only prune workflows confirmed gone, not those the tag filter hides
+        let existing: HashSet<String> = if filtered {
+            let params = WorkflowListParams {
+                limit: Some(250),
+                ..Default::default()
+            };
+            client
+                .list_all_workflows(params)
+                .await?
+                .into_iter()
+                .map(|w| w.id)
+                .collect()
+        } else {
+            summaries.iter().map(|w| w.id.clone()).collect()
+        };
+
+        let stale: Vec<String> = manifest
+            .workflows
+            .iter()
+            .filter(|(_, id)| !existing.contains(*id))
+            .map(|(file, _)| file.clone())
+            .collect();
+
+        for file in stale {
+            let path = dir.join(&file);
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| N8nError::FileWrite {
+                    path: path.display().to_string(),
+                    source: e,
+                })?;
+            }
+            manifest.workflows.remove(&file);
+            report.removed.push(file);
+        }
+    }
+
+    manifest.save(dir)?;
+    Ok(report)
+}
+
+/// Kind of change a push would make
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncActionKind {
+    Create,
+    Update,
+    Delete,
+    Unchanged,
+}
+
+impl std::fmt::Display for SyncActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create => write!(f, "create"),
+            Self::Update => write!(f, "update"),
+            Self::Delete => write!(f, "delete"),
+            Self::Unchanged => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// One planned change of a push
+#[derive(Debug, Serialize)]
+pub struct SyncAction {
+    pub action: SyncActionKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    /// Local workflow (absent for deletions)
+    #[serde(skip)]
+    pub workflow: Option<TypedWorkflow>,
+    /// Differences against the server version (updates only)
+    #[serde(skip)]
+    pub diff: Option<WorkflowDiff>,
+    /// Server `versionId` the plan was computed against (updates only)
+    #[serde(skip)]
+    pub version_id: Option<String>,
+}
+
+impl Outputable for SyncAction {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("action", "ACTION", |a: &Self| a.action.to_string()),
+            Column::new("file", "FILE", |a: &Self| {
+                a.file.clone().unwrap_or_else(|| "-".to_string())
+            }),
+            Column::new("id", "ID", |a: &Self| {
+                a.id.clone().unwrap_or_else(|| "-".to_string())
+            }),
+            Column::new("name", "NAME", |a: &Self| a.name.clone()),
+        ]
+    }
+}
+
+/// Planned changes for pushing a directory to the server
+#[derive(Debug, Default)]
+pub struct SyncPlan {
+    pub actions: Vec<SyncAction>,
+}
+
+impl SyncPlan {
+    /// Check if the plan would change anything
+    pub fn has_changes(&self) -> bool {
+        self.actions
+            .iter()
+            .any(|a| a.action != SyncActionKind::Unchanged)
+    }
+
+    /// Print the plan with per-workflow diff summaries
+    pub fn print_summary(&self) {
+        if !self.has_changes() {
+            println!("Everything up to date.");
+            return;
+        }
+
+        for action in &self.actions {
+            let file = action.file.as_deref().unwrap_or("-");
+            match action.action {
+                SyncActionKind::Create => println!("+ create {} ({})", file, action.name),
+                SyncActionKind::Delete => println!(
+                    "- delete {} ({})",
+                    action.id.as_deref().unwrap_or("-"),
+                    action.name
+                ),
+                SyncActionKind::Update => {
+                    println!(
+                        "~ update {} -> {} ({})",
+                        file,
+                        action.id.as_deref().unwrap_or("-"),
+                        action.name
+                    );
+                    if let Some(diff) = &action.diff
+                        && !diff.is_empty()
+                    {
+                        diff.print_summary();
+                    }
+                    println!();
+                }
+                SyncActionKind::Unchanged => {}
+            }
+        }
+    }
+}
+
+/// Compare the workflow files in `dir` with the server
+///
+/// With `delete`, server workflows within the tag filter that no local file
+/// tracks are planned for deletion.
+pub async fn plan_push(
+    client: &N8nClient,
+    dir: &Path,
+    tags: Option<String>,
+    delete: bool,
+) -> Result<SyncPlan> {
+    let manifest = SyncManifest::load(dir)?;
+    let mut plan = SyncPlan::default();
+    let mut local_ids = HashSet::new();
+
+    for file in workflow_files(dir)? {
+        let path = dir.join(&file);
+        let content = fs::read_to_string(&path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        let local: TypedWorkflow =
+            serde_json::from_str(&content).map_err(|e| N8nError::InvalidFile {
+                path: path.display().to_string(),
+                source: e,
+            })?;
+
+        let remote = match manifest.id_for_file(&file) {
+            Some(id) => match client.get_workflow(id).await {
+                Ok(detail) => Some(TypedWorkflow::from_detail(detail)?),
+                // Deleted on the server: recreate it
+                Err(N8nError::Api(e)) if e.code == 404 => None,
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+
+        let action = match remote {
+            Some(remote) => {
+                let id = remote.id.clone();
+                if let Some(id) = &id {
+                    local_ids.insert(id.clone());
+                }
+
+                let diff = WorkflowDiff::compare(&remote, &local);
+                let settings_changed = serde_json::to_value(&remote.settings).ok()
+                    != serde_json::to_value(&local.settings).ok();
+                let kind = if diff.is_empty() && !settings_changed {
+                    SyncActionKind::Unchanged
+                } else {
+                    SyncActionKind::Update
+                };
+
+                SyncAction {
+                    action: kind,
+                    file: Some(file),
+                    id,
+                    name: local.name.clone(),
+                    workflow: Some(local),
+                    diff: Some(diff),
+                    version_id: remote.version_id,
+                }
+            }
+            None => SyncAction {
+                action: SyncActionKind::Create,
+                file: Some(file),
+                id: None,
+                name: local.name.clone(),
+                workflow: Some(local),
+                diff: None,
+                version_id: None,
+            },
+        };
+        plan.actions.push(action);
+    }
+
+    if delete {
+        let params = WorkflowListParams {
+            limit: Some(250),
+            tags,
+            ..Default::default()
+        };
+        for remote in client.list_all_workflows(params).await? {
+            if !local_ids.contains(&remote.id) {
+                plan.actions.push(SyncAction {
+                    action: SyncActionKind::Delete,
+                    file: manifest.file_for_id(&remote.id).map(String::from),
+                    id: Some(remote.id),
+                    name: remote.name,
+                    workflow: None,
+                    diff: None,
+                    version_id: None,
+                });
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Apply a push plan and update the manifest
+pub async fn apply_push(client: &N8nClient, dir: &Path, plan: &SyncPlan) -> Result<()> {
+    let mut manifest = SyncManifest::load(dir)?;
+
+    for action in &plan.actions {
+        match action.action {
+            SyncActionKind::Create => {
+                let (Some(workflow), Some(file)) = (&action.workflow, &action.file) else {
+                    continue;
+                };
+                let created = client.create_workflow(&workflow.to_definition()).await?;
+                manifest.workflows.insert(file.clone(), created.id.clone());
+                // Persist immediately so a later failure doesn't lose the mapping
+                manifest.save(dir)?;
+
+                if workflow.active {
+                    client.activate_workflow(&created.id).await?;
+                }
+            }
+            SyncActionKind::Update => {
+                let (Some(workflow), Some(id)) = (&action.workflow, &action.id) else {
+                    continue;
+                };
+                // Refuse to overwrite changes made on the server after planning
+                client
+                    .update_workflow_if_version(
+                        id,
+                        &workflow.to_definition(),
+                        action.version_id.as_deref(),
+                    )
+                    .await?;
+
+                if let Some((_, active)) = action.diff.as_ref().and_then(|d| d.active_changed) {
+                    if active {
+                        client.activate_workflow(id).await?;
+                    } else {
+                        client.deactivate_workflow(id).await?;
+                    }
+                }
+            }
+            SyncActionKind::Delete => {
+                let Some(id) = &action.id else {
+                    continue;
+                };
+                client.delete_workflow(id).await?;
+                manifest.workflows.retain(|_, wf_id| wf_id != id);
+                manifest.save(dir)?;
+            }
+            SyncActionKind::Unchanged => {}
+        }
+    }
+
+    manifest.save(dir)
+}
+
+/// List workflow files in a sync directory, sorted by name
+fn workflow_files(dir: &Path) -> Result<Vec<String>> {
+    let entries = fs::read_dir(dir).map_err(|e| N8nError::FileRead {
+        path: dir.display().to_string(),
+        source: e,
+    })?;
+
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".json") && name != MANIFEST_FILE)
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::mock::{Fixtures, MockServer};
+    use crate::models::Node;
+    use std::collections::HashMap;
+
+    fn mock_client(server: &MockServer) -> N8nClient {
+        let config = Config {
+            base_url: server.url(),
+            api_key: server.api_key().to_string(),
+            ..Config::default()
+        };
+        N8nClient::new(&config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pull_prunes_only_deleted_workflows() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+
+        let report = pull(&client, dir.path(), None, false).await.unwrap();
+        assert_eq!(report.written.len(), 2);
+
+        // "Nightly report" lacks the tag but still exists
+        let report = pull(&client, dir.path(), Some("production".to_string()), true)
+            .await
+            .unwrap();
+        assert!(report.removed.is_empty());
+        assert_eq!(SyncManifest::load(dir.path()).unwrap().workflows.len(), 2);
+
+        client.delete_workflow("2").await.unwrap();
+        let report = pull(&client, dir.path(), Some("production".to_string()), true)
+            .await
+            .unwrap();
+        assert_eq!(report.removed, vec!["nightly-report.json"]);
+        assert!(!dir.path().join("nightly-report.json").exists());
+        assert!(dir.path().join("order-notifications.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_pull_keeps_untracked_files() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+        let local = r#"{"name": "Local draft", "nodes": [], "connections": {}}"#;
+        fs::write(dir.path().join("nightly-report.json"), local).unwrap();
+
+        let report = pull(&client, dir.path(), None, false).await.unwrap();
+        assert!(report
+            .written
+            .contains(&"nightly-report-2.json".to_string()));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("nightly-report.json")).unwrap(),
+            local
+        );
+        let manifest = SyncManifest::load(dir.path()).unwrap();
+        assert_eq!(manifest.id_for_file("nightly-report-2.json"), Some("2"));
+        assert_eq!(manifest.id_for_file("nightly-report.json"), None);
+    }
+
+    #[tokio::test]
+    async fn test_plan_and_apply_push() {
+        let server = MockServer::start(Fixtures::sample()).unwrap();
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+        pull(&client, dir.path(), None, false).await.unwrap();
+
+        let plan = plan_push(&client, dir.path(), None, true).await.unwrap();
+        assert!(!plan.has_changes());
+
+        // Rename one workflow, add a new file, and leave a server workflow untracked
+        let path = dir.path().join("nightly-report.json");
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, content.replace("Nightly report", "Daily report")).unwrap();
+        fs::write(
+            dir.path().join("new.json"),
+            r#"{"name": "New", "nodes": [], "connections": {}}"#,
+        )
+        .unwrap();
+        let mut manifest = SyncManifest::load(dir.path()).unwrap();
+        manifest.workflows.remove("order-notifications.json");
+        manifest.save(dir.path()).unwrap();
+        fs::remove_file(dir.path().join("order-notifications.json")).unwrap();
+
+        let plan = plan_push(&client, dir.path(), None, true).await.unwrap();
+        let actions: Vec<_> = plan
+            .actions
+            .iter()
+            .map(|a| (a.action, a.name.as_str()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (SyncActionKind::Create, "New"),
+                (SyncActionKind::Update, "Daily report"),
+                (SyncActionKind::Delete, "Order notifications"),
+            ]
+        );
+
+        apply_push(&client, dir.path(), &plan).await.unwrap();
+        assert!(client.get_workflow("1").await.is_err());
+        assert_eq!(client.get_workflow("2").await.unwrap().name, "Daily report");
+        let plan = plan_push(&client, dir.path(), None, true).await.unwrap();
+        assert!(!plan.has_changes());
+    }
+
+    #[test]
+    fn test_normalize_is_stable_and_strips_server_fields() {
+        let mut workflow = TypedWorkflow {
+            id: Some("abc".to_string()),
+            name: "Test".to_string(),
+            active: false,
+            nodes: vec![
+                Node::new("2".into(), "Zeta".into(), "type".into()),
+                Node::new("1".into(), "Alpha".into(), "type".into()),
+            ],
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: Some("v1".to_string()),
+            pin_data: None,
+        };
+
+        let first = normalize_workflow(&workflow).unwrap();
+        workflow.nodes.reverse();
+        let second = normalize_workflow(&workflow).unwrap();
+
+        assert_eq!(first, second);
+        assert!(!first.contains("versionId"));
+        assert!(!first.contains("\"abc\""));
+        assert!(first.find("Alpha").unwrap() < first.find("Zeta").unwrap());
+    }
+}
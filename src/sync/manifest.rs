@@ -0,0 +1,98 @@
+use crate::error::{N8nError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Name of the manifest file kept in a sync directory
+pub const MANIFEST_FILE: &str = ".n8n-sync.json";
+
+/// Maps workflow files in a sync directory to server workflow IDs
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SyncManifest {
+    /// File name -> workflow ID
+    #[serde(default)]
+    pub workflows: BTreeMap<String, String>,
+}
+
+impl SyncManifest {
+    /// Load the manifest from a directory (empty if it doesn't exist yet)
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        serde_json::from_str(&content).map_err(|e| N8nError::InvalidFile {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+
+    /// Write the manifest to a directory
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = serde_json::to_string_pretty(self).map_err(N8nError::Serialize)?;
+        fs::write(&path, format!("{}\n", content)).map_err(|e| N8nError::FileWrite {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+
+    /// Find the file tracking a workflow ID
+    pub fn file_for_id(&self, id: &str) -> Option<&str> {
+        self.workflows
+            .iter()
+            .find(|(_, wf_id)| wf_id.as_str() == id)
+            .map(|(file, _)| file.as_str())
+    }
+
+    /// Find the workflow ID tracked by a file
+    pub fn id_for_file(&self, file: &str) -> Option<&str> {
+        self.workflows.get(file).map(String::as_str)
+    }
+}
+
+/// Derive a file name for a workflow name that doesn't collide with `taken`
+pub fn file_name_for(name: &str, taken: &HashSet<String>) -> String {
+    let mut slug = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    let slug = if slug.is_empty() { "workflow" } else { slug };
+
+    let mut candidate = format!("{}.json", slug);
+    let mut n = 2;
+    while taken.contains(&candidate) || candidate == MANIFEST_FILE {
+        candidate = format!("{}-{}.json", slug, n);
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_for() {
+        let mut taken = HashSet::new();
+        assert_eq!(file_name_for("Sync Orders (v2)", &taken), "sync-orders-v2.json");
+
+        taken.insert("sync-orders-v2.json".to_string());
+        assert_eq!(
+            file_name_for("sync orders v2", &taken),
+            "sync-orders-v2-2.json"
+        );
+        assert_eq!(file_name_for("!!!", &taken), "workflow.json");
+    }
+}
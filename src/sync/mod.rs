@@ -0,0 +1,8 @@
+mod directory;
+mod manifest;
+
+pub use directory::{
+    apply_push, normalize_workflow, plan_push, pull, PullReport, SyncAction, SyncActionKind,
+    SyncPlan,
+};
+pub use manifest::{file_name_for, SyncManifest, MANIFEST_FILE};
@@ -0,0 +1,144 @@
+use super::ValidationSeverity;
+use crate::error::{N8nError, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the lint config file looked up from the working directory upwards
+pub const LINT_CONFIG_FILE: &str = ".n8nlint.toml";
+
+/// Severity override for a rule in `.n8nlint.toml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Info,
+    #[serde(alias = "warn")]
+    Warning,
+    Error,
+}
+
+impl RuleLevel {
+    /// Severity to report with, or `None` when the rule is disabled
+    pub fn severity(self) -> Option<ValidationSeverity> {
+        match self {
+            Self::Off => None,
+            Self::Info => Some(ValidationSeverity::Info),
+            Self::Warning => Some(ValidationSeverity::Warning),
+            Self::Error => Some(ValidationSeverity::Error),
+        }
+    }
+}
+
+/// Contents of a `.n8nlint.toml` file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Rule ID -> level
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleLevel>,
+}
+
+impl LintConfig {
+    /// Load a lint config file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| N8nError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        toml::from_str(&content).map_err(|e| N8nError::LintConfigParse {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+
+    /// Find the nearest `.n8nlint.toml` in `start` or one of its parents
+    pub fn find(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(LINT_CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Load the nearest lint config, or the defaults when there is none
+    pub fn discover(start: &Path) -> Result<Self> {
+        match Self::find(start) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+/// Rules disabled by a `n8nlint-disable` directive in node notes
+///
+/// The directive must be on a line of its own: `n8nlint-disable` alone
+/// disables every rule for the node; `n8nlint-disable orphan-node, self-loop`
+/// disables only the listed ones. Returns `None` when the notes contain no
+/// directive.
+pub fn inline_disables(notes: &str) -> Option<Vec<String>> {
+    const DIRECTIVE: &str = "n8nlint-disable";
+
+    let mut found = false;
+    let mut rules = Vec::new();
+    for line in notes.lines() {
+        let Some(rest) = line.trim().strip_prefix(DIRECTIVE) else {
+            continue;
+        };
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+        found = true;
+
+        let ids: Vec<String> = rest
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect();
+        if ids.is_empty() {
+            // A bare directive wins over any rule list
+            return Some(vec![]);
+        }
+        rules.extend(ids);
+    }
+
+    found.then_some(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lint_config() {
+        let config: LintConfig = toml::from_str(
+            r#"
+            [rules]
+            orphan-node = "off"
+            no-trigger = "error"
+            self-loop = "warn"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.rules["orphan-node"], RuleLevel::Off);
+        assert_eq!(config.rules["no-trigger"], RuleLevel::Error);
+        assert_eq!(config.rules["self-loop"], RuleLevel::Warning);
+    }
+
+    #[test]
+    fn test_inline_disables() {
+        assert_eq!(inline_disables("Just a note"), None);
+        assert_eq!(inline_disables("legacy\nn8nlint-disable"), Some(vec![]));
+        assert_eq!(
+            inline_disables("n8nlint-disable orphan-node, self-loop"),
+            Some(vec!["orphan-node".to_string(), "self-loop".to_string()])
+        );
+        // Mentions in prose don't count
+        assert_eq!(
+            inline_disables("Don't add n8nlint-disable here, fix the loop"),
+            None
+        );
+        assert_eq!(inline_disables("n8nlint-disabled"), None);
+    }
+}
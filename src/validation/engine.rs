@@ -0,0 +1,205 @@
+use super::config::{inline_disables, LintConfig};
+use super::rule::Rule;
+use super::rules::builtin_rules;
+use super::{ValidationIssue, ValidationResult, ValidationSeverity};
+use crate::error::{N8nError, Result};
+use crate::models::TypedWorkflow;
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Runs a set of rules against workflows, honoring a lint config
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    config: LintConfig,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            rules: builtin_rules(),
+            config: LintConfig::default(),
+        }
+    }
+}
+
+impl Linter {
+    /// Linter with the built-in rules
+    pub fn new(config: LintConfig) -> Result<Self> {
+        Self::with_rules(builtin_rules(), config)
+    }
+
+    /// Linter with a custom rule set
+    ///
+    /// Fails if the config refers to a rule that isn't in the set.
+    pub fn with_rules(rules: Vec<Box<dyn Rule>>, config: LintConfig) -> Result<Self> {
+        for id in config.rules.keys() {
+            if !rules.iter().any(|r| r.id() == id) {
+                return Err(N8nError::Config(format!("Unknown lint rule '{}'", id)));
+            }
+        }
+        Ok(Self { rules, config })
+    }
+
+    /// Effective severity of a rule (`None` when disabled)
+    pub fn severity(&self, rule: &dyn Rule) -> Option<ValidationSeverity> {
+        match self.config.rules.get(rule.id()) {
+            Some(level) => level.severity(),
+            None => Some(rule.default_severity()),
+        }
+    }
+
+    /// Describe the configured rules
+    pub fn rule_infos(&self) -> Vec<RuleInfo> {
+        self.rules
+            .iter()
+            .map(|rule| RuleInfo {
+                id: rule.id(),
+                default_severity: rule.default_severity(),
+                severity: self.severity(rule.as_ref()),
+                description: rule.description(),
+            })
+            .collect()
+    }
+
+    /// Run all enabled rules against a workflow
+    pub fn lint(&self, workflow: &TypedWorkflow) -> ValidationResult {
+        // Node name -> rules disabled in its notes (empty = all rules)
+        let disabled: HashMap<&str, Vec<String>> = workflow
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let notes = node.notes.as_deref()?;
+                inline_disables(notes).map(|rules| (node.name.as_str(), rules))
+            })
+            .collect();
+
+        let mut issues = Vec::new();
+        for rule in &self.rules {
+            let Some(severity) = self.severity(rule.as_ref()) else {
+                continue;
+            };
+
+            for finding in rule.check(workflow) {
+                let suppressed = finding
+                    .node
+                    .as_deref()
+                    .and_then(|node| disabled.get(node))
+                    .is_some_and(|rules| rules.is_empty() || rules.iter().any(|r| r == rule.id()));
+                if suppressed {
+                    continue;
+                }
+
+                issues.push(ValidationIssue {
+                    rule: rule.id().to_string(),
+                    severity,
+                    message: finding.message,
+                    node: finding.node,
+                });
+            }
+        }
+
+        ValidationResult { issues }
+    }
+}
+
+/// A rule and its effective severity (for `workflows lint --list-rules`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleInfo {
+    pub id: &'static str,
+    #[serde(rename = "defaultSeverity")]
+    pub default_severity: ValidationSeverity,
+    /// `None` when disabled
+    pub severity: Option<ValidationSeverity>,
+    pub description: &'static str,
+}
+
+impl Outputable for RuleInfo {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("rule", "RULE", |r: &Self| r.id.to_string()),
+            Column::new("severity", "SEVERITY", |r: &Self| {
+                r.severity
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "off".to_string())
+            }),
+            Column::new("default", "DEFAULT", |r: &Self| {
+                r.default_severity.to_string()
+            }),
+            Column::new("description", "DESCRIPTION", |r: &Self| {
+                r.description.to_string()
+            }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Connection, Node};
+    use crate::validation::config::RuleLevel;
+    use std::collections::HashMap;
+
+    fn workflow() -> TypedWorkflow {
+        let mut workflow = TypedWorkflow {
+            id: None,
+            name: "Test".to_string(),
+            active: false,
+            nodes: vec![
+                Node::new(
+                    "1".into(),
+                    "Start".into(),
+                    "n8n-nodes-base.manualTrigger".into(),
+                ),
+                Node::new("2".into(), "Loop".into(), "n8n-nodes-base.set".into()),
+                Node::new("3".into(), "Unused".into(), "n8n-nodes-base.set".into()),
+            ],
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: None,
+        };
+        workflow.add_connection(Connection::new("Start".into(), "Loop".into()));
+        workflow.add_connection(Connection::new("Loop".into(), "Loop".into()));
+        workflow
+    }
+
+    fn rules(result: &ValidationResult) -> Vec<&str> {
+        result.issues.iter().map(|i| i.rule.as_str()).collect()
+    }
+
+    #[test]
+    fn test_lint_reports_rule_ids() {
+        let result = Linter::default().lint(&workflow());
+        assert_eq!(rules(&result), vec!["orphan-node", "self-loop"]);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn test_lint_config_overrides_severity() {
+        let mut config = LintConfig::default();
+        config.rules.insert("orphan-node".into(), RuleLevel::Off);
+        config.rules.insert("self-loop".into(), RuleLevel::Error);
+
+        let result = Linter::new(config).unwrap().lint(&workflow());
+        assert_eq!(rules(&result), vec!["self-loop"]);
+        assert!(!result.is_valid());
+    }
+
+    #[test]
+    fn test_lint_unknown_rule_in_config() {
+        let mut config = LintConfig::default();
+        config.rules.insert("no-such-rule".into(), RuleLevel::Off);
+        assert!(Linter::new(config).is_err());
+    }
+
+    #[test]
+    fn test_lint_inline_disable() {
+        let mut workflow = workflow();
+        workflow.nodes[2].notes = Some("Kept for reference\nn8nlint-disable orphan-node".into());
+
+        let result = Linter::default().lint(&workflow);
+        assert_eq!(rules(&result), vec!["self-loop"]);
+    }
+}
@@ -1,3 +1,11 @@
+mod config;
+mod engine;
+mod rule;
+mod rules;
 mod workflow;
 
+pub use config::{inline_disables, LintConfig, RuleLevel, LINT_CONFIG_FILE};
+pub use engine::{Linter, RuleInfo};
+pub use rule::{Finding, Rule};
+pub use rules::builtin_rules;
 pub use workflow::{validate_workflow, ValidationIssue, ValidationResult, ValidationSeverity};
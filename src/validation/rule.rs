@@ -0,0 +1,42 @@
+use super::ValidationSeverity;
+use crate::models::TypedWorkflow;
+
+/// A problem reported by a rule, before severity and suppressions are applied
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub message: String,
+    pub node: Option<String>,
+}
+
+impl Finding {
+    /// A finding about the workflow as a whole
+    pub fn workflow(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            node: None,
+        }
+    }
+
+    /// A finding attached to a node (can be disabled through the node's notes)
+    pub fn node(node: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            node: Some(node.to_string()),
+        }
+    }
+}
+
+/// A single lint check
+pub trait Rule: Send + Sync {
+    /// Stable identifier used in `.n8nlint.toml`, inline disables and output
+    fn id(&self) -> &'static str;
+
+    /// Severity used unless the lint config overrides it
+    fn default_severity(&self) -> ValidationSeverity;
+
+    /// One-line description of what the rule checks
+    fn description(&self) -> &'static str;
+
+    /// Run the check
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding>;
+}
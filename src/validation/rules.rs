@@ -0,0 +1,440 @@
+use super::rule::{Finding, Rule};
+use super::ValidationSeverity;
+use crate::graph::WorkflowGraph;
+use crate::models::expression;
+use crate::models::TypedWorkflow;
+use std::collections::HashSet;
+
+/// All built-in rules, in reporting order
+pub fn builtin_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(EmptyWorkflow),
+        Box::new(DuplicateNodeId),
+        Box::new(DuplicateNodeName),
+        Box::new(NoTrigger),
+        Box::new(DanglingConnection),
+        Box::new(OrphanNode),
+        Box::new(SelfLoop),
+        Box::new(UnreachableNode),
+        Box::new(Cycle),
+        Box::new(UnusedOutput),
+        Box::new(ExpressionSyntax),
+        Box::new(UnknownNodeReference),
+        Box::new(EmptyNodeName),
+        Box::new(EmptyWorkflowName),
+    ]
+}
+
+pub struct EmptyWorkflow;
+
+impl Rule for EmptyWorkflow {
+    fn id(&self) -> &'static str {
+        "empty-workflow"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Workflow has no nodes"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        if workflow.nodes.is_empty() {
+            vec![Finding::workflow("Workflow has no nodes")]
+        } else {
+            vec![]
+        }
+    }
+}
+
+pub struct DuplicateNodeId;
+
+impl Rule for DuplicateNodeId {
+    fn id(&self) -> &'static str {
+        "duplicate-node-id"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Two nodes share the same ID"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let mut seen = HashSet::new();
+        workflow
+            .nodes
+            .iter()
+            .filter(|node| !seen.insert(&node.id))
+            .map(|node| Finding::node(&node.name, format!("Duplicate node ID: {}", node.id)))
+            .collect()
+    }
+}
+
+pub struct DuplicateNodeName;
+
+impl Rule for DuplicateNodeName {
+    fn id(&self) -> &'static str {
+        "duplicate-node-name"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Two nodes share the same name (n8n uses names in connections)"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let mut seen = HashSet::new();
+        workflow
+            .nodes
+            .iter()
+            .filter(|node| !seen.insert(&node.name))
+            .map(|node| Finding::node(&node.name, format!("Duplicate node name: {}", node.name)))
+            .collect()
+    }
+}
+
+pub struct NoTrigger;
+
+impl Rule for NoTrigger {
+    fn id(&self) -> &'static str {
+        "no-trigger"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Workflow has no trigger node"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        // An empty workflow is already reported by empty-workflow
+        if workflow.nodes.is_empty() || workflow.has_trigger() {
+            return vec![];
+        }
+        vec![Finding::workflow(
+            "No trigger node found. Workflow can only be executed manually.",
+        )]
+    }
+}
+
+pub struct DanglingConnection;
+
+impl Rule for DanglingConnection {
+    fn id(&self) -> &'static str {
+        "dangling-connection"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Connection references a node that doesn't exist"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let valid_nodes: HashSet<_> = workflow.nodes.iter().map(|n| n.name.as_str()).collect();
+        let mut findings = Vec::new();
+
+        for conn in workflow.connections_flat() {
+            if !valid_nodes.contains(conn.source_node.as_str()) {
+                findings.push(Finding::node(
+                    &conn.source_node,
+                    format!(
+                        "Connection references non-existent source node: {}",
+                        conn.source_node
+                    ),
+                ));
+            }
+            if !valid_nodes.contains(conn.target_node.as_str()) {
+                findings.push(Finding::node(
+                    &conn.target_node,
+                    format!(
+                        "Connection references non-existent target node: {}",
+                        conn.target_node
+                    ),
+                ));
+            }
+        }
+        findings
+    }
+}
+
+pub struct OrphanNode;
+
+impl Rule for OrphanNode {
+    fn id(&self) -> &'static str {
+        "orphan-node"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Non-trigger node is not connected to any other node"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let mut connected: HashSet<String> = HashSet::new();
+        for conn in workflow.connections_flat() {
+            connected.insert(conn.source_node);
+            connected.insert(conn.target_node);
+        }
+
+        workflow
+            .nodes
+            .iter()
+            .filter(|node| !node.is_trigger() && !connected.contains(&node.name))
+            .map(|node| {
+                Finding::node(
+                    &node.name,
+                    format!("Node '{}' is not connected to any other node", node.name),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct SelfLoop;
+
+impl Rule for SelfLoop {
+    fn id(&self) -> &'static str {
+        "self-loop"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Node is connected to itself"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        workflow
+            .connections_flat()
+            .into_iter()
+            .filter(|conn| conn.source_node == conn.target_node)
+            .map(|conn| {
+                Finding::node(
+                    &conn.source_node,
+                    format!("Node '{}' has a self-loop connection", conn.source_node),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct EmptyNodeName;
+
+impl Rule for EmptyNodeName {
+    fn id(&self) -> &'static str {
+        "empty-node-name"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Node has an empty name"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        workflow
+            .nodes
+            .iter()
+            .filter(|node| node.name.trim().is_empty())
+            // There is no name to show (or to disable the rule by), so use the ID
+            .map(|node| Finding::workflow(format!("Node with ID '{}' has empty name", node.id)))
+            .collect()
+    }
+}
+
+pub struct EmptyWorkflowName;
+
+impl Rule for EmptyWorkflowName {
+    fn id(&self) -> &'static str {
+        "empty-workflow-name"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Workflow has an empty name"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        if workflow.name.trim().is_empty() {
+            vec![Finding::workflow("Workflow has empty name")]
+        } else {
+            vec![]
+        }
+    }
+}
+
+pub struct UnreachableNode;
+
+impl Rule for UnreachableNode {
+    fn id(&self) -> &'static str {
+        "unreachable-node"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Connected node can't be reached from any trigger"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        WorkflowGraph::new(workflow)
+            .unreachable()
+            .into_iter()
+            .map(|name| {
+                Finding::node(
+                    name,
+                    format!("Node '{}' can't be reached from any trigger", name),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct Cycle;
+
+impl Rule for Cycle {
+    fn id(&self) -> &'static str {
+        "cycle"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Warning
+    }
+
+    fn description(&self) -> &'static str {
+        "Nodes form a loop (other than Loop Over Items)"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        WorkflowGraph::new(workflow)
+            .cycles()
+            .into_iter()
+            .map(|cycle| {
+                Finding::node(
+                    cycle[0],
+                    format!("Nodes form a cycle: {}", cycle.join(", ")),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct UnusedOutput;
+
+impl Rule for UnusedOutput {
+    fn id(&self) -> &'static str {
+        "unused-output"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Info
+    }
+
+    fn description(&self) -> &'static str {
+        "IF or Switch output is not connected (its items are dropped)"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        WorkflowGraph::new(workflow)
+            .unused_outputs()
+            .into_iter()
+            .map(|unused| {
+                Finding::node(
+                    &unused.node,
+                    format!(
+                        "Output '{}' of '{}' is not connected",
+                        unused.label, unused.node
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct ExpressionSyntax;
+
+impl Rule for ExpressionSyntax {
+    fn id(&self) -> &'static str {
+        "expression-syntax"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Expression has an unclosed {{ block or unmatched closing braces"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for node in &workflow.nodes {
+            expression::visit_strings(&node.parameters, "parameters", &mut |path, _, value| {
+                for error in expression::check_expression(value) {
+                    findings.push(Finding::node(&node.name, format!("{}: {}", path, error)));
+                }
+            });
+        }
+        findings
+    }
+}
+
+pub struct UnknownNodeReference;
+
+impl Rule for UnknownNodeReference {
+    fn id(&self) -> &'static str {
+        "unknown-node-reference"
+    }
+
+    fn default_severity(&self) -> ValidationSeverity {
+        ValidationSeverity::Error
+    }
+
+    fn description(&self) -> &'static str {
+        "Expression references a node that doesn't exist"
+    }
+
+    fn check(&self, workflow: &TypedWorkflow) -> Vec<Finding> {
+        let names: HashSet<&str> = workflow.nodes.iter().map(|n| n.name.as_str()).collect();
+        let mut findings = Vec::new();
+
+        for node in &workflow.nodes {
+            expression::visit_strings(&node.parameters, "parameters", &mut |path, key, value| {
+                for reference in expression::node_references(key, value) {
+                    if !names.contains(reference.name.as_str()) {
+                        findings.push(Finding::node(
+                            &node.name,
+                            format!("{}: references unknown node '{}'", path, reference.name),
+                        ));
+                    }
+                }
+            });
+        }
+        findings
+    }
+}
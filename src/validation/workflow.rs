@@ -1,19 +1,49 @@
+use super::Linter;
 use crate::models::TypedWorkflow;
-use std::collections::HashSet;
+use crate::output::{Column, Outputable};
+use serde::Serialize;
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ValidationSeverity {
     Error,
     Warning,
+    Info,
 }
 
-#[derive(Debug)]
+impl fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+            Self::Info => write!(f, "info"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct ValidationIssue {
+    /// ID of the rule that reported the issue
+    pub rule: String,
     pub severity: ValidationSeverity,
     pub message: String,
     pub node: Option<String>,
 }
 
+impl Outputable for ValidationIssue {
+    fn columns() -> Vec<Column<Self>> {
+        vec![
+            Column::new("severity", "SEVERITY", |i: &Self| i.severity.to_string()),
+            Column::new("rule", "RULE", |i: &Self| i.rule.clone()),
+            Column::new("node", "NODE", |i: &Self| {
+                i.node.clone().unwrap_or_else(|| "-".to_string())
+            }),
+            Column::new("message", "MESSAGE", |i: &Self| i.message.clone()),
+        ]
+    }
+}
+
 pub struct ValidationResult {
     pub issues: Vec<ValidationIssue>,
 }
@@ -45,13 +75,14 @@ impl ValidationResult {
         let mut output = Vec::new();
 
         for issue in &self.issues {
-            if !include_warnings && matches!(issue.severity, ValidationSeverity::Warning) {
+            if !include_warnings && !matches!(issue.severity, ValidationSeverity::Error) {
                 continue;
             }
 
             let prefix = match issue.severity {
                 ValidationSeverity::Error => "ERROR",
                 ValidationSeverity::Warning => "WARNING",
+                ValidationSeverity::Info => "INFO",
             };
 
             let node_info = issue
@@ -60,141 +91,19 @@ impl ValidationResult {
                 .map(|n| format!(" [{}]", n))
                 .unwrap_or_default();
 
-            output.push(format!("{}{}: {}", prefix, node_info, issue.message));
+            output.push(format!(
+                "{}{}: {} ({})",
+                prefix, node_info, issue.message, issue.rule
+            ));
         }
 
         output.join("\n")
     }
 }
 
-/// Validate a workflow
+/// Validate a workflow with the built-in rules at their default severities
 pub fn validate_workflow(workflow: &TypedWorkflow) -> ValidationResult {
-    let mut issues = Vec::new();
-
-    // Check for empty workflow
-    if workflow.nodes.is_empty() {
-        issues.push(ValidationIssue {
-            severity: ValidationSeverity::Warning,
-            message: "Workflow has no nodes".to_string(),
-            node: None,
-        });
-        return ValidationResult { issues };
-    }
-
-    // Check for duplicate node IDs
-    let mut seen_ids = HashSet::new();
-    for node in &workflow.nodes {
-        if !seen_ids.insert(&node.id) {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Error,
-                message: format!("Duplicate node ID: {}", node.id),
-                node: Some(node.name.clone()),
-            });
-        }
-    }
-
-    // Check for duplicate node names (n8n uses names in connections)
-    let mut seen_names = HashSet::new();
-    for node in &workflow.nodes {
-        if !seen_names.insert(&node.name) {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Error,
-                message: format!("Duplicate node name: {}", node.name),
-                node: Some(node.name.clone()),
-            });
-        }
-    }
-
-    // Build set of valid node names
-    let valid_nodes: HashSet<_> = workflow.nodes.iter().map(|n| n.name.as_str()).collect();
-
-    // Check for trigger nodes
-    let has_trigger = workflow.has_trigger();
-
-    if !has_trigger {
-        issues.push(ValidationIssue {
-            severity: ValidationSeverity::Warning,
-            message: "No trigger node found. Workflow can only be executed manually.".to_string(),
-            node: None,
-        });
-    }
-
-    // Check connections reference valid nodes
-    let connections = workflow.connections_flat();
-    for conn in &connections {
-        if !valid_nodes.contains(conn.source_node.as_str()) {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Error,
-                message: format!(
-                    "Connection references non-existent source node: {}",
-                    conn.source_node
-                ),
-                node: Some(conn.source_node.clone()),
-            });
-        }
-        if !valid_nodes.contains(conn.target_node.as_str()) {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Error,
-                message: format!(
-                    "Connection references non-existent target node: {}",
-                    conn.target_node
-                ),
-                node: Some(conn.target_node.clone()),
-            });
-        }
-    }
-
-    // Check for orphan nodes (not connected to anything except triggers)
-    let mut connected_nodes: HashSet<&str> = HashSet::new();
-    for conn in &connections {
-        connected_nodes.insert(&conn.source_node);
-        connected_nodes.insert(&conn.target_node);
-    }
-
-    for node in &workflow.nodes {
-        let is_trigger = node.is_trigger();
-
-        if !is_trigger && !connected_nodes.contains(node.name.as_str()) {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Warning,
-                message: format!("Node '{}' is not connected to any other node", node.name),
-                node: Some(node.name.clone()),
-            });
-        }
-    }
-
-    // Check for self-loops
-    for conn in &connections {
-        if conn.source_node == conn.target_node {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Warning,
-                message: format!("Node '{}' has a self-loop connection", conn.source_node),
-                node: Some(conn.source_node.clone()),
-            });
-        }
-    }
-
-    // Check for empty node names
-    for node in &workflow.nodes {
-        if node.name.trim().is_empty() {
-            issues.push(ValidationIssue {
-                severity: ValidationSeverity::Error,
-                message: "Node has empty name".to_string(),
-                node: Some(node.id.clone()),
-            });
-        }
-    }
-
-    // Check for empty workflow name
-    if workflow.name.trim().is_empty() {
-        issues.push(ValidationIssue {
-            severity: ValidationSeverity::Error,
-            message: "Workflow has empty name".to_string(),
-            node: None,
-        });
-    }
-
-    ValidationResult { issues }
+    Linter::default().lint(workflow)
 }
 
 #[cfg(test)]
@@ -214,10 +123,11 @@ mod tests {
             settings: Default::default(),
             tags: vec![],
             version_id: None,
+            pin_data: None,
         };
 
         let result = validate_workflow(&workflow);
-        assert!(result.warnings().len() > 0);
+        assert!(!result.warnings().is_empty());
     }
 
     #[test]
@@ -234,9 +144,34 @@ mod tests {
             settings: Default::default(),
             tags: vec![],
             version_id: None,
+            pin_data: None,
         };
 
         let result = validate_workflow(&workflow);
         assert!(!result.is_valid());
     }
+
+    #[test]
+    fn test_validate_empty_node_name() {
+        let workflow = TypedWorkflow {
+            id: None,
+            name: "Test".to_string(),
+            active: false,
+            nodes: vec![Node::new("abc".into(), " ".into(), "type".into())],
+            connections: HashMap::new(),
+            settings: Default::default(),
+            tags: vec![],
+            version_id: None,
+            pin_data: None,
+        };
+
+        let result = validate_workflow(&workflow);
+        let issue = result
+            .errors()
+            .into_iter()
+            .find(|i| i.rule == "empty-node-name")
+            .unwrap();
+        assert_eq!(issue.message, "Node with ID 'abc' has empty name");
+        assert_eq!(issue.node, None);
+    }
 }
@@ -0,0 +1,463 @@
+//! End-to-end tests of the `n8n` binary against the in-process mock server
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use n8n_cli::mock::{Fixtures, MockServer};
+use predicates::prelude::*;
+use serde_json::{json, Value};
+use std::fs;
+use tempfile::TempDir;
+
+/// `n8n` pointed at `server`, with an empty config directory in `home`
+fn n8n(server: &MockServer, home: &TempDir) -> Command {
+    let mut cmd = cargo_bin_cmd!("n8n");
+    cmd.env("N8N_BASE_URL", server.url())
+        .env("N8N_API_KEY", server.api_key())
+        .env_remove("N8N_PROFILE")
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path());
+    cmd
+}
+
+fn json_output(cmd: &mut Command) -> Value {
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn list_follows_cursors() {
+    let fixtures = Fixtures {
+        workflows: (0..120)
+            .map(|i| json!({ "name": format!("Workflow {}", i) }))
+            .collect(),
+        ..Default::default()
+    };
+    let server = MockServer::start(fixtures).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .args(["wf", "list", "--limit", "50"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Use --cursor 50 to continue"));
+
+    let all = json_output(n8n(&server, &home).args(["wf", "list", "--all", "-o", "json"]));
+    assert_eq!(all.as_array().unwrap().len(), 120);
+}
+
+#[test]
+fn create_remaps_credentials_by_name() {
+    let fixtures = Fixtures {
+        credentials: vec![json!({ "id": "7", "name": "Slack bot", "type": "slackApi" })],
+        ..Default::default()
+    };
+    let server = MockServer::start(fixtures).unwrap();
+    let home = TempDir::new().unwrap();
+
+    let file = home.path().join("workflow.json");
+    let workflow = json!({
+        "name": "Exported",
+        "nodes": [{
+            "id": "a",
+            "name": "Slack",
+            "type": "n8n-nodes-base.slack",
+            "typeVersion": 1,
+            "position": [0, 0],
+            "parameters": {},
+            "credentials": { "slackApi": { "id": "123", "name": "Slack bot" } }
+        }],
+        "connections": {}
+    });
+    fs::write(&file, workflow.to_string()).unwrap();
+
+    let created = json_output(
+        n8n(&server, &home)
+            .args(["wf", "create", "-o", "json"])
+            .arg(&file),
+    );
+    let id = created["id"].as_str().unwrap();
+
+    let store = server.store();
+    let stored = store.workflow(id).unwrap();
+    assert_eq!(stored["name"], "Exported");
+    assert_eq!(stored["nodes"][0]["credentials"]["slackApi"]["id"], "7");
+}
+
+#[test]
+fn bulk_deactivate_changes_every_workflow() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .args(["wf", "bulk", "deactivate", "--all", "--force"])
+        .assert()
+        .success();
+
+    assert!(server
+        .store()
+        .workflows()
+        .iter()
+        .all(|w| w["active"] == false));
+}
+
+#[test]
+fn run_and_wait_records_an_execution() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .args(["exec", "run", "2", "--wait", "--data", r#"{"x":1}"#])
+        .assert()
+        .success();
+
+    let store = server.store();
+    let execution = store.executions().last().unwrap();
+    assert_eq!(execution["workflowId"], "2");
+    assert_eq!(execution["data"]["input"], json!({ "x": 1 }));
+}
+
+#[test]
+fn logs_print_one_line_per_execution() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .args(["exec", "logs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"(?m)^\S.*  2 +1 +error +manual +").unwrap());
+    n8n(&server, &home)
+        .args(["exec", "logs", "-o", "csv"])
+        .assert()
+        .code(64)
+        .stderr(predicate::str::contains("-o csv is not supported"));
+    n8n(&server, &home)
+        .args(["exec", "logs", "--columns", "id"])
+        .assert()
+        .code(64);
+}
+
+#[test]
+fn run_triggers_the_webhook() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    let response = json_output(
+        n8n(&server, &home)
+            .args(["wf", "run", "1", "-o", "json", "--data"])
+            .arg(r#"{"order":7}"#),
+    );
+    assert_eq!(response["message"], "Workflow was started");
+
+    let store = server.store();
+    let execution = store.executions().last().unwrap();
+    assert_eq!(execution["mode"], "webhook");
+    assert_eq!(execution["data"]["input"], json!({ "order": 7 }));
+}
+
+#[test]
+fn rename_updates_workflows_with_pinned_data() {
+    let mut fixtures = Fixtures::sample();
+    fixtures.workflows[0]["pinData"] = json!({ "Webhook": [{ "json": { "order": 7 } }] });
+    let server = MockServer::start(fixtures).unwrap();
+    let home = TempDir::new().unwrap();
+
+    // The API rejects pinData in update bodies, so it must not be sent,
+    // and the report says so even in quiet mode
+    let output = n8n(&server, &home)
+        .args(["-q", "-o", "json"])
+        .args(["wf", "nodes", "rename", "1", "Webhook", "Order Hook"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("pinned data stays under 'Webhook'"));
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["locations"][1]["location"], "pinData.Order Hook");
+
+    let store = server.store();
+    let workflow = &store.workflows()[0];
+    assert_eq!(workflow["nodes"][0]["name"], "Order Hook");
+    assert!(workflow["pinData"]["Webhook"].is_array());
+}
+
+#[test]
+fn validate_honors_the_lint_config() {
+    let mut fixtures = Fixtures::sample();
+    fixtures.workflows[0]["nodes"][0]["parameters"]["path"] = json!("={{ $json.path }");
+    let server = MockServer::start(fixtures).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .current_dir(home.path())
+        .args(["wf", "validate", "1"])
+        .assert()
+        .code(65)
+        .stderr(predicate::str::contains("Unclosed '{{'"));
+
+    fs::write(
+        home.path().join(".n8nlint.toml"),
+        "[rules]\nexpression-syntax = \"off\"\n",
+    )
+    .unwrap();
+    n8n(&server, &home)
+        .current_dir(home.path())
+        .args(["wf", "validate", "1"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn update_refuses_outdated_exports() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+    let export = home.path().join("workflow.json");
+
+    n8n(&server, &home)
+        .args(["wf", "export", "1", "--file"])
+        .arg(&export)
+        .assert()
+        .success();
+    // Someone else saves in between, which changes the versionId
+    n8n(&server, &home)
+        .args(["wf", "nodes", "move", "1", "Webhook", "100,100"])
+        .assert()
+        .success();
+
+    let update = || {
+        let mut cmd = n8n(&server, &home);
+        cmd.args(["wf", "update", "1"]).arg(&export);
+        cmd
+    };
+    update()
+        .assert()
+        .code(75)
+        .stderr(predicate::str::contains("was modified since it was read"));
+    assert_eq!(
+        server.store().workflows()[0]["nodes"][0]["position"],
+        json!([100, 100])
+    );
+
+    update().arg("--overwrite").assert().success();
+    assert_ne!(
+        server.store().workflows()[0]["nodes"][0]["position"],
+        json!([100, 100])
+    );
+
+    // Without a versionId there is nothing to compare against
+    let mut workflow: Value = serde_json::from_str(&fs::read_to_string(&export).unwrap()).unwrap();
+    workflow.as_object_mut().unwrap().remove("versionId");
+    fs::write(&export, workflow.to_string()).unwrap();
+    update()
+        .assert()
+        .code(65)
+        .stderr(predicate::str::contains("the file has no versionId"));
+    update().arg("--force").assert().success();
+}
+
+#[test]
+fn promote_copies_between_profiles() {
+    let dev = MockServer::start(Fixtures::sample()).unwrap();
+    let prod = MockServer::start(Fixtures {
+        credentials: vec![json!({ "id": "40", "name": "Slack bot", "type": "slackApi" })],
+        ..Default::default()
+    })
+    .unwrap();
+    let home = TempDir::new().unwrap();
+
+    let config_dir = home.path().join("n8n-cli");
+    fs::create_dir_all(&config_dir).unwrap();
+    let config = format!(
+        "[profiles.dev]\nbase_url = \"{}\"\napi_key = \"{}\"\n\n\
+         [profiles.prod]\nbase_url = \"{}\"\napi_key = \"{}\"\n",
+        dev.url(),
+        dev.api_key(),
+        prod.url(),
+        prod.api_key()
+    );
+    fs::write(config_dir.join("config.toml"), config).unwrap();
+
+    n8n(&dev, &home)
+        .args([
+            "wf", "promote", "1", "--from", "dev", "--to", "prod", "--force",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created workflow on 'prod'"));
+
+    let store = prod.store();
+    let promoted = &store.workflows()[0];
+    assert_eq!(promoted["name"], "Order notifications");
+    assert_eq!(promoted["active"], true);
+    assert_eq!(promoted["tags"][0]["name"], "production");
+    assert_eq!(promoted["nodes"][1]["credentials"]["slackApi"]["id"], "40");
+}
+
+#[test]
+fn exit_codes_follow_api_errors() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    n8n(&server, &home)
+        .args(["wf", "get", "999"])
+        .assert()
+        .code(69);
+
+    n8n(&server, &home)
+        .args(["wf", "list"])
+        .env("N8N_API_KEY", "wrong-key")
+        .assert()
+        .code(77);
+
+    n8n(&server, &home)
+        .args(["health", "check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ok"));
+}
+
+#[test]
+fn doctor_reports_each_check() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+
+    let checks = json_output(n8n(&server, &home).args(["doctor", "-o", "json"]));
+    let checks = checks.as_array().unwrap();
+    assert_eq!(checks.len(), 10);
+    assert!(checks.iter().all(|c| c["status"] == "pass"), "{:?}", checks);
+
+    let output = n8n(&server, &home)
+        .args(["doctor", "-o", "json"])
+        .env("N8N_API_KEY", "wrong-key")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let checks: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let auth = checks
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "Authentication")
+        .unwrap();
+    assert_eq!(auth["status"], "fail");
+    assert!(auth["hint"].is_string());
+}
+
+#[test]
+fn profiles_are_written_to_the_config_file() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+    let config_file = home.path().join("n8n-cli").join("config.toml");
+    fs::create_dir_all(config_file.parent().unwrap()).unwrap();
+    fs::write(&config_file, "# Managed by hand\ntimeout_secs = 60\n").unwrap();
+
+    n8n(&server, &home)
+        .args([
+            "config",
+            "profiles",
+            "add",
+            "mock",
+            "--default",
+            "--api-key-stdin",
+        ])
+        .args(["--base-url", &server.url()])
+        .write_stdin(format!("{}\n", server.api_key()))
+        .assert()
+        .success();
+    n8n(&server, &home)
+        .args([
+            "config",
+            "profiles",
+            "edit",
+            "mock",
+            "--output-format",
+            "json",
+        ])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&config_file).unwrap();
+    assert!(
+        content.starts_with("# Managed by hand\ntimeout_secs = 60\ndefault_profile = \"mock\"\n")
+    );
+
+    // Only the profile supplies the URL, key and output format
+    let profile_only = || {
+        let mut cmd = cargo_bin_cmd!("n8n");
+        cmd.env_remove("N8N_BASE_URL")
+            .env_remove("N8N_API_KEY")
+            .env_remove("N8N_PROFILE")
+            .env("HOME", home.path())
+            .env("XDG_CONFIG_HOME", home.path());
+        cmd
+    };
+    let workflows = json_output(profile_only().args(["wf", "list"]));
+    assert_eq!(workflows.as_array().unwrap().len(), 2);
+    let settings = json_output(profile_only().args(["config", "show"]));
+    let base_url = settings
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["setting"] == "base_url")
+        .unwrap();
+    assert_eq!(base_url["value"], server.url());
+    assert_eq!(base_url["source"], "file");
+    assert_eq!(base_url["origin"], "profiles.mock.base_url");
+    let output = settings
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["setting"] == "output")
+        .unwrap();
+    assert_eq!(output["source"], "file");
+    assert_eq!(output["origin"], "profiles.mock.output_format");
+
+    let profiles =
+        json_output(n8n(&server, &home).args(["config", "profiles", "list", "-o", "json"]));
+    assert_eq!(profiles[0]["name"], "mock");
+    assert_eq!(profiles[0]["default"], true);
+
+    n8n(&server, &home)
+        .args(["config", "profiles", "remove", "mock", "--force"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&config_file).unwrap();
+    assert_eq!(content, "# Managed by hand\ntimeout_secs = 60\n");
+}
+
+#[test]
+fn replay_answers_without_the_server() {
+    let server = MockServer::start(Fixtures::sample()).unwrap();
+    let home = TempDir::new().unwrap();
+    let cassettes = home.path().join("cassettes");
+
+    let recorded = n8n(&server, &home)
+        .args(["wf", "list", "-o", "json", "--record"])
+        .arg(&cassettes)
+        .output()
+        .unwrap();
+    assert!(recorded.status.success());
+    drop(server);
+
+    let recording = fs::read_dir(&cassettes).unwrap().next().unwrap().unwrap();
+    let content = fs::read_to_string(recording.path()).unwrap();
+    assert!(content.contains("<redacted>"));
+    assert!(!content.contains("n8n-mock-api-key"));
+
+    // Neither a base URL nor an API key is needed to replay
+    cargo_bin_cmd!("n8n")
+        .env_remove("N8N_BASE_URL")
+        .env_remove("N8N_API_KEY")
+        .env_remove("N8N_PROFILE")
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["wf", "list", "-o", "json", "--replay"])
+        .arg(&cassettes)
+        .assert()
+        .success()
+        .stdout(recorded.stdout);
+}